@@ -0,0 +1,61 @@
+//! The CRC64 flavour used by the storage services for the
+//! `x-ms-content-crc64` header.
+
+const POLYNOMIAL: u64 = 0x9A6C_9329_AC4B_C9B5;
+
+const TABLE: [u64; 256] = make_table();
+
+const fn make_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            j += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Updates `crc` with `data`. Start from 0.
+pub fn update(crc: u64, data: &[u8]) -> u64 {
+    let mut crc = !crc;
+    for byte in data {
+        crc = TABLE[((crc ^ u64::from(*byte)) & 0xff) as usize] ^ (crc >> 8);
+    }
+    !crc
+}
+
+/// Returns the CRC64 of `data` in the byte order sent on the wire.
+pub fn checksum(data: &[u8]) -> [u8; 8] {
+    update(0, data).to_le_bytes()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_value() {
+        assert_eq!(update(0, b"123456789"), 0xAE8B_1486_0A79_9888);
+        assert_eq!(checksum(b""), [0; 8]);
+    }
+
+    #[test]
+    fn incremental_update() {
+        let crc = update(update(0, b"12345"), b"6789");
+        assert_eq!(crc, update(0, b"123456789"));
+        assert_eq!(
+            checksum(b"123456789"),
+            [0x88, 0x98, 0x79, 0x0A, 0x86, 0x14, 0x8B, 0xAE]
+        );
+    }
+}
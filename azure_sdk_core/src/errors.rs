@@ -1,9 +1,12 @@
-use crate::{enumerations::ParsingError, range::ParseError};
+use crate::http_client::ResponseFuture;
+use crate::retry_policy::retry_after_from_headers;
+use crate::service_error::{ServiceError, ServiceErrorCode};
+use crate::{enumerations::ParsingError, range::ParseError, Consistency};
 use base64;
 use chrono;
 use http;
 use http::header::ToStrError;
-use hyper::{self, body, Body, StatusCode};
+use hyper::{self, body, Body, HeaderMap, StatusCode};
 use serde_json;
 use serde_xml_rs;
 use std;
@@ -13,6 +16,7 @@ use std::num::ParseIntError;
 use std::str;
 use std::str::ParseBoolError;
 use std::string;
+use std::time::Duration;
 use url::ParseError as URLParseError;
 use uuid;
 use xml::BuilderError as XMLError;
@@ -62,6 +66,8 @@ pub struct UnexpectedHTTPResult {
     expected: Vec<StatusCode>,
     received: StatusCode,
     body: String,
+    service_error: Option<Box<ServiceError>>,
+    retry_after: Option<Duration>,
 }
 
 impl UnexpectedHTTPResult {
@@ -70,6 +76,8 @@ impl UnexpectedHTTPResult {
             expected: vec![expected],
             received,
             body: body.to_owned(),
+            service_error: ServiceError::from_body(body).map(Box::new),
+            retry_after: None,
         }
     }
 
@@ -82,12 +90,43 @@ impl UnexpectedHTTPResult {
             expected: allowed,
             received,
             body: body.to_owned(),
+            service_error: ServiceError::from_body(body).map(Box::new),
+            retry_after: None,
+        }
+    }
+
+    /// Completes the service error with the error code, request id and
+    /// activity id found in the response headers, and keeps the wait the
+    /// service asked for before retrying.
+    pub fn with_headers(self, headers: &HeaderMap) -> UnexpectedHTTPResult {
+        let service_error = ServiceError::from_headers_and_body(headers, &self.body).map(Box::new);
+        UnexpectedHTTPResult {
+            service_error,
+            retry_after: retry_after_from_headers(headers),
+            ..self
         }
     }
 
     pub fn status_code(&self) -> StatusCode {
         self.received
     }
+
+    pub fn body(&self) -> &str {
+        &self.body
+    }
+
+    pub fn service_error(&self) -> Option<&ServiceError> {
+        self.service_error.as_deref()
+    }
+
+    pub fn error_code(&self) -> Option<&ServiceErrorCode> {
+        self.service_error.as_ref().map(|e| e.code())
+    }
+
+    /// The `x-ms-retry-after-ms` or `Retry-After` wait sent with the response.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after
+    }
 }
 
 impl std::fmt::Display for UnexpectedHTTPResult {
@@ -96,7 +135,11 @@ impl std::fmt::Display for UnexpectedHTTPResult {
             f,
             "Unexpected HTTP result (expected: {:?}, received: {:?})",
             self.expected, self.received
-        )
+        )?;
+        if let Some(service_error) = &self.service_error {
+            write!(f, ": {}", service_error)?;
+        }
+        Ok(())
     }
 }
 
@@ -160,6 +203,12 @@ quick_error! {
         }
         CRC64Not8BytesLong(len : u64) {
             display("CRC64 length {} bytes instead of 8", len)
+        }
+        ConsistencyMismatch(expected: Consistency, computed: Consistency) {
+            display("consistency mismatch: expected {:?}, computed {:?}", expected, computed)
+        }
+        ETagMismatch(expected: String, received: String) {
+            display("ETag mismatch: expected {}, received {}", expected, received)
         }
          ParseBoolError(err: ParseBoolError) {
             from()
@@ -343,7 +392,7 @@ impl From<failure::Error> for AzureError {
 
 #[inline]
 pub async fn extract_status_headers_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
 ) -> Result<(hyper::StatusCode, hyper::HeaderMap, body::Bytes), AzureError> {
     let res = resp.await?;
     let (head, body) = res.into_parts();
@@ -356,24 +405,23 @@ pub async fn extract_status_headers_and_body(
 
 #[inline]
 pub async fn check_status_extract_headers_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<(hyper::HeaderMap, body::Bytes), AzureError> {
     let (status, headers, body) = extract_status_headers_and_body(resp).await?;
     if status == expected_status_code {
         Ok((headers, body))
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status_code,
-            status,
-            str::from_utf8(&body)?,
-        )))
+        Err(AzureError::UnexpectedHTTPResult(
+            UnexpectedHTTPResult::new(expected_status_code, status, str::from_utf8(&body)?)
+                .with_headers(&headers),
+        ))
     }
 }
 
 #[inline]
 pub async fn check_status_extract_headers_and_body_as_string(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<(hyper::HeaderMap, String), AzureError> {
     let (headers, body) = check_status_extract_headers_and_body(resp, expected_status_code).await?;
@@ -383,7 +431,7 @@ pub async fn check_status_extract_headers_and_body_as_string(
 
 #[inline]
 pub async fn extract_status_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
 ) -> Result<(StatusCode, String), AzureError> {
     let res = resp.await?;
     let status = res.status();
@@ -393,7 +441,7 @@ pub async fn extract_status_and_body(
 
 #[inline]
 pub async fn extract_location_status_and_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
 ) -> Result<(http::StatusCode, String, String), AzureError> {
     let res = resp.await?;
     let status = res.status();
@@ -407,18 +455,17 @@ pub async fn extract_location_status_and_body(
 
 #[inline]
 pub async fn check_status_extract_body(
-    resp: hyper::client::ResponseFuture,
+    resp: ResponseFuture,
     expected_status_code: hyper::StatusCode,
 ) -> Result<String, AzureError> {
-    let (status, body) = extract_status_and_body(resp).await?;
+    let (status, headers, body) = extract_status_headers_and_body(resp).await?;
+    let body = str::from_utf8(&body)?;
     if status == expected_status_code {
-        Ok(body)
+        Ok(body.to_owned())
     } else {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status_code,
-            status,
-            &body,
-        )))
+        Err(AzureError::UnexpectedHTTPResult(
+            UnexpectedHTTPResult::new(expected_status_code, status, body).with_headers(&headers),
+        ))
     }
 }
 
@@ -426,17 +473,17 @@ pub async fn check_status_extract_body_2(
     resp: hyper::Response<Body>,
     expected_status: StatusCode,
 ) -> Result<String, AzureError> {
-    let received_status = resp.status();
+    let (head, body) = resp.into_parts();
+    let received_status = head.status;
 
-    let body = body::to_bytes(resp.into_body()).await?;
+    let body = body::to_bytes(body).await?;
     let s = String::from_utf8(body.to_vec())?;
     debug!("body: {}", s);
     if received_status != expected_status {
-        Err(AzureError::UnexpectedHTTPResult(UnexpectedHTTPResult::new(
-            expected_status,
-            received_status,
-            &s,
-        )))
+        Err(AzureError::UnexpectedHTTPResult(
+            UnexpectedHTTPResult::new(expected_status, received_status, &s)
+                .with_headers(&head.headers),
+        ))
     } else {
         Ok(s)
     }
@@ -472,6 +519,44 @@ mod test {
         error_generator().map_err(sync_fn).unwrap();
     }
 
+    #[test]
+    fn test_unexpected_http_result_service_error() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <Error><Code>ContainerAlreadyExists</Code>\
+            <Message>The specified container already exists.</Message></Error>";
+        let mut headers = HeaderMap::new();
+        headers.insert(crate::headers::REQUEST_ID, "a6b9a1a0".parse().unwrap());
+
+        let result = UnexpectedHTTPResult::new(StatusCode::CREATED, StatusCode::CONFLICT, body)
+            .with_headers(&headers);
+        assert_eq!(
+            result.error_code(),
+            Some(&ServiceErrorCode::ContainerAlreadyExists)
+        );
+        assert_eq!(
+            result.service_error().unwrap().request_id(),
+            Some("a6b9a1a0")
+        );
+        assert_eq!(
+            result.to_string(),
+            "Unexpected HTTP result (expected: [201], received: 409): \
+            ContainerAlreadyExists: The specified container already exists."
+        );
+    }
+
+    #[test]
+    fn test_unexpected_http_result_retry_after() {
+        let result = UnexpectedHTTPResult::new(StatusCode::OK, StatusCode::SERVICE_UNAVAILABLE, "");
+        assert_eq!(result.retry_after(), None);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(crate::headers::RETRY_AFTER_MS, "1500".parse().unwrap());
+        assert_eq!(
+            result.with_headers(&headers).retry_after(),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
     // This does not compile
     //#[test]
     //fn test_not_send() {
@@ -4,6 +4,7 @@ pub const LEASE_ID: &str = "x-ms-lease-id"; //=> [LeaseId] }
 pub const CLIENT_REQUEST_ID: &str = "x-ms-client-request-id"; //=> [String] }
 pub const BLOB_PUBLIC_ACCESS: &str = "x-ms-blob-public-access"; // [PublicAccess]
 pub const REQUEST_ID: &str = "x-ms-request-id"; //=> [String] }
+pub const ERROR_CODE: &str = "x-ms-error-code";
 pub const LEASE_STATUS: &str = "x-ms-lease-status"; //=> [LeaseStatus] }
 pub const LEASE_STATE: &str = "x-ms-lease-state"; //=> [LeaseState] }
 pub const LEASE_DURATION: &str = "x-ms-lease-duration"; //=> [LeaseDuration] }
@@ -16,6 +17,7 @@ pub const PROPOSED_LEASE_ID: &str = "x-ms-proposed-lease-id"; //=> [LeaseId] }
 pub const LEASE_TIME: &str = "x-ms-lease-time";
 pub const CREATION_TIME: &str = "x-ms-creation-time";
 pub const COPY_ID: &str = "x-ms-copy-id";
+pub const COPY_ACTION: &str = "x-ms-copy-action";
 pub const COPY_STATUS_DESCRIPTION: &str = "x-ms-copy-status-description";
 pub const COPY_COMPLETION_TIME: &str = "x-ms-copy-completion-time";
 pub const COPY_PROGRESS: &str = "x-ms-copy-progress";
@@ -23,26 +25,57 @@ pub const COPY_SOURCE: &str = "x-ms-copy-source";
 pub const COPY_STATUS: &str = "x-ms-copy-status";
 pub const CONTENT_MD5: &str = "Content-MD5"; //=> [String] }
 pub const SOURCE_CONTENT_MD5: &str = "x-ms-source-content-md5"; //=> [String] }
+pub const SOURCE_RANGE: &str = "x-ms-source-range";
 pub const SERVER_ENCRYPTED: &str = "x-ms-server-encrypted";
 pub const BLOB_TYPE: &str = "x-ms-blob-type";
 pub const CONTENT_CRC64: &str = "x-ms-content-crc64";
 pub const BLOB_CONTENT_LENGTH: &str = "x-ms-blob-content-length";
+pub const BLOB_CONTENT_TYPE: &str = "x-ms-blob-content-type";
+pub const BLOB_CONTENT_ENCODING: &str = "x-ms-blob-content-encoding";
+pub const BLOB_CONTENT_LANGUAGE: &str = "x-ms-blob-content-language";
+pub const BLOB_CONTENT_MD5: &str = "x-ms-blob-content-md5";
 pub const BLOB_ACCESS_TIER: &str = "x-ms-access-tier";
+pub const ACCESS_TIER_INFERRED: &str = "x-ms-access-tier-inferred";
+pub const ACCESS_TIER_CHANGE_TIME: &str = "x-ms-access-tier-change-time";
+pub const ARCHIVE_STATUS: &str = "x-ms-archive-status";
+pub const REHYDRATE_PRIORITY: &str = "x-ms-rehydrate-priority";
+pub const TAGS: &str = "x-ms-tags";
+pub const IF_TAGS: &str = "x-ms-if-tags";
 pub const BLOB_SEQUENCE_NUMBER: &str = "x-ms-blob-sequence-number";
 pub const IF_SEQUENCE_NUMBER_LE: &str = "x-ms-if-sequence-number-le";
 pub const IF_SEQUENCE_NUMBER_LT: &str = "x-ms-if-sequence-number-lt";
 pub const IF_SEQUENCE_NUMBER_EQ: &str = "x-ms-if-sequence-number-eq";
 pub const PAGE_WRITE: &str = "x-ms-page-write";
+pub const SEQUENCE_NUMBER_ACTION: &str = "x-ms-sequence-number-action";
 pub const REQUEST_SERVER_ENCRYPTED: &str = "x-ms-request-server-encrypted";
 pub const DELETE_TYPE_PERMANENT: &str = "x-ms-delete-type-permanent";
 pub const DELETE_SNAPSHOTS: &str = "x-ms-delete-snapshots";
+pub const SNAPSHOT: &str = "x-ms-snapshot";
 pub const SKU_NAME: &str = "x-ms-sku-name";
 pub const ACCOUNT_KIND: &str = "x-ms-account-kind";
 pub const APPEND_POSITION: &str = "x-ms-blob-condition-appendpos";
 pub const CACHE_CONTROL: &str = "x-ms-blob-cache-control";
 pub const CONTENT_DISPOSITION: &str = "x-ms-blob-content-disposition";
 pub const ACTIVITY_ID: &str = "x-ms-activity-id";
+pub const RETRY_AFTER_MS: &str = "x-ms-retry-after-ms";
 pub const HEADER_CONTINUATION: &str = "x-ms-continuation";
 pub const SESSION_TOKEN: &str = "x-ms-session-token";
 pub const REQUIRES_SYNC: &str = "x-ms-requires-sync";
 pub const VERSION: &str = "x-ms-version";
+pub const POP_RECEIPT: &str = "x-ms-popreceipt";
+pub const TIME_NEXT_VISIBLE: &str = "x-ms-time-next-visible";
+pub const SHARE_QUOTA: &str = "x-ms-share-quota";
+pub const FILE_TYPE: &str = "x-ms-type";
+pub const FILE_CONTENT_LENGTH: &str = "x-ms-content-length";
+pub const FILE_WRITE: &str = "x-ms-write";
+pub const FILE_PERMISSION: &str = "x-ms-file-permission";
+pub const FILE_ATTRIBUTES: &str = "x-ms-file-attributes";
+pub const FILE_CREATION_TIME: &str = "x-ms-file-creation-time";
+pub const FILE_LAST_WRITE_TIME: &str = "x-ms-file-last-write-time";
+pub const RENAME_SOURCE: &str = "x-ms-rename-source";
+pub const OWNER: &str = "x-ms-owner";
+pub const GROUP: &str = "x-ms-group";
+pub const PERMISSIONS: &str = "x-ms-permissions";
+pub const ACL: &str = "x-ms-acl";
+pub const UMASK: &str = "x-ms-umask";
+pub const NAMESPACE_ENABLED: &str = "x-ms-namespace-enabled";
@@ -0,0 +1,78 @@
+use crate::errors::AzureError;
+use futures::future::Future;
+use hyper::client::connect::Connect;
+use hyper::{Body, Request, Response};
+use hyper_rustls::HttpsConnector;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::sync::Arc;
+
+pub type ResponseFuture = Pin<Box<dyn Future<Output = Result<Response<Body>, AzureError>> + Send>>;
+
+/// The transport used by every client to send its requests.
+///
+/// Implement this trait to use a custom connector, a proxy or an in-memory
+/// mock in place of the default hyper client.
+pub trait HttpClient: Debug + Send + Sync {
+    fn request(&self, request: Request<Body>) -> ResponseFuture;
+}
+
+impl<C> HttpClient for hyper::Client<C, Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        let response = hyper::Client::request(self, request);
+        Box::pin(async move { Ok(response.await?) })
+    }
+}
+
+impl<T> HttpClient for Arc<T>
+where
+    T: HttpClient + ?Sized,
+{
+    #[inline]
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        self.as_ref().request(request)
+    }
+}
+
+/// Returns the default transport: a hyper client over rustls.
+pub fn default_http_client() -> Arc<dyn HttpClient> {
+    Arc::new(hyper::Client::builder().build::<_, Body>(HttpsConnector::new()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use futures::executor::block_on;
+    use hyper::StatusCode;
+
+    #[derive(Debug)]
+    struct MockHttpClient;
+
+    impl HttpClient for MockHttpClient {
+        fn request(&self, request: Request<Body>) -> ResponseFuture {
+            let response = Response::builder()
+                .status(StatusCode::OK)
+                .body(Body::from(request.uri().to_string()))
+                .unwrap();
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[test]
+    fn mock_http_client() {
+        let http_client: Arc<dyn HttpClient> = Arc::new(MockHttpClient);
+        let request = Request::get("https://example.com/test")
+            .body(Body::empty())
+            .unwrap();
+
+        let body = block_on(crate::errors::check_status_extract_body(
+            http_client.request(request),
+            StatusCode::OK,
+        ))
+        .unwrap();
+        assert_eq!(body, "https://example.com/test");
+    }
+}
@@ -18,11 +18,15 @@ pub mod util;
 use crate::util::HeaderMapExt;
 use std::fmt::Debug;
 pub mod ba512_range;
+pub mod crc64;
 use base64::encode;
 pub mod modify_conditions;
 use self::modify_conditions::{IfMatchCondition, IfSinceCondition, SequenceNumberCondition};
 pub mod headers;
+pub mod http_client;
 pub mod range;
+pub mod retry_policy;
+pub mod service_error;
 use self::headers::*;
 use hyper::header::{
     HeaderName, CONTENT_ENCODING, CONTENT_LANGUAGE, CONTENT_LENGTH, CONTENT_TYPE, DATE, ETAG,
@@ -39,9 +43,10 @@ use std::collections::HashMap;
 mod stored_access_policy;
 pub use self::stored_access_policy::{StoredAccessPolicy, StoredAccessPolicyList};
 pub mod prelude;
-use chrono::{DateTime, Utc};
+use crate::http_client::HttpClient;
+use chrono::{DateTime, Timelike, Utc};
 use http::status::StatusCode;
-use hyper::{Body, Client, Request};
+use hyper::{Body, Request};
 
 #[macro_export]
 macro_rules! response_from_headers {
@@ -88,6 +93,26 @@ impl NotAssigned for No {}
 
 create_enum!(DeleteSnapshotsMethod, (Include, "include"), (Only, "only"));
 
+create_enum!(
+    AccessTier,
+    (P4, "P4"),
+    (P6, "P6"),
+    (P10, "P10"),
+    (P15, "P15"),
+    (P20, "P20"),
+    (P30, "P30"),
+    (P40, "P40"),
+    (P50, "P50"),
+    (P60, "P60"),
+    (P70, "P70"),
+    (P80, "P80"),
+    (Hot, "Hot"),
+    (Cool, "Cool"),
+    (Archive, "Archive")
+);
+
+create_enum!(RehydratePriority, (High, "High"), (Standard, "Standard"));
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Consistency {
     Md5([u8; 16]),
@@ -337,18 +362,47 @@ pub trait ContentLanguageOption<'a> {
     }
 }
 
-pub trait AccessTierSupport<'a> {
+pub trait AccessTierSupport {
     type O;
-    fn with_access_tier(self, access_tier: &'a str) -> Self::O;
+    fn with_access_tier(self, access_tier: AccessTier) -> Self::O;
 }
 
-pub trait AccessTierOption<'a> {
-    fn access_tier(&self) -> Option<&'a str>;
+pub trait AccessTierOption {
+    fn access_tier(&self) -> Option<AccessTier>;
 
     #[must_use]
     fn add_header(&self, mut builder: Builder) -> Builder {
         if let Some(access_tier) = self.access_tier() {
-            builder = builder.header(BLOB_ACCESS_TIER, access_tier);
+            let s: &'static str = access_tier.into();
+            builder = builder.header(BLOB_ACCESS_TIER, s);
+        }
+        builder
+    }
+}
+
+pub trait AccessTierRequired {
+    fn access_tier(&self) -> AccessTier;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        let s: &'static str = self.access_tier().into();
+        builder.header(BLOB_ACCESS_TIER, s)
+    }
+}
+
+pub trait RehydratePrioritySupport {
+    type O;
+    fn with_rehydrate_priority(self, rehydrate_priority: RehydratePriority) -> Self::O;
+}
+
+pub trait RehydratePriorityOption {
+    fn rehydrate_priority(&self) -> Option<RehydratePriority>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(rehydrate_priority) = self.rehydrate_priority() {
+            let s: &'static str = rehydrate_priority.into();
+            builder = builder.header(REHYDRATE_PRIORITY, s);
         }
         builder
     }
@@ -411,11 +465,8 @@ pub trait SnapshotOption {
     fn snapshot(&self) -> Option<DateTime<Utc>>;
 
     fn to_uri_parameter(&self) -> Option<String> {
-        if let Some(ref nm) = self.snapshot() {
-            Some(format!("snapshot={}", nm.to_rfc2822()))
-        } else {
-            None
-        }
+        self.snapshot()
+            .map(|snapshot| format!("snapshot={}", format_snapshot(&snapshot)))
     }
 }
 
@@ -423,10 +474,33 @@ pub trait SnapshotRequired {
     fn snapshot(&self) -> DateTime<Utc>;
 
     fn to_uri_parameter(&self) -> String {
-        format!("snapshot={}", self.snapshot().to_rfc2822())
+        format!("snapshot={}", format_snapshot(&self.snapshot()))
+    }
+}
+
+pub trait PrevSnapshotSupport {
+    type O;
+    fn with_prev_snapshot(self, prev_snapshot: DateTime<Utc>) -> Self::O;
+}
+
+pub trait PrevSnapshotOption {
+    fn prev_snapshot(&self) -> Option<DateTime<Utc>>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.prev_snapshot()
+            .map(|snapshot| format!("prevsnapshot={}", format_snapshot(&snapshot)))
     }
 }
 
+// snapshots are identified by their exact time, with 7 fractional digits
+fn format_snapshot(snapshot: &DateTime<Utc>) -> String {
+    format!(
+        "{}.{:07}Z",
+        snapshot.format("%Y-%m-%dT%H:%M:%S"),
+        snapshot.nanosecond() / 100
+    )
+}
+
 pub trait DelimiterSupport<'a> {
     type O;
     fn with_delimiter(self, delimiter: &'a str) -> Self::O;
@@ -831,6 +905,23 @@ pub trait BA512RangeRequired<'a> {
     }
 }
 
+pub trait SourceRangeSupport<'a> {
+    type O;
+    fn with_source_range(self, _: &'a range::Range) -> Self::O;
+}
+
+pub trait SourceRangeOption<'a> {
+    fn source_range(&self) -> Option<&'a range::Range>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(source_range) = self.source_range() {
+            builder = builder.header(SOURCE_RANGE, &source_range.to_string() as &str);
+        }
+        builder
+    }
+}
+
 pub trait LeaseDurationSupport {
     type O;
     fn with_lease_duration(self, _: i8) -> Self::O;
@@ -921,6 +1012,33 @@ pub trait BlobNameRequired<'a> {
     fn blob_name(&self) -> &'a str;
 }
 
+pub trait QueueNameSupport<'a> {
+    type O;
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O;
+}
+
+pub trait QueueNameRequired<'a> {
+    fn queue_name(&self) -> &'a str;
+}
+
+pub trait ShareNameSupport<'a> {
+    type O;
+    fn with_share_name(self, share_name: &'a str) -> Self::O;
+}
+
+pub trait ShareNameRequired<'a> {
+    fn share_name(&self) -> &'a str;
+}
+
+pub trait FileSystemNameSupport<'a> {
+    type O;
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O;
+}
+
+pub trait FileSystemNameRequired<'a> {
+    fn file_system_name(&self) -> &'a str;
+}
+
 pub fn lease_id_from_headers(headers: &HeaderMap) -> Result<LeaseId, AzureError> {
     let lease_id = headers
         .get_as_str(LEASE_ID)
@@ -1078,6 +1196,16 @@ pub fn account_kind_from_headers(headers: &HeaderMap) -> Result<String, AzureErr
     Ok(account_kind.to_owned())
 }
 
+pub fn snapshot_from_headers(headers: &HeaderMap) -> Result<DateTime<Utc>, AzureError> {
+    let snapshot = headers
+        .get_as_str(SNAPSHOT)
+        .ok_or_else(|| AzureError::HeaderNotFound(SNAPSHOT.to_owned()))?;
+    let snapshot = DateTime::parse_from_rfc3339(snapshot)?.with_timezone(&Utc);
+
+    trace!("snapshot == {:?}", snapshot);
+    Ok(snapshot)
+}
+
 pub fn etag_from_headers_optional(headers: &HeaderMap) -> Result<Option<String>, AzureError> {
     if headers.contains_key(ETAG) {
         Ok(Some(etag_from_headers(headers)?))
@@ -1171,7 +1299,7 @@ pub fn request_server_encrypted_from_headers(headers: &HeaderMap) -> Result<bool
 }
 
 pub async fn perform_http_request(
-    client: &Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
+    client: &dyn HttpClient,
     req: Request<Body>,
     expected_status: StatusCode,
 ) -> Result<String, AzureError> {
@@ -1179,3 +1307,17 @@ pub async fn perform_http_request(
     let res = client.request(req).await?;
     check_status_extract_body_2(res, expected_status).await
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let mut headers = HeaderMap::new();
+        headers.insert(SNAPSHOT, "2011-03-09T01:42:34.9360000Z".parse().unwrap());
+
+        let snapshot = snapshot_from_headers(&headers).unwrap();
+        assert_eq!(format_snapshot(&snapshot), "2011-03-09T01:42:34.9360000Z");
+    }
+}
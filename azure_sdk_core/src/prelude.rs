@@ -2,29 +2,33 @@ pub use crate::ba512_range::BA512Range;
 pub use crate::modify_conditions::{IfMatchCondition, IfSinceCondition, SequenceNumberCondition};
 pub use crate::range::Range;
 pub use crate::{
-    AccessTierOption, AccessTierSupport, ActivityIdOption, ActivityIdSupport, AppendPositionOption,
-    AppendPositionSupport, BA512RangeOption, BA512RangeRequired, BA512RangeSupport,
-    BlobNameRequired, BlobNameSupport, BlockIdRequired, BlockIdSupport, BodyRequired, BodySupport,
-    CacheControlOption, CacheControlSupport, ChunkSizeOption, ChunkSizeSupport,
-    ClientRequestIdOption, ClientRequestIdSupport, ContainerNameRequired, ContainerNameSupport,
-    ContentDispositionOption, ContentDispositionSupport, ContentEncodingOption,
-    ContentEncodingSupport, ContentLanguageOption, ContentLanguageSupport, ContentLengthOption,
-    ContentLengthRequired, ContentLengthSupport, ContentMD5Option, ContentMD5Support,
-    ContentTypeOption, ContentTypeRequired, ContentTypeSupport, DeleteSnapshotsMethod,
-    DeleteSnapshotsMethodSupport, DelimiterOption, DelimiterSupport, IfMatchConditionOption,
-    IfMatchConditionSupport, IfModifiedSinceOption, IfModifiedSinceSupport, IfSinceConditionOption,
-    IfSinceConditionSupport, IncludeCopyOption, IncludeCopySupport, IncludeDeletedOption,
-    IncludeDeletedSupport, IncludeListOptions, IncludeMetadataOption, IncludeMetadataSupport,
-    IncludeSnapshotsOption, IncludeSnapshotsSupport, IncludeUncommittedBlobsOption,
-    IncludeUncommittedBlobsSupport, IsSynchronousOption, IsSynchronousSupport,
-    LeaseBreakPeriodOption, LeaseBreakPeriodRequired, LeaseBreakPeriodSupport,
-    LeaseDurationRequired, LeaseDurationSupport, LeaseIdOption, LeaseIdRequired, LeaseIdSupport,
-    MaxResultsOption, MaxResultsSupport, MetadataOption, MetadataSupport, NextMarkerOption,
-    NextMarkerSupport, PageBlobLengthRequired, PageBlobLengthSupport, PrefixOption, PrefixSupport,
-    ProposedLeaseIdOption, ProposedLeaseIdRequired, ProposedLeaseIdSupport, RangeOption,
-    RangeRequired, RangeSupport, SequenceNumberConditionOption, SequenceNumberConditionSupport,
-    SequenceNumberOption, SequenceNumberSupport, SnapshotOption, SnapshotRequired, SnapshotSupport,
-    SourceContentMD5Option, SourceContentMD5Support, SourceUrlRequired, SourceUrlSupport,
-    StoredAccessPolicy, StoredAccessPolicyList, TimeoutOption, TimeoutSupport, UserAgentOption,
-    UserAgentSupport,
+    AccessTier, AccessTierOption, AccessTierRequired, AccessTierSupport, ActivityIdOption,
+    ActivityIdSupport, AppendPositionOption, AppendPositionSupport, BA512RangeOption,
+    BA512RangeRequired, BA512RangeSupport, BlobNameRequired, BlobNameSupport, BlockIdRequired,
+    BlockIdSupport, BodyRequired, BodySupport, CacheControlOption, CacheControlSupport,
+    ChunkSizeOption, ChunkSizeSupport, ClientRequestIdOption, ClientRequestIdSupport,
+    ContainerNameRequired, ContainerNameSupport, ContentDispositionOption,
+    ContentDispositionSupport, ContentEncodingOption, ContentEncodingSupport,
+    ContentLanguageOption, ContentLanguageSupport, ContentLengthOption, ContentLengthRequired,
+    ContentLengthSupport, ContentMD5Option, ContentMD5Support, ContentTypeOption,
+    ContentTypeRequired, ContentTypeSupport, DeleteSnapshotsMethod, DeleteSnapshotsMethodSupport,
+    DelimiterOption, DelimiterSupport, FileSystemNameRequired, FileSystemNameSupport,
+    IfMatchConditionOption, IfMatchConditionSupport, IfModifiedSinceOption, IfModifiedSinceSupport,
+    IfSinceConditionOption, IfSinceConditionSupport, IncludeCopyOption, IncludeCopySupport,
+    IncludeDeletedOption, IncludeDeletedSupport, IncludeListOptions, IncludeMetadataOption,
+    IncludeMetadataSupport, IncludeSnapshotsOption, IncludeSnapshotsSupport,
+    IncludeUncommittedBlobsOption, IncludeUncommittedBlobsSupport, IsSynchronousOption,
+    IsSynchronousSupport, LeaseBreakPeriodOption, LeaseBreakPeriodRequired,
+    LeaseBreakPeriodSupport, LeaseDurationRequired, LeaseDurationSupport, LeaseIdOption,
+    LeaseIdRequired, LeaseIdSupport, MaxResultsOption, MaxResultsSupport, MetadataOption,
+    MetadataSupport, NextMarkerOption, NextMarkerSupport, PageBlobLengthRequired,
+    PageBlobLengthSupport, PrefixOption, PrefixSupport, PrevSnapshotOption, PrevSnapshotSupport,
+    ProposedLeaseIdOption, ProposedLeaseIdRequired, ProposedLeaseIdSupport, QueueNameRequired,
+    QueueNameSupport, RangeOption, RangeRequired, RangeSupport, RehydratePriority,
+    RehydratePriorityOption, RehydratePrioritySupport, SequenceNumberConditionOption,
+    SequenceNumberConditionSupport, SequenceNumberOption, SequenceNumberSupport, ShareNameRequired,
+    ShareNameSupport, SnapshotOption, SnapshotRequired, SnapshotSupport, SourceContentMD5Option,
+    SourceContentMD5Support, SourceRangeOption, SourceRangeSupport, SourceUrlRequired,
+    SourceUrlSupport, StoredAccessPolicy, StoredAccessPolicyList, TimeoutOption, TimeoutSupport,
+    UserAgentOption, UserAgentSupport,
 };
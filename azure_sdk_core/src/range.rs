@@ -96,6 +96,36 @@ impl fmt::Display for Range {
     }
 }
 
+/// The `Content-Range` header of a ranged response, e.g. `bytes 0-1023/4096`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ContentRange {
+    pub range: Range,
+    pub total_length: u64,
+}
+
+impl FromStr for ContentRange {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<ContentRange, ParseError> {
+        let s = s.trim_start_matches("bytes").trim();
+        let mut v = s.split('/');
+        let (range, total_length) = match (v.next(), v.next(), v.next()) {
+            (Some(range), Some(total_length), None) => (range, total_length),
+            _ => return Err(ParseError::SplitNotFound),
+        };
+
+        let mut v = range.split('-');
+        let (start, end) = match (v.next(), v.next(), v.next()) {
+            (Some(start), Some(end), None) => (start.parse::<u64>()?, end.parse::<u64>()? + 1),
+            _ => return Err(ParseError::SplitNotFound),
+        };
+
+        Ok(ContentRange {
+            range: Range::new(start, end),
+            total_length: total_length.parse::<u64>()?,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -131,4 +161,13 @@ mod test {
 
         assert_eq!(txt, "bytes=100-500");
     }
+
+    #[test]
+    fn test_content_range_parse() {
+        let content_range = "bytes 1024-2047/4096".parse::<ContentRange>().unwrap();
+
+        assert_eq!(content_range.range, Range::new(1024, 2048));
+        assert_eq!(content_range.total_length, 4096);
+        assert!("bytes 1024/4096".parse::<ContentRange>().is_err());
+    }
 }
@@ -0,0 +1,436 @@
+use crate::headers::RETRY_AFTER_MS;
+use crate::http_client::{HttpClient, ResponseFuture};
+use crate::util::HeaderMapExt;
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, Method, StatusCode};
+use hyper::header::RETRY_AFTER;
+use hyper::{body, Body, Request};
+use rand::Rng;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+const DEFAULT_MAX_ELAPSED_TIME: Duration = Duration::from_secs(120);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RetryMode {
+    Fixed,
+    Exponential,
+}
+
+/// Describes if and how a failed request is retried.
+///
+/// Only safe and idempotent requests (GET, HEAD, OPTIONS, PUT and DELETE) are
+/// retried, and only for transport errors or for the 408, 429, 500, 502, 503 and
+/// 504 status codes. Requests carrying the `NonRetriable` extension are never
+/// retried. A `x-ms-retry-after-ms` or `Retry-After` header sent by the
+/// service takes precedence over the computed delay.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    mode: RetryMode,
+    delay: Duration,
+    max_delay: Duration,
+    max_retries: u32,
+    max_elapsed_time: Duration,
+}
+
+impl RetryPolicy {
+    /// Never retries.
+    pub fn no_retry() -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Fixed,
+            delay: Duration::from_secs(0),
+            max_delay: Duration::from_secs(0),
+            max_retries: 0,
+            max_elapsed_time: Duration::from_secs(0),
+        }
+    }
+
+    /// Waits `delay` between every attempt.
+    pub fn fixed(delay: Duration) -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Fixed,
+            delay,
+            max_delay: delay,
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_elapsed_time: DEFAULT_MAX_ELAPSED_TIME,
+        }
+    }
+
+    /// Doubles the wait after every attempt, starting from `initial_delay`.
+    /// A random jitter of up to half the delay is subtracted from each wait.
+    pub fn exponential(initial_delay: Duration) -> RetryPolicy {
+        RetryPolicy {
+            mode: RetryMode::Exponential,
+            delay: initial_delay,
+            max_delay: DEFAULT_MAX_DELAY,
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_elapsed_time: DEFAULT_MAX_ELAPSED_TIME,
+        }
+    }
+
+    pub fn with_max_retries(self, max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            ..self
+        }
+    }
+
+    pub fn with_max_delay(self, max_delay: Duration) -> RetryPolicy {
+        RetryPolicy { max_delay, ..self }
+    }
+
+    pub fn with_max_elapsed_time(self, max_elapsed_time: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_elapsed_time,
+            ..self
+        }
+    }
+
+    pub fn mode(&self) -> RetryMode {
+        self.mode
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn max_elapsed_time(&self) -> Duration {
+        self.max_elapsed_time
+    }
+
+    /// The wait before the given retry (starting from 0), without jitter.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = match self.mode {
+            RetryMode::Fixed => self.delay,
+            RetryMode::Exponential => self
+                .delay
+                .checked_mul(2u32.saturating_pow(retry))
+                .unwrap_or(self.max_delay),
+        };
+        std::cmp::min(delay, self.max_delay)
+    }
+
+    /// The wait before the given retry (starting from 0), jitter included.
+    pub fn delay_with_jitter(&self, retry: u32) -> Duration {
+        let delay = self.delay(retry);
+        match self.mode {
+            RetryMode::Fixed => delay,
+            RetryMode::Exponential => delay.mul_f64(rand::thread_rng().gen_range(0.5, 1.0)),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::exponential(Duration::from_millis(800))
+    }
+}
+
+/// Request extension marking a request that must be sent only once, whatever
+/// its method. Used by the operations that consume what they return (e.g.
+/// receiving and deleting a message) and by the non idempotent PUTs (appending
+/// a block, renaming a path), which would lose or duplicate data if replayed:
+/// `request_builder.extension(NonRetriable)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonRetriable;
+
+#[inline]
+pub fn is_retriable_method(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+    )
+}
+
+#[inline]
+pub fn is_retriable_request<B>(request: &Request<B>) -> bool {
+    is_retriable_method(request.method()) && request.extensions().get::<NonRetriable>().is_none()
+}
+
+#[inline]
+pub fn is_retriable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::REQUEST_TIMEOUT
+            | StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Extracts the wait requested by the service, either from the
+/// `x-ms-retry-after-ms` header or from the `Retry-After` header.
+pub fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(ms) = headers
+        .get_as_str(RETRY_AFTER_MS)
+        .and_then(|ms| ms.trim().parse::<f64>().ok())
+    {
+        return Some(Duration::from_secs_f64(ms.max(0.0) / 1000.0));
+    }
+
+    let retry_after = headers.get_as_str(RETRY_AFTER.as_str())?.trim();
+    if let Ok(seconds) = retry_after.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(retry_after).ok()?;
+    Some(
+        date.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_else(|_| Duration::from_secs(0)),
+    )
+}
+
+/// A transport that retries the requests sent through the wrapped one
+/// according to a `RetryPolicy`.
+#[derive(Debug, Clone)]
+pub struct RetryHttpClient {
+    http_client: Arc<dyn HttpClient>,
+    retry_policy: RetryPolicy,
+}
+
+impl RetryHttpClient {
+    pub fn new(http_client: Arc<dyn HttpClient>, retry_policy: RetryPolicy) -> RetryHttpClient {
+        RetryHttpClient {
+            http_client,
+            retry_policy,
+        }
+    }
+
+    pub fn http_client(&self) -> &Arc<dyn HttpClient> {
+        &self.http_client
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns a copy that sends its requests through `http_client`.
+    pub fn with_http_client(&self, http_client: Arc<dyn HttpClient>) -> RetryHttpClient {
+        RetryHttpClient::new(http_client, self.retry_policy.clone())
+    }
+
+    /// Returns a copy that retries its requests according to `retry_policy`.
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> RetryHttpClient {
+        RetryHttpClient::new(self.http_client.clone(), retry_policy)
+    }
+}
+
+impl HttpClient for RetryHttpClient {
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        if self.retry_policy.max_retries == 0 || !is_retriable_request(&request) {
+            return self.http_client.request(request);
+        }
+
+        let http_client = self.http_client.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        Box::pin(async move {
+            let start = Instant::now();
+            let (parts, body) = request.into_parts();
+            // the body must be kept around to be sent again
+            let body = body::to_bytes(body).await?;
+
+            let mut retry = 0;
+            loop {
+                let mut request = Request::new(Body::from(body.clone()));
+                *request.method_mut() = parts.method.clone();
+                *request.uri_mut() = parts.uri.clone();
+                *request.version_mut() = parts.version;
+                *request.headers_mut() = parts.headers.clone();
+
+                let result = http_client.request(request).await;
+                let retry_after = match &result {
+                    Ok(response) if is_retriable_status(response.status()) => {
+                        retry_after_from_headers(response.headers())
+                    }
+                    Ok(_) => return result,
+                    Err(_) => None,
+                };
+
+                let delay = retry_after.unwrap_or_else(|| retry_policy.delay_with_jitter(retry));
+                if retry >= retry_policy.max_retries
+                    || start.elapsed() + delay > retry_policy.max_elapsed_time
+                {
+                    return result;
+                }
+
+                debug!(
+                    "retrying {} {} in {:?} (retry {} of {})",
+                    parts.method,
+                    parts.uri,
+                    delay,
+                    retry + 1,
+                    retry_policy.max_retries
+                );
+                if delay > Duration::from_secs(0) {
+                    tokio::time::delay_for(delay).await;
+                }
+                retry += 1;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::errors::{check_status_extract_body, AzureError};
+    use futures::executor::block_on;
+    use hyper::Response;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct MockHttpClient {
+        responses: Mutex<Vec<(StatusCode, Option<&'static str>)>>,
+        requests: Mutex<Vec<Method>>,
+    }
+
+    impl MockHttpClient {
+        fn new(responses: Vec<(StatusCode, Option<&'static str>)>) -> MockHttpClient {
+            MockHttpClient {
+                responses: Mutex::new(responses),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn request(&self, request: Request<Body>) -> ResponseFuture {
+            self.requests.lock().unwrap().push(request.method().clone());
+
+            let (status, retry_after_ms) = self.responses.lock().unwrap().remove(0);
+            let mut response = Response::builder().status(status);
+            if let Some(retry_after_ms) = retry_after_ms {
+                response = response.header(RETRY_AFTER_MS, retry_after_ms);
+            }
+            let response = response.body(Body::empty()).unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    fn send_request(
+        mock: Arc<MockHttpClient>,
+        retry_policy: RetryPolicy,
+        request: Request<Body>,
+    ) -> Result<String, AzureError> {
+        let http_client = RetryHttpClient::new(mock, retry_policy);
+        block_on(check_status_extract_body(
+            http_client.request(request),
+            StatusCode::OK,
+        ))
+    }
+
+    fn send(
+        mock: Arc<MockHttpClient>,
+        retry_policy: RetryPolicy,
+        method: Method,
+    ) -> Result<String, AzureError> {
+        let request = Request::builder()
+            .method(method)
+            .uri("https://example.com")
+            .body(Body::from("body"))
+            .unwrap();
+        send_request(mock, retry_policy, request)
+    }
+
+    #[test]
+    fn exponential_delay() {
+        let retry_policy =
+            RetryPolicy::exponential(Duration::from_secs(1)).with_max_delay(Duration::from_secs(5));
+        assert_eq!(retry_policy.delay(0), Duration::from_secs(1));
+        assert_eq!(retry_policy.delay(1), Duration::from_secs(2));
+        assert_eq!(retry_policy.delay(2), Duration::from_secs(4));
+        assert_eq!(retry_policy.delay(3), Duration::from_secs(5));
+        assert_eq!(retry_policy.delay(100), Duration::from_secs(5));
+
+        for retry in 0..4 {
+            let delay = retry_policy.delay_with_jitter(retry);
+            assert!(delay <= retry_policy.delay(retry));
+            assert!(delay >= retry_policy.delay(retry) / 2);
+        }
+    }
+
+    #[test]
+    fn retry_after() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after_from_headers(&headers), None);
+
+        headers.insert(RETRY_AFTER, "3".parse().unwrap());
+        assert_eq!(
+            retry_after_from_headers(&headers),
+            Some(Duration::from_secs(3))
+        );
+
+        headers.insert(RETRY_AFTER_MS, "150".parse().unwrap());
+        assert_eq!(
+            retry_after_from_headers(&headers),
+            Some(Duration::from_millis(150))
+        );
+    }
+
+    #[test]
+    fn retries_until_success() {
+        let mock = Arc::new(MockHttpClient::new(vec![
+            (StatusCode::SERVICE_UNAVAILABLE, Some("0")),
+            (StatusCode::TOO_MANY_REQUESTS, Some("0")),
+            (StatusCode::OK, None),
+        ]));
+        let retry_policy = RetryPolicy::fixed(Duration::from_secs(0));
+
+        assert!(send(mock.clone(), retry_policy, Method::GET).is_ok());
+        assert_eq!(mock.requests.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let mock = Arc::new(MockHttpClient::new(vec![
+            (StatusCode::SERVICE_UNAVAILABLE, Some("0")),
+            (StatusCode::SERVICE_UNAVAILABLE, Some("0")),
+            (StatusCode::OK, None),
+        ]));
+        let retry_policy = RetryPolicy::fixed(Duration::from_secs(0)).with_max_retries(1);
+
+        match send(mock.clone(), retry_policy, Method::DELETE) {
+            Err(AzureError::UnexpectedHTTPResult(result)) => {
+                assert_eq!(result.status_code(), StatusCode::SERVICE_UNAVAILABLE)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(mock.requests.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn does_not_retry_post() {
+        let mock = Arc::new(MockHttpClient::new(vec![
+            (StatusCode::SERVICE_UNAVAILABLE, Some("0")),
+            (StatusCode::OK, None),
+        ]));
+        let retry_policy = RetryPolicy::fixed(Duration::from_secs(0));
+
+        assert!(send(mock.clone(), retry_policy, Method::POST).is_err());
+        assert_eq!(mock.requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn does_not_retry_non_retriable_request() {
+        let mock = Arc::new(MockHttpClient::new(vec![
+            (StatusCode::SERVICE_UNAVAILABLE, Some("0")),
+            (StatusCode::OK, None),
+        ]));
+        let retry_policy = RetryPolicy::fixed(Duration::from_secs(0));
+        let request = Request::builder()
+            .method(Method::DELETE)
+            .uri("https://example.com/messages/head")
+            .extension(NonRetriable)
+            .body(Body::empty())
+            .unwrap();
+
+        assert!(send_request(mock.clone(), retry_policy, request).is_err());
+        assert_eq!(mock.requests.lock().unwrap().len(), 1);
+    }
+}
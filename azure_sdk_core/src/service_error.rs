@@ -0,0 +1,303 @@
+use crate::headers::{ACTIVITY_ID, ERROR_CODE, REQUEST_ID};
+use crate::parsing::cast_optional;
+use crate::util::HeaderMapExt;
+use http::HeaderMap;
+use xml::Element;
+
+macro_rules! service_error_codes {
+    ($($na:ident),*) => (
+        /// The error codes returned by the storage and Cosmos services.
+        /// Codes not known by this crate are kept in `Other`.
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum ServiceErrorCode {
+            $(
+                $na,
+            )*
+            Other(String),
+        }
+
+        impl ServiceErrorCode {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(
+                        ServiceErrorCode::$na => stringify!($na),
+                    )*
+                    ServiceErrorCode::Other(code) => code,
+                }
+            }
+        }
+
+        impl From<&str> for ServiceErrorCode {
+            fn from(s: &str) -> ServiceErrorCode {
+                match s {
+                    $(
+                        stringify!($na) => ServiceErrorCode::$na,
+                    )*
+                    _ => ServiceErrorCode::Other(s.to_owned()),
+                }
+            }
+        }
+    )
+}
+
+service_error_codes!(
+    // common storage codes
+    AccountIsDisabled,
+    AuthenticationFailed,
+    AuthorizationFailure,
+    ConditionHeadersNotSupported,
+    ConditionNotMet,
+    InsufficientAccountPermissions,
+    InternalError,
+    InvalidAuthenticationInfo,
+    InvalidHeaderValue,
+    InvalidInput,
+    InvalidMd5,
+    InvalidQueryParameterValue,
+    InvalidRange,
+    InvalidResourceName,
+    InvalidUri,
+    InvalidXmlDocument,
+    Md5Mismatch,
+    MissingRequiredHeader,
+    OperationTimedOut,
+    OutOfRangeInput,
+    RequestBodyTooLarge,
+    ResourceAlreadyExists,
+    ResourceNotFound,
+    ResourceTypeMismatch,
+    ServerBusy,
+    UnsupportedHeader,
+    // blob service
+    BlobAlreadyExists,
+    BlobArchived,
+    BlobBeingRehydrated,
+    BlobNotFound,
+    CannotVerifyCopySource,
+    ContainerAlreadyExists,
+    ContainerBeingDeleted,
+    ContainerDisabled,
+    ContainerNotFound,
+    InvalidBlobOrBlock,
+    InvalidBlobTier,
+    InvalidBlockId,
+    InvalidBlockList,
+    LeaseAlreadyPresent,
+    LeaseIdMismatchWithBlobOperation,
+    LeaseIdMismatchWithContainerOperation,
+    LeaseIdMismatchWithLeaseOperation,
+    LeaseIdMissing,
+    LeaseIsBrokenAndCannotBeRenewed,
+    LeaseLost,
+    LeaseNotPresentWithBlobOperation,
+    LeaseNotPresentWithContainerOperation,
+    LeaseNotPresentWithLeaseOperation,
+    NoPendingCopyOperation,
+    PendingCopyOperation,
+    SnapshotsPresent,
+    // queue service
+    MessageNotFound,
+    MessageTooLarge,
+    PopReceiptMismatch,
+    QueueAlreadyExists,
+    QueueBeingDeleted,
+    QueueDisabled,
+    QueueNotEmpty,
+    QueueNotFound,
+    // file service
+    DirectoryNotEmpty,
+    ParentNotFound,
+    ShareAlreadyExists,
+    ShareBeingDeleted,
+    ShareDisabled,
+    ShareHasSnapshots,
+    ShareNotFound,
+    // table service
+    EntityAlreadyExists,
+    EntityNotFound,
+    EntityTooLarge,
+    TableAlreadyExists,
+    TableBeingDeleted,
+    TableNotFound,
+    UpdateConditionNotSatisfied,
+    // Cosmos
+    BadRequest,
+    Conflict,
+    Forbidden,
+    NotFound,
+    PreconditionFailed,
+    RequestEntityTooLarge,
+    RequestTimeout,
+    RetryWith,
+    ServiceUnavailable,
+    TooManyRequests,
+    Unauthorized
+);
+
+impl std::fmt::Display for ServiceErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The error returned by the service, as parsed from the response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceError {
+    code: ServiceErrorCode,
+    message: Option<String>,
+    request_id: Option<String>,
+    activity_id: Option<String>,
+}
+
+impl ServiceError {
+    /// Parses the storage XML (`<Error><Code>`), the table JSON (`odata.error`)
+    /// or the Cosmos JSON (`{code, message}`) error body.
+    pub fn from_body(body: &str) -> Option<ServiceError> {
+        let (code, message) = code_and_message_from_body(body)?;
+        Some(ServiceError {
+            code: code.as_str().into(),
+            message,
+            request_id: None,
+            activity_id: None,
+        })
+    }
+
+    /// Like `from_body` but the `x-ms-error-code` header, if present, takes
+    /// precedence over the body. The request and activity ids are taken from
+    /// the headers as well.
+    pub fn from_headers_and_body(headers: &HeaderMap, body: &str) -> Option<ServiceError> {
+        let (code, message) = match (
+            headers.get_as_str(ERROR_CODE),
+            code_and_message_from_body(body),
+        ) {
+            (Some(code), Some((_, message))) => (code.to_owned(), message),
+            (Some(code), None) => (code.to_owned(), None),
+            (None, Some((code, message))) => (code, message),
+            (None, None) => return None,
+        };
+
+        Some(ServiceError {
+            code: code.as_str().into(),
+            message,
+            request_id: headers.get_as_string(REQUEST_ID),
+            activity_id: headers.get_as_string(ACTIVITY_ID),
+        })
+    }
+
+    pub fn code(&self) -> &ServiceErrorCode {
+        &self.code
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    pub fn activity_id(&self) -> Option<&str> {
+        self.activity_id.as_deref()
+    }
+}
+
+impl std::fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+fn code_and_message_from_body(body: &str) -> Option<(String, Option<String>)> {
+    let body = body.trim_start_matches('\u{feff}').trim();
+
+    if body.starts_with('<') {
+        let elem: Element = body.parse().ok()?;
+        let code = cast_optional::<String>(&elem, &["Code"]).ok()??;
+        let message = cast_optional::<String>(&elem, &["Message"]).ok()?;
+        return Some((code, message));
+    }
+
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    // the table service wraps the error in an odata.error node
+    let (json, message) = match json.get("odata.error") {
+        Some(error) => (error, error.get("message").and_then(|m| m.get("value"))),
+        None => (&json, json.get("message")),
+    };
+
+    let code = json.get("code")?.as_str()?.to_owned();
+    let message = message.and_then(|m| m.as_str()).map(|m| m.to_owned());
+    Some((code, message))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_storage_error() {
+        let body = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <Error><Code>BlobNotFound</Code>\
+            <Message>The specified blob does not exist.\nRequestId:a6b9a1a0</Message></Error>";
+
+        let error = ServiceError::from_body(body).unwrap();
+        assert_eq!(error.code(), &ServiceErrorCode::BlobNotFound);
+        assert_eq!(
+            error.message(),
+            Some("The specified blob does not exist.\nRequestId:a6b9a1a0")
+        );
+    }
+
+    #[test]
+    fn parse_table_error() {
+        let body = r#"{"odata.error":{"code":"TableAlreadyExists","message":{"lang":"en-US","value":"The table specified already exists."}}}"#;
+
+        let error = ServiceError::from_body(body).unwrap();
+        assert_eq!(error.code(), &ServiceErrorCode::TableAlreadyExists);
+        assert_eq!(error.message(), Some("The table specified already exists."));
+    }
+
+    #[test]
+    fn parse_cosmos_error() {
+        let body = r#"{"code":"TooManyRequests","message":"Request rate is large"}"#;
+        let mut headers = HeaderMap::new();
+        headers.insert(ACTIVITY_ID, "8f4a7f0c".parse().unwrap());
+
+        let error = ServiceError::from_headers_and_body(&headers, body).unwrap();
+        assert_eq!(error.code(), &ServiceErrorCode::TooManyRequests);
+        assert_eq!(error.message(), Some("Request rate is large"));
+        assert_eq!(error.activity_id(), Some("8f4a7f0c"));
+        assert_eq!(error.request_id(), None);
+    }
+
+    #[test]
+    fn header_code_takes_precedence() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ERROR_CODE, "LeaseIdMissing".parse().unwrap());
+        headers.insert(REQUEST_ID, "a6b9a1a0".parse().unwrap());
+
+        let error = ServiceError::from_headers_and_body(&headers, "").unwrap();
+        assert_eq!(error.code(), &ServiceErrorCode::LeaseIdMissing);
+        assert_eq!(error.message(), None);
+        assert_eq!(error.request_id(), Some("a6b9a1a0"));
+
+        assert_eq!(
+            ServiceError::from_headers_and_body(&HeaderMap::new(), ""),
+            None
+        );
+    }
+
+    #[test]
+    fn unknown_code() {
+        let code: ServiceErrorCode = "SomethingNew".into();
+        assert_eq!(code, ServiceErrorCode::Other("SomethingNew".to_owned()));
+        assert_eq!(code.as_str(), "SomethingNew");
+        assert_eq!(
+            ServiceErrorCode::ContainerAlreadyExists.as_str(),
+            "ContainerAlreadyExists"
+        );
+    }
+}
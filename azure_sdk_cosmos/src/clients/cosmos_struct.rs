@@ -2,10 +2,12 @@ use crate::clients::DatabaseStruct;
 use crate::headers::*;
 use crate::requests;
 use crate::{
-    AuthorizationToken, CosmosClient, HasHyperClient, IntoDatabaseClient, ResourceType,
+    AuthorizationToken, CosmosClient, HasHttpClient, IntoDatabaseClient, ResourceType,
     WithDatabaseClient,
 };
 use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::http_client::{default_http_client, HttpClient};
+use azure_sdk_core::retry_policy::{RetryHttpClient, RetryPolicy};
 use azure_sdk_core::No;
 use base64;
 use chrono;
@@ -14,10 +16,10 @@ use hyper::{
     self,
     header::{self, HeaderValue},
 };
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::sync::Arc;
 use url::form_urlencoded;
 
 const AZURE_VERSION: &str = "2018-12-31";
@@ -33,7 +35,7 @@ pub struct CosmosStruct<'a, CUB>
 where
     CUB: CosmosUriBuilder,
 {
-    hyper_client: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    http_client: RetryHttpClient,
     account: Cow<'a, str>,
     auth_token: AuthorizationToken,
     cosmos_uri_builder: CUB,
@@ -45,12 +47,31 @@ where
 {
     pub fn with_auth_token(&self, auth_token: AuthorizationToken) -> Self {
         Self {
-            hyper_client: self.hyper_client.clone(),
+            http_client: self.http_client.clone(),
             account: self.account.clone(),
             auth_token,
             cosmos_uri_builder: self.cosmos_uri_builder.clone(),
         }
     }
+
+    pub fn with_http_client(&self, http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            http_client: self.http_client.with_http_client(http_client),
+            account: self.account.clone(),
+            auth_token: self.auth_token.clone(),
+            cosmos_uri_builder: self.cosmos_uri_builder.clone(),
+        }
+    }
+
+    /// By default requests are not retried.
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            http_client: self.http_client.with_retry_policy(retry_policy),
+            account: self.account.clone(),
+            auth_token: self.auth_token.clone(),
+            cosmos_uri_builder: self.cosmos_uri_builder.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -115,11 +136,11 @@ impl ClientBuilder {
         IntoCowStr: Into<Cow<'a, str>>,
     {
         let account = account.into();
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = RetryHttpClient::new(default_http_client(), RetryPolicy::no_retry());
         let cosmos_uri_builder = DefaultCosmosUri::new(account.as_ref());
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            http_client: client,
             account: account,
             auth_token,
             cosmos_uri_builder,
@@ -134,11 +155,11 @@ impl ClientBuilder {
         IntoCowStr: Into<Cow<'a, str>>,
     {
         let account = account.into();
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = RetryHttpClient::new(default_http_client(), RetryPolicy::no_retry());
         let cosmos_uri_builder = ChinaCosmosUri::new(account.as_ref());
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            http_client: client,
             account,
             auth_token,
             cosmos_uri_builder,
@@ -153,10 +174,10 @@ impl ClientBuilder {
     where
         IntoCowStr: Into<Cow<'a, str>>,
     {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = RetryHttpClient::new(default_http_client(), RetryPolicy::no_retry());
 
         Ok(CosmosStruct {
-            hyper_client: client,
+            http_client: client,
             account: account.into(),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri { uri },
@@ -167,7 +188,7 @@ impl ClientBuilder {
         address: &str,
         port: u16,
     ) -> Result<CosmosStruct<CustomCosmosUri>, AzureError> {
-        let client = hyper::Client::builder().build(HttpsConnector::new());
+        let client = RetryHttpClient::new(default_http_client(), RetryPolicy::no_retry());
 
         //Account name: localhost:<port>
         //Account key: C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==
@@ -175,7 +196,7 @@ impl ClientBuilder {
             "C2y6yDjf5/R+ob0N8A7Cgv30VRDJIWEHLM+4QDU5DE2nQ9nDuVTqobD4b8mGGyPMbIZnqyMsEcaGQy67XIw/Jw==",
         ).unwrap();
         Ok(CosmosStruct {
-            hyper_client: client,
+            http_client: client,
             account: Cow::Owned(format!("{}:{}", address, port)),
             auth_token,
             cosmos_uri_builder: CustomCosmosUri {
@@ -185,13 +206,13 @@ impl ClientBuilder {
     }
 }
 
-impl<'a, CUB> HasHyperClient for CosmosStruct<'a, CUB>
+impl<'a, CUB> HasHttpClient for CosmosStruct<'a, CUB>
 where
     CUB: CosmosUriBuilder + Debug,
 {
     #[inline]
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>> {
-        &self.hyper_client
+    fn http_client(&self) -> &dyn HttpClient {
+        &self.http_client
     }
 }
 
@@ -1,6 +1,7 @@
 use crate::clients::{CollectionStruct, UserStruct};
 use crate::traits::*;
 use crate::{requests, CosmosClient};
+use azure_sdk_core::http_client::HttpClient;
 use azure_sdk_core::No;
 use std::borrow::Cow;
 
@@ -26,15 +27,13 @@ where
     }
 }
 
-impl<'a, C> HasHyperClient for DatabaseStruct<'a, C>
+impl<'a, C> HasHttpClient for DatabaseStruct<'a, C>
 where
     C: CosmosClient + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.cosmos_client().hyper_client()
+    fn http_client(&self) -> &dyn HttpClient {
+        self.cosmos_client().http_client()
     }
 }
 
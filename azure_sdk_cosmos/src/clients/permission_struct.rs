@@ -1,5 +1,6 @@
 use crate::requests;
 use crate::traits::*;
+use azure_sdk_core::http_client::HttpClient;
 //use azure_sdk_core::No;
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -33,17 +34,15 @@ where
     }
 }
 
-impl<'a, C, D, USER> HasHyperClient for PermissionStruct<'a, C, D, USER>
+impl<'a, C, D, USER> HasHttpClient for PermissionStruct<'a, C, D, USER>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
     USER: UserClient<C, D> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.user_client.hyper_client()
+    fn http_client(&self) -> &dyn HttpClient {
+        self.user_client.http_client()
     }
 }
 
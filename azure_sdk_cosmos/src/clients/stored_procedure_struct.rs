@@ -1,5 +1,6 @@
 use crate::requests;
 use crate::traits::*;
+use azure_sdk_core::http_client::HttpClient;
 use azure_sdk_core::No;
 use std::borrow::Cow;
 use std::marker::PhantomData;
@@ -36,17 +37,15 @@ where
     }
 }
 
-impl<'a, C, D, COLL> HasHyperClient for StoredProcedureStruct<'a, C, D, COLL>
+impl<'a, C, D, COLL> HasHttpClient for StoredProcedureStruct<'a, C, D, COLL>
 where
     C: CosmosClient + Clone,
     D: DatabaseClient<C> + Clone,
     COLL: CollectionClient<C, D> + Clone,
 {
     #[inline]
-    fn hyper_client(
-        &self,
-    ) -> &hyper::Client<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>> {
-        self.collection_client.hyper_client()
+    fn http_client(&self) -> &dyn HttpClient {
+        self.collection_client.http_client()
     }
 }
 
@@ -474,7 +474,7 @@ where
         let req = req.body(hyper::Body::from(serialized))?;
 
         let (status_code, headers, whole_body) =
-            extract_status_headers_and_body(self.collection_client.hyper_client().request(req))
+            extract_status_headers_and_body(self.collection_client.http_client().request(req))
                 .await?;
 
         debug!("status_core == {:?}", status_code);
@@ -489,6 +489,7 @@ where
                 status_code,
                 std::str::from_utf8(&whole_body)?,
             )
+            .with_headers(&headers)
             .into());
         } else if status_code != StatusCode::CREATED && status_code != StatusCode::OK {
             return Err(UnexpectedHTTPResult::new_multiple(
@@ -496,6 +497,7 @@ where
                 status_code,
                 std::str::from_utf8(&whole_body)?,
             )
+            .with_headers(&headers)
             .into());
         }
 
@@ -171,7 +171,7 @@ where
 
         let (headers, body) = check_status_extract_headers_and_body(
             self.stored_procedure_client()
-                .hyper_client()
+                .http_client()
                 .request(request),
             StatusCode::NO_CONTENT,
         )
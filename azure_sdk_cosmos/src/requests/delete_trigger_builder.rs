@@ -168,7 +168,7 @@ where
         let request = req.body(hyper::Body::empty())?;
 
         let (headers, body) = check_status_extract_headers_and_body(
-            self.trigger_client().hyper_client().request(request),
+            self.trigger_client().http_client().request(request),
             StatusCode::NO_CONTENT,
         )
         .await?;
@@ -155,7 +155,7 @@ where
         debug!("\nreq == {:?}", req);
 
         let (headers, body) = check_status_extract_headers_and_body(
-            self.user_client.hyper_client().request(req),
+            self.user_client.http_client().request(req),
             StatusCode::NO_CONTENT,
         )
         .await?;
@@ -168,7 +168,7 @@ where
         debug!("\nrequest == {:#?}", request);
 
         let (status, headers, body) =
-            extract_status_headers_and_body(self.permission_client.hyper_client().request(request))
+            extract_status_headers_and_body(self.permission_client.http_client().request(request))
                 .await?;
 
         match status {
@@ -179,6 +179,7 @@ where
                 status,
                 std::str::from_utf8(&body)?,
             )
+            .with_headers(&headers)
             .into()),
         }
     }
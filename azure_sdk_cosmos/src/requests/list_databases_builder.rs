@@ -161,7 +161,7 @@ impl<'a> ListDatabasesBuilder<'a> {
 
         let request = request.body(hyper::Body::empty())?;
 
-        let future_response = self.cosmos_client.hyper_client().request(request);
+        let future_response = self.cosmos_client.http_client().request(request);
         let (headers, body) =
             check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
 
@@ -256,7 +256,7 @@ where
         debug!("\nrequest == {:#?}", request);
 
         let (headers, body) = check_status_extract_headers_and_body(
-            self.user_client.hyper_client().request(request),
+            self.user_client.http_client().request(request),
             StatusCode::OK,
         )
         .await?;
@@ -500,7 +500,7 @@ where
         debug!("request == {:#?}", req);
 
         let (headers, body) = check_status_extract_headers_and_body(
-            self.collection_client.hyper_client().request(req),
+            self.collection_client.http_client().request(req),
             StatusCode::OK,
         )
         .await?;
@@ -243,7 +243,7 @@ where
 
         let (headers, body) = check_status_extract_headers_and_body(
             self.stored_procedure_client()
-                .hyper_client()
+                .http_client()
                 .request(request),
             StatusCode::OK,
         )
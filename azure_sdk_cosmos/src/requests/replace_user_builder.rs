@@ -224,7 +224,7 @@ where
         debug!("\nreq == {:?}", req);
 
         let (status_code, headers, body) =
-            extract_status_headers_and_body(self.user_client.hyper_client().request(req)).await?;
+            extract_status_headers_and_body(self.user_client.http_client().request(req)).await?;
 
         match status_code {
             StatusCode::NOT_FOUND => Ok(None),
@@ -234,6 +234,7 @@ where
                 status_code,
                 std::str::from_utf8(&body)?,
             )
+            .with_headers(&headers)
             .into()),
         }
     }
@@ -1,16 +1,16 @@
 use crate::requests;
 use crate::{PartitionKeys, ResourceType};
+use azure_sdk_core::http_client::HttpClient;
 use azure_sdk_core::No;
 use http::request::Builder;
-use hyper_rustls::HttpsConnector;
 use std::borrow::Cow;
 use std::fmt::Debug;
 
-pub trait HasHyperClient: Debug + Send + Sync {
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
+pub trait HasHttpClient: Debug + Send + Sync {
+    fn http_client(&self) -> &dyn HttpClient;
 }
 
-pub trait CosmosClient: HasHyperClient + Send + Sync {
+pub trait CosmosClient: HasHttpClient + Send + Sync {
     fn create_database(&self) -> requests::CreateDatabaseBuilder<'_, No>;
     fn list_databases(&self) -> requests::ListDatabasesBuilder<'_>;
 
@@ -22,7 +22,7 @@ pub trait CosmosClient: HasHyperClient + Send + Sync {
     ) -> Builder;
 }
 
-pub trait HasCosmosClient<C>: HasHyperClient
+pub trait HasCosmosClient<C>: HasHttpClient
 where
     C: CosmosClient,
 {
@@ -3,18 +3,18 @@ use crate::event_hub::{
     unlock_message, PeekLockResponse,
 };
 use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::http_client::{default_http_client, HttpClient};
+use azure_sdk_core::retry_policy::{RetryHttpClient, RetryPolicy};
 use chrono::Duration;
-use hyper_rustls::HttpsConnector;
 use ring::hmac::Key;
-
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
+use std::sync::Arc;
 
 pub struct Client {
     namespace: String,
     event_hub: String,
     policy_name: String,
     signing_key: Key,
-    http_client: HttpClient,
+    http_client: RetryHttpClient,
 }
 
 impl Client {
@@ -31,7 +31,7 @@ impl Client {
         K: AsRef<str>,
     {
         let signing_key = Key::new(ring::hmac::HMAC_SHA256, key.as_ref().as_bytes());
-        let http_client = hyper::Client::builder().build(HttpsConnector::new());
+        let http_client = RetryHttpClient::new(default_http_client(), RetryPolicy::no_retry());
 
         Ok(Client {
             namespace: namespace.into(),
@@ -42,6 +42,23 @@ impl Client {
         })
     }
 
+    /// Sends the requests of this client through `http_client`.
+    pub fn with_http_client(self, http_client: Arc<dyn HttpClient>) -> Self {
+        Client {
+            http_client: self.http_client.with_http_client(http_client),
+            ..self
+        }
+    }
+
+    /// Retries the requests of this client according to `retry_policy`.
+    /// By default requests are not retried.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Client {
+            http_client: self.http_client.with_retry_policy(retry_policy),
+            ..self
+        }
+    }
+
     pub async fn send_event(
         &mut self,
         event_body: &str,
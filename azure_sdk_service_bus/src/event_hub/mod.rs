@@ -1,9 +1,10 @@
 use azure_sdk_core::errors::{
     check_status_extract_body, extract_location_status_and_body, AzureError,
 };
+use azure_sdk_core::http_client::{HttpClient, ResponseFuture};
+use azure_sdk_core::retry_policy::{NonRetriable, RetryHttpClient};
 use chrono::Duration;
 use hyper::{self, header, Body, StatusCode};
-use hyper_rustls::HttpsConnector;
 use ring::hmac;
 use std::ops::Add;
 use url::{form_urlencoded, Url};
@@ -11,18 +12,16 @@ use url::{form_urlencoded, Url};
 mod client;
 pub use self::client::Client;
 
-type HttpClient = hyper::Client<HttpsConnector<hyper::client::HttpConnector>>;
-
 #[inline]
 fn send_event_prepare<B: Into<String>>(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     event_body: B,
     duration: Duration,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages",
@@ -43,14 +42,14 @@ fn send_event_prepare<B: Into<String>>(
 }
 
 fn peek_lock_prepare(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     timeout: Option<Duration>,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let mut url = Url::parse(&format!(
         "https://{}.servicebus.windows.net/{}/messages/head",
@@ -75,7 +74,7 @@ fn peek_lock_prepare(
 }
 
 async fn peek_lock(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -97,7 +96,7 @@ async fn peek_lock(
 }
 
 async fn peek_lock_full(
-    http_client: &HttpClient,
+    http_client: &RetryHttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -118,7 +117,7 @@ async fn peek_lock_full(
     let a = extract_location_status_and_body(req?).await?;
 
     Ok(PeekLockResponse {
-        http_client: http_client.to_owned(),
+        http_client: http_client.clone(),
         status: a.0,
         delete_location: a.1,
         body: a.2,
@@ -129,7 +128,7 @@ async fn peek_lock_full(
 }
 
 pub struct PeekLockResponse {
-    http_client: HttpClient,
+    http_client: RetryHttpClient,
     status: StatusCode,
     delete_location: String,
     body: String,
@@ -159,13 +158,13 @@ impl PeekLockResponse {
 }
 
 fn receive_and_delete_prepare(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages/head",
@@ -177,15 +176,17 @@ fn receive_and_delete_prepare(
     let sas = generate_signature(policy_name, signing_key, &url, duration);
     debug!("sas == {}", sas);
 
+    // the message is gone once received: a replayed request would lose it
     let request = hyper::Request::delete(url)
         .header(header::AUTHORIZATION, sas)
+        .extension(NonRetriable)
         .body(Body::empty())?;
 
     Ok(http_client.request(request))
 }
 
 async fn receive_and_delete(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -205,7 +206,7 @@ async fn receive_and_delete(
 }
 
 fn delete_message_prepare(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -213,7 +214,7 @@ fn delete_message_prepare(
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages/{}/{}",
@@ -227,12 +228,12 @@ fn delete_message_prepare(
 }
 
 fn delete_message_get_request(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     policy_name: &str,
     signing_key: &hmac::Key,
     duration: Duration,
     url: String,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     let sas = generate_signature(policy_name, signing_key, &url, duration);
     debug!("sas == {}", sas);
 
@@ -244,7 +245,7 @@ fn delete_message_get_request(
 }
 
 async fn delete_message(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -271,7 +272,7 @@ async fn delete_message(
 }
 
 fn unlock_message_prepare(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -279,7 +280,7 @@ fn unlock_message_prepare(
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages/{}/{}",
@@ -299,7 +300,7 @@ fn unlock_message_prepare(
 }
 
 async fn unlock_message(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -326,7 +327,7 @@ async fn unlock_message(
 }
 
 fn renew_lock_prepare(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -334,7 +335,7 @@ fn renew_lock_prepare(
     duration: Duration,
     message_id: &str,
     lock_token: &str,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     // prepare the url to call
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages/{}/{}",
@@ -354,7 +355,7 @@ fn renew_lock_prepare(
 }
 
 async fn renew_lock(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -381,7 +382,7 @@ async fn renew_lock(
 }
 
 async fn send_event(
-    http_client: &HttpClient,
+    http_client: &dyn HttpClient,
     namespace: &str,
     event_hub: &str,
     policy_name: &str,
@@ -438,3 +439,49 @@ fn generate_signature(
         &url_encoded, sig, expiry, policy_name
     )
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use azure_sdk_core::retry_policy::RetryPolicy;
+    use futures::executor::block_on;
+    use hyper::Request;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct MockHttpClient {
+        requests: Mutex<u32>,
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn request(&self, _request: Request<Body>) -> ResponseFuture {
+            *self.requests.lock().unwrap() += 1;
+            let response = hyper::Response::builder()
+                .status(StatusCode::SERVICE_UNAVAILABLE)
+                .body(Body::empty())
+                .unwrap();
+            Box::pin(async { Ok(response) })
+        }
+    }
+
+    #[test]
+    fn receive_and_delete_is_not_retried() {
+        let mock = Arc::new(MockHttpClient::default());
+        let http_client = RetryHttpClient::new(
+            mock.clone(),
+            RetryPolicy::fixed(std::time::Duration::from_secs(0)),
+        );
+        let signing_key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+
+        let result = block_on(receive_and_delete(
+            &http_client,
+            "namespace",
+            "event_hub",
+            "policy",
+            &signing_key,
+            Duration::hours(1),
+        ));
+        assert!(result.is_err());
+        assert_eq!(*mock.requests.lock().unwrap(), 1);
+    }
+}
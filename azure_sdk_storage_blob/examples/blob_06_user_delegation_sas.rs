@@ -0,0 +1,46 @@
+use azure_sdk_storage_blob::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use chrono::{Duration, Utc};
+use std::error::Error;
+use url::Url;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // No account key is needed: the SAS is signed with a user delegation key
+    // obtained with an Azure AD bearer token (see blob_05_default_credential.rs).
+    let account = std::env::args()
+        .nth(1)
+        .expect("please specify the account name as first command line parameter");
+    let container = std::env::args()
+        .nth(2)
+        .expect("please specify the container name as second command line parameter");
+    let blob = std::env::args()
+        .nth(3)
+        .expect("please specify the blob name as third command line parameter");
+    let bearer_token = std::env::args()
+        .nth(4)
+        .expect("please specify the bearer token as fourth command line parameter");
+
+    let client = client::with_bearer_token(&account, bearer_token);
+
+    let now = Utc::now();
+    let response = client
+        .get_user_delegation_key()
+        .with_key_start(now)
+        .with_key_expiry(now + Duration::hours(1))
+        .finalize()
+        .await?;
+    let user_delegation_key = response.user_delegation_key;
+
+    let path = Url::parse(&format!("{}/{}/{}", client.blob_uri(), container, blob))?;
+    let end = now + Duration::minutes(30);
+    let url = BlobSASBuilder::new(&path)
+        .with_user_delegation_key(&user_delegation_key)
+        .with_validity_end(&end)
+        .allow_read()
+        .finalize();
+
+    println!("url: '{}'", url);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_blob::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+    let file_name = std::env::args()
+        .nth(3)
+        .expect("please specify the destination file as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    // The blob is fetched in 8 MB ranges, 8 at a time. Every range is
+    // checked against the ETag of the first one so a blob modified
+    // during the download fails instead of producing a mixed file.
+    let res = client
+        .download_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_chunk_size(8 * 1024 * 1024)
+        .with_max_concurrency(8)
+        .finalize_to_file(&file_name)
+        .await?;
+    println!("download_blob == {:?}", res);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_blob::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let container = std::env::args()
+        .nth(1)
+        .expect("please specify container name as command line parameter");
+    let blob_name = std::env::args()
+        .nth(2)
+        .expect("please specify blob name as command line parameter");
+    let file_name = std::env::args()
+        .nth(3)
+        .expect("please specify the file to upload as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    // The file is split in 8 MB blocks, uploaded 8 at a time.
+    // Files smaller than a block are sent with a single Put Blob.
+    let res = client
+        .upload_blob()
+        .with_container_name(&container)
+        .with_blob_name(&blob_name)
+        .with_chunk_size(8 * 1024 * 1024)
+        .with_max_concurrency(8)
+        .with_content_type("application/octet-stream")
+        .finalize_from_file(&file_name)
+        .await?;
+    println!("upload_blob == {:?}", res);
+
+    Ok(())
+}
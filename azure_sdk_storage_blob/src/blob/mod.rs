@@ -14,19 +14,26 @@ mod block_with_size_list;
 pub use self::block_with_size_list::BlockWithSizeList;
 mod block_list;
 pub use self::block_list::BlockList;
+mod page_ranges;
+pub use self::page_ranges::PageRanges;
+mod tags;
+pub use self::tags::Tags;
 pub mod requests;
 pub mod responses;
 use azure_sdk_core::headers::{
+    ACCESS_TIER_CHANGE_TIME, ACCESS_TIER_INFERRED, ARCHIVE_STATUS, BLOB_ACCESS_TIER,
     BLOB_SEQUENCE_NUMBER, BLOB_TYPE, CONTENT_MD5, COPY_COMPLETION_TIME, COPY_ID, COPY_PROGRESS,
-    COPY_SOURCE, COPY_STATUS, COPY_STATUS_DESCRIPTION, CREATION_TIME, LEASE_DURATION, LEASE_STATE,
-    LEASE_STATUS, SERVER_ENCRYPTED,
+    COPY_SOURCE, COPY_STATUS, COPY_STATUS_DESCRIPTION, CREATION_TIME, IF_TAGS, LEASE_DURATION,
+    LEASE_STATE, LEASE_STATUS, META_PREFIX, SEQUENCE_NUMBER_ACTION, SERVER_ENCRYPTED, TAGS,
 };
-use azure_sdk_storage_core::Client;
+use azure_sdk_storage_core::{Client, CopyId};
 use chrono::{DateTime, Utc};
+use http::request::Builder;
 use hyper::header;
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Duration;
 use url::form_urlencoded;
 use xml::Element;
 use xml::Xml::ElementNode;
@@ -38,6 +45,7 @@ use azure_sdk_core::{
     parsing::{cast_must, cast_optional, from_azure_time, inner_text, traverse},
     range::Range,
     util::HeaderMapExt,
+    AccessTier,
 };
 
 #[cfg(feature = "azurite_workaround")]
@@ -86,6 +94,126 @@ where
     }
 }
 
+pub trait MaxConcurrencySupport {
+    type O;
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O;
+}
+
+pub trait MaxConcurrencyOption {
+    fn max_concurrency(&self) -> usize;
+}
+
+pub trait MaxBlockRetriesSupport {
+    type O;
+    fn with_max_block_retries(self, max_block_retries: u32) -> Self::O;
+}
+
+pub trait MaxBlockRetriesOption {
+    fn max_block_retries(&self) -> u32;
+}
+
+pub trait CopyIdSupport<'a> {
+    type O;
+    fn with_copy_id(self, copy_id: &'a CopyId) -> Self::O;
+}
+
+pub trait CopyIdRequired<'a> {
+    fn copy_id(&self) -> &'a CopyId;
+
+    fn to_uri_parameter(&self) -> String {
+        format!("copyid={}", self.copy_id())
+    }
+}
+
+pub trait PollIntervalSupport {
+    type O;
+    fn with_poll_interval(self, poll_interval: Duration) -> Self::O;
+}
+
+pub trait PollIntervalOption {
+    fn poll_interval(&self) -> Duration;
+}
+
+/// How Set Blob Sequence Number changes the sequence number of a page blob.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SequenceNumberAction {
+    /// Sets the sequence number to the higher of the current value and this one.
+    Max(u64),
+    /// Sets the sequence number to this value.
+    Update(u64),
+    /// Increments the sequence number by one.
+    Increment,
+}
+
+impl SequenceNumberAction {
+    #[must_use]
+    pub(crate) fn add_header(self, builder: Builder) -> Builder {
+        match self {
+            SequenceNumberAction::Max(sequence_number) => builder
+                .header(SEQUENCE_NUMBER_ACTION, "max")
+                .header(BLOB_SEQUENCE_NUMBER, &sequence_number.to_string() as &str),
+            SequenceNumberAction::Update(sequence_number) => builder
+                .header(SEQUENCE_NUMBER_ACTION, "update")
+                .header(BLOB_SEQUENCE_NUMBER, &sequence_number.to_string() as &str),
+            SequenceNumberAction::Increment => builder.header(SEQUENCE_NUMBER_ACTION, "increment"),
+        }
+    }
+}
+
+pub trait SequenceNumberActionSupport {
+    type O;
+    fn with_sequence_number_action(self, sequence_number_action: SequenceNumberAction) -> Self::O;
+}
+
+pub trait SequenceNumberActionRequired {
+    fn sequence_number_action(&self) -> SequenceNumberAction;
+
+    #[must_use]
+    fn add_header(&self, builder: Builder) -> Builder {
+        self.sequence_number_action().add_header(builder)
+    }
+}
+
+pub trait TagsSupport<'a> {
+    type O;
+    fn with_tags(self, tags: &'a Tags) -> Self::O;
+}
+
+pub trait TagsOption<'a> {
+    fn tags(&self) -> Option<&'a Tags>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(tags) = self.tags() {
+            builder = builder.header(TAGS, tags.to_header_value());
+        }
+        builder
+    }
+}
+
+pub trait TagsRequired<'a> {
+    fn tags(&self) -> &'a Tags;
+}
+
+pub trait IfTagsSupport<'a> {
+    type O;
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O;
+}
+
+/// The condition is a SQL-like expression on the blob tags,
+/// for example `"stage" = 'raw'`.
+pub trait IfTagsOption<'a> {
+    fn if_tags(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(if_tags) = self.if_tags() {
+            builder = builder.header(IF_TAGS, if_tags);
+        }
+        builder
+    }
+}
+
 create_enum!(
     BlobType,
     (BlockBlob, "BlockBlob"),
@@ -103,6 +231,25 @@ create_enum!(
 
 create_enum!(PageWriteType, (Update, "update"), (Clear, "clear"));
 
+create_enum!(
+    ArchiveStatus,
+    (RehydratePendingToHot, "rehydrate-pending-to-hot"),
+    (RehydratePendingToCool, "rehydrate-pending-to-cool")
+);
+
+// The service adds access tiers and archive statuses over time: a value
+// unknown to this version is logged and skipped instead of failing the blob.
+fn parse_lenient<T: FromStr>(field: &str, value: Option<&str>) -> Option<T> {
+    let value = value?;
+    match value.parse() {
+        Ok(parsed) => Some(parsed),
+        Err(_) => {
+            warn!("ignoring unknown {} {:?}", field, value);
+            None
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Blob {
     pub name: String,
@@ -123,7 +270,8 @@ pub struct Blob {
     pub content_disposition: Option<String>,
     pub x_ms_blob_sequence_number: Option<u64>,
     pub blob_type: BlobType,
-    pub access_tier: Option<String>,
+    pub access_tier: Option<AccessTier>,
+    pub archive_status: Option<ArchiveStatus>,
     pub lease_status: Option<LeaseStatus>,
     pub lease_state: LeaseState,
     pub lease_duration: Option<LeaseDuration>,
@@ -168,7 +316,14 @@ impl Blob {
             cast_optional::<u64>(elem, &["Properties", "x-ms-blob-sequence-number"])?;
 
         let blob_type = cast_must::<BlobType>(elem, &["Properties", "BlobType"])?;
-        let access_tier = cast_optional::<String>(elem, &["Properties", "AccessTier"])?;
+        let access_tier = parse_lenient::<AccessTier>(
+            "access tier",
+            cast_optional::<String>(elem, &["Properties", "AccessTier"])?.as_deref(),
+        );
+        let archive_status = parse_lenient::<ArchiveStatus>(
+            "archive status",
+            cast_optional::<String>(elem, &["Properties", "ArchiveStatus"])?.as_deref(),
+        );
 
         let lease_status = cast_optional::<LeaseStatus>(elem, &["Properties", "LeaseStatus"])?;
         let lease_state = cast_must::<LeaseState>(elem, &["Properties", "LeaseState"])?;
@@ -247,6 +402,7 @@ impl Blob {
             x_ms_blob_sequence_number,
             blob_type,
             access_tier,
+            archive_status,
             lease_status,
             lease_state,
             lease_duration,
@@ -386,6 +542,24 @@ impl Blob {
             .ok_or_else(|| AzureError::HeaderNotFound(SERVER_ENCRYPTED.to_owned()))?
             .parse::<bool>()?;
 
+        let access_tier = parse_lenient("access tier", h.get_as_str(BLOB_ACCESS_TIER));
+        trace!("access_tier == {:?}", access_tier);
+
+        let archive_status = parse_lenient("archive status", h.get_as_str(ARCHIVE_STATUS));
+        trace!("archive_status == {:?}", archive_status);
+
+        let access_tier_inferred = h
+            .get_as_str(ACCESS_TIER_INFERRED)
+            .map(str::parse::<bool>)
+            .transpose()?;
+        trace!("access_tier_inferred == {:?}", access_tier_inferred);
+
+        let access_tier_change_time = h
+            .get_as_str(ACCESS_TIER_CHANGE_TIME)
+            .map(from_azure_time)
+            .transpose()?;
+        trace!("access_tier_change_time == {:?}", access_tier_change_time);
+
         Ok(Blob {
             name: blob_name.to_owned(),
             container_name: container_name.to_owned(),
@@ -402,7 +576,8 @@ impl Blob {
             content_disposition,
             x_ms_blob_sequence_number,
             blob_type,
-            access_tier: None,
+            access_tier,
+            archive_status,
             lease_status,
             lease_state,
             lease_duration,
@@ -414,11 +589,11 @@ impl Blob {
             copy_status_description,
             incremental_copy: None, // TODO: Not present or documentation bug?
             server_encrypted,
-            access_tier_inferred: None,     // TODO: Not present
-            access_tier_change_time: None,  // TODO: Not present
+            access_tier_inferred,
+            access_tier_change_time,
             deleted_time: None,             // TODO
             remaining_retention_days: None, // TODO: Not present or documentation bug?
-            metadata: HashMap::new(),       // TODO: Not present or documentation bug?
+            metadata: metadata_from_headers(h)?,
         })
     }
 }
@@ -487,3 +662,37 @@ pub(crate) fn copy_status_from_headers(
         })?;
     Ok(CopyStatus::from_str(val)?)
 }
+
+pub(crate) fn metadata_from_headers(
+    headers: &http::HeaderMap,
+) -> Result<HashMap<String, String>, AzureError> {
+    let mut metadata = HashMap::new();
+    for (key, value) in headers {
+        if key.as_str().starts_with(META_PREFIX) {
+            metadata.insert(
+                key.as_str()[META_PREFIX.len()..].to_owned(),
+                value.to_str()?.to_owned(),
+            );
+        }
+    }
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unknown_access_tier_is_skipped() {
+        assert_eq!(
+            parse_lenient::<AccessTier>("access tier", Some("Cool")),
+            Some(AccessTier::Cool)
+        );
+        assert_eq!(parse_lenient::<AccessTier>("access tier", Some("P1")), None);
+        assert_eq!(
+            parse_lenient::<ArchiveStatus>("archive status", Some("rehydrate-pending-to-cold")),
+            None
+        );
+        assert_eq!(parse_lenient::<AccessTier>("access tier", None), None);
+    }
+}
@@ -0,0 +1,76 @@
+use azure_sdk_core::ba512_range::BA512Range;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, traverse};
+use xml::Element;
+
+/// The valid page ranges of a page blob. When diffing against a previous
+/// snapshot `page_ranges` holds the pages written since the snapshot and
+/// `clear_ranges` the pages cleared since the snapshot.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PageRanges {
+    pub page_ranges: Vec<BA512Range>,
+    pub clear_ranges: Vec<BA512Range>,
+}
+
+impl PageRanges {
+    pub(crate) fn from_xml(xml: &str) -> Result<PageRanges, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        Ok(PageRanges {
+            page_ranges: ranges(&elem, "PageRange")?,
+            clear_ranges: ranges(&elem, "ClearRange")?,
+        })
+    }
+}
+
+fn ranges(elem: &Element, name: &str) -> Result<Vec<BA512Range>, AzureError> {
+    traverse(elem, &[name], true)?
+        .into_iter()
+        .map(|range| {
+            let start = cast_must::<u64>(range, &["Start"])?;
+            let end = cast_must::<u64>(range, &["End"])?;
+            BA512Range::new(start, end)
+                .map_err(|_| AzureError::PageNot512ByteAlignedError(start, end))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_page_list() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <PageList>\
+            <PageRange><Start>0</Start><End>511</End></PageRange>\
+            <ClearRange><Start>512</Start><End>1023</End></ClearRange>\
+            <PageRange><Start>1024</Start><End>4095</End></PageRange>\
+            </PageList>";
+
+        let page_ranges = PageRanges::from_xml(xml).unwrap();
+        assert_eq!(
+            page_ranges.page_ranges,
+            vec![
+                BA512Range::new(0, 511).unwrap(),
+                BA512Range::new(1024, 4095).unwrap()
+            ]
+        );
+        assert_eq!(
+            page_ranges.clear_ranges,
+            vec![BA512Range::new(512, 1023).unwrap()]
+        );
+    }
+
+    #[test]
+    fn parse_empty_page_list() {
+        let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?><PageList />";
+        assert_eq!(PageRanges::from_xml(xml).unwrap(), PageRanges::default());
+    }
+
+    #[test]
+    fn reject_unaligned_range() {
+        let xml = "<PageList><PageRange><Start>0</Start><End>100</End></PageRange></PageList>";
+        assert!(PageRanges::from_xml(xml).is_err());
+    }
+}
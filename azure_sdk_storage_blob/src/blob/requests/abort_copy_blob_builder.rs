@@ -0,0 +1,346 @@
+use crate::blob::responses::AbortCopyBlobResponse;
+use crate::blob::{generate_blob_uri, CopyIdRequired, CopyIdSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::COPY_ACTION;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_core::CopyId;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_copy_id: PhantomData<CopyIdSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    copy_id: Option<&'a CopyId>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> AbortCopyBlobBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> AbortCopyBlobBuilder<'a, C, No, No, No> {
+        AbortCopyBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_copy_id: PhantomData {},
+            copy_id: None,
+            timeout: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequired<'a, C>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, CopyIdSet> ContainerNameRequired<'a>
+    for AbortCopyBlobBuilder<'a, C, Yes, BlobNameSet, CopyIdSet>
+where
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, CopyIdSet> BlobNameRequired<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, Yes, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CopyIdRequired<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn copy_id(&self) -> &'a CopyId {
+        self.copy_id.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> TimeoutOption
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> LeaseIdOption<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequestIdOption<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, CopyIdSet> ContainerNameSupport<'a>
+    for AbortCopyBlobBuilder<'a, C, No, BlobNameSet, CopyIdSet>
+where
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, Yes, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, CopyIdSet> BlobNameSupport<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, No, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, ContainerNameSet, Yes, CopyIdSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            copy_id: self.copy_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CopyIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_copy_id(self, copy_id: &'a CopyId) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: Some(copy_id),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> TimeoutSupport
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> LeaseIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet> ClientRequestIdSupport<'a>
+    for AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    CopyIdSet: ToAssign,
+    C: Client,
+{
+    type O = AbortCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, CopyIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AbortCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_copy_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            copy_id: self.copy_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> AbortCopyBlobBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<AbortCopyBlobResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=copy"),
+        );
+        uri = format!("{}&{}", uri, CopyIdRequired::to_uri_parameter(&self));
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = request.header(COPY_ACTION, "abort");
+                request = LeaseIdOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        AbortCopyBlobResponse::from_headers(&headers)
+    }
+}
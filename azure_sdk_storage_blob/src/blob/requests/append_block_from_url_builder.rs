@@ -0,0 +1,625 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::PutBlockResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::retry_policy::NonRetriable;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_source_url: PhantomData<SourceUrlSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    source_url: Option<&'a str>,
+    source_range: Option<&'a Range>,
+    source_content_md5: Option<&'a [u8]>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    append_position: Option<u32>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> AppendBlockFromUrlBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> AppendBlockFromUrlBuilder<'a, C, No, No, No> {
+        AppendBlockFromUrlBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_source_url: PhantomData {},
+            source_url: None,
+            source_range: None,
+            source_content_md5: None,
+            timeout: None,
+            lease_id: None,
+            append_position: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequired<'a, C>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, SourceUrlSet> ContainerNameRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet> BlobNameRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SourceUrlRequired<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_url(&self) -> &'a str {
+        self.source_url.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> SourceRangeOption<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_range(&self) -> Option<&'a Range> {
+        self.source_range
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> SourceContentMD5Option<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_content_md5(&self) -> Option<&'a [u8]> {
+        self.source_content_md5
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> TimeoutOption
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> LeaseIdOption<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> AppendPositionOption
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn append_position(&self) -> Option<u32> {
+        self.append_position
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfSinceConditionOption
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfMatchConditionOption<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequestIdOption<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, SourceUrlSet> ContainerNameSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, No, BlobNameSet, SourceUrlSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet> BlobNameSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, No, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SourceUrlSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_source_url(self, source_url: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: Some(source_url),
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> SourceRangeSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_source_range(self, source_range: &'a Range) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: Some(source_range),
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> SourceContentMD5Support<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_source_content_md5(self, source_content_md5: &'a [u8]) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: Some(source_content_md5),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> TimeoutSupport
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> LeaseIdSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> AppendPositionSupport
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_append_position(self, append_position: u32) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: Some(append_position),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfSinceConditionSupport
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfMatchConditionSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequestIdSupport<'a>
+    for AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = AppendBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AppendBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            append_position: self.append_position,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> AppendBlockFromUrlBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    /// Sent once, even when the client has a retry policy: a replayed append
+    /// would write the block twice. Callers retrying it themselves should set
+    /// `with_append_position` so that a replay of a committed append fails
+    /// with 412 instead of duplicating the data.
+    #[inline]
+    pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=appendblock"),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = SourceUrlRequired::add_header(&self, request);
+                request = SourceRangeOption::add_header(&self, request);
+                request = SourceContentMD5Option::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = AppendPositionOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request.extension(NonRetriable)
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutBlockResponse::from_headers(&headers)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::blob::generate_blob_uri;
 use crate::blob::responses::DeleteBlobResponse;
+use crate::blob::{IfTagsOption, IfTagsSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::lease::LeaseId;
 use azure_sdk_core::prelude::*;
@@ -26,6 +27,7 @@ where
     delete_snapshots_method: DeleteSnapshotsMethod,
     timeout: Option<u64>,
     lease_id: Option<&'a LeaseId>,
+    if_tags: Option<&'a str>,
     client_request_id: Option<&'a str>,
 }
 
@@ -45,6 +47,7 @@ where
             delete_snapshots_method: DeleteSnapshotsMethod::Include,
             timeout: None,
             lease_id: None,
+            if_tags: None,
             client_request_id: None,
         }
     }
@@ -134,6 +137,20 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> IfTagsOption<'a>
+    for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> ClientRequestIdOption<'a>
     for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
 where
@@ -169,6 +186,7 @@ where
             delete_snapshots_method: self.delete_snapshots_method,
             timeout: self.timeout,
             lease_id: self.lease_id,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -195,6 +213,7 @@ where
             delete_snapshots_method: self.delete_snapshots_method,
             timeout: self.timeout,
             lease_id: self.lease_id,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -224,6 +243,7 @@ where
             delete_snapshots_method,
             timeout: self.timeout,
             lease_id: self.lease_id,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -251,6 +271,7 @@ where
             delete_snapshots_method: self.delete_snapshots_method,
             timeout: Some(timeout),
             lease_id: self.lease_id,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -278,6 +299,35 @@ where
             delete_snapshots_method: self.delete_snapshots_method,
             timeout: self.timeout,
             lease_id: Some(lease_id),
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet> IfTagsSupport<'a>
+    for DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    DeleteSnapshotMethodSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, DeleteSnapshotMethodSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        DeleteBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_delete_snapshots_method: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            delete_snapshots_method: self.delete_snapshots_method,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: Some(if_tags),
             client_request_id: self.client_request_id,
         }
     }
@@ -305,6 +355,7 @@ where
             delete_snapshots_method: self.delete_snapshots_method,
             timeout: self.timeout,
             lease_id: self.lease_id,
+            if_tags: self.if_tags,
             client_request_id: Some(client_request_id),
         }
     }
@@ -331,6 +382,7 @@ where
             &|mut request| {
                 request = DeleteSnapshotsMethodRequired::add_header(&self, request);
                 request = LeaseIdOption::add_header(&self, request);
+                request = IfTagsOption::add_header(&self, request);
                 request = ClientRequestIdOption::add_header(&self, request);
                 request
             },
@@ -0,0 +1,632 @@
+use crate::blob::requests::GetBlobBuilder;
+use crate::blob::responses::{DownloadBlobResponse, GetBlobResponse};
+use crate::blob::{MaxConcurrencyOption, MaxConcurrencySupport};
+use crate::Blob;
+use azure_sdk_core::crc64;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::CONTENT_MD5;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::range::Range;
+use azure_sdk_core::{Consistency, No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use chrono::{DateTime, Utc};
+use futures::io::{AsyncWrite, AsyncWriteExt};
+use futures::stream::{self, StreamExt};
+use hyper::StatusCode;
+use std::io::SeekFrom;
+use std::marker::PhantomData;
+use std::path::Path;
+use tokio::io::AsyncWriteExt as _;
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    chunk_size: u64,
+    max_concurrency: usize,
+    snapshot: Option<DateTime<Utc>>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> DownloadBlobBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DownloadBlobBuilder<'a, C, No, No> {
+        DownloadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            chunk_size: 4194304,
+            max_concurrency: 4,
+            snapshot: None,
+            timeout: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequired<'a, C>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet> ContainerNameRequired<'a> for DownloadBlobBuilder<'a, C, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameRequired<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxConcurrencyOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SnapshotOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn snapshot(&self) -> Option<DateTime<Utc>> {
+        self.snapshot
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutOption
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet> ContainerNameSupport<'a> for DownloadBlobBuilder<'a, C, No, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_chunk_size(self, chunk_size: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxConcurrencySupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SnapshotSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_snapshot(self, snapshot: DateTime<Utc>) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: Some(snapshot),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = DownloadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DownloadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DownloadBlobBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    /// Downloads the blob into the file at `path`, creating or truncating it.
+    /// The chunks are written at their offset as soon as they arrive.
+    ///
+    /// Every chunk is checked against the MD5 computed by the service. The
+    /// service computes it only for ranges up to 4 MiB (the default chunk
+    /// size): bigger chunks are not verified.
+    pub async fn finalize_to_file<P>(self, path: P) -> Result<DownloadBlobResponse, AzureError>
+    where
+        P: AsRef<Path>,
+    {
+        let (first_chunk, ranges) = self.get_first_chunk().await?;
+        let total_length = total_length(&first_chunk);
+        let etag = first_chunk.blob.etag.clone();
+        let chunk_count = ranges.len() + 1;
+
+        let mut file = tokio::fs::File::create(path).await?;
+        file.set_len(total_length).await?;
+        file.write_all(&first_chunk.data).await?;
+
+        let this = &self;
+        let etag = etag.as_deref();
+        // the chunks complete out of order: each is written at the start of
+        // the range it was requested for
+        let mut chunks = stream::iter(ranges)
+            .map(|range| async move {
+                let chunk = this.get_chunk(range, etag).await?;
+                Ok::<_, AzureError>((range.start, chunk))
+            })
+            .buffer_unordered(self.max_concurrency());
+        while let Some(result) = chunks.next().await {
+            let (offset, chunk) = result?;
+            file.seek(SeekFrom::Start(offset)).await?;
+            file.write_all(&chunk.data).await?;
+        }
+        file.flush().await?;
+
+        Ok(DownloadBlobResponse::from_first_chunk(
+            first_chunk,
+            chunk_count,
+        ))
+    }
+
+    /// Downloads the blob writing it in order into `writer`. Up to
+    /// `max_concurrency` chunks are fetched ahead of the one being written.
+    /// Chunks are verified as in `finalize_to_file`.
+    pub async fn finalize_to_writer<W>(self, writer: W) -> Result<DownloadBlobResponse, AzureError>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let (first_chunk, ranges) = self.get_first_chunk().await?;
+        let etag = first_chunk.blob.etag.clone();
+        let chunk_count = ranges.len() + 1;
+
+        let mut writer = writer;
+        writer.write_all(&first_chunk.data).await?;
+
+        let mut chunks = stream::iter(ranges)
+            .map(|range| self.get_chunk(range, etag.as_deref()))
+            .buffered(self.max_concurrency());
+        while let Some(chunk) = chunks.next().await {
+            writer.write_all(&chunk?.data).await?;
+        }
+        writer.flush().await?;
+
+        Ok(DownloadBlobResponse::from_first_chunk(
+            first_chunk,
+            chunk_count,
+        ))
+    }
+
+    // Gets the first chunk, which tells the blob length and ETag, and
+    // returns it along with the ranges still to download.
+    async fn get_first_chunk(&self) -> Result<(GetBlobResponse, Vec<Range>), AzureError> {
+        if self.chunk_size() == 0 || self.max_concurrency() == 0 {
+            return Err(AzureError::InputParametersError(
+                "chunk size and max concurrency must be greater than zero".to_owned(),
+            ));
+        }
+
+        let first_range = Range::new(0, self.chunk_size());
+        let first_chunk = match self.get_chunk(first_range, None).await {
+            Ok(chunk) => chunk,
+            // an empty blob has no satisfiable range
+            Err(AzureError::UnexpectedHTTPResult(ref result))
+                if result.status_code() == StatusCode::RANGE_NOT_SATISFIABLE =>
+            {
+                let response = self.get_blob_builder().finalize().await?;
+                // the whole blob digest is returned only if it was stored
+                check_consistency(response.consistency.as_ref(), &response.data, false)?;
+                return Ok((response, Vec::new()));
+            }
+            Err(err) => return Err(err),
+        };
+
+        let ranges = split_in_ranges(
+            first_chunk.data.len() as u64,
+            total_length(&first_chunk),
+            self.chunk_size(),
+        );
+        Ok((first_chunk, ranges))
+    }
+
+    async fn get_chunk(
+        &self,
+        range: Range,
+        etag: Option<&str>,
+    ) -> Result<GetBlobResponse, AzureError> {
+        // GetBlobBuilder asks for the range MD5 when the service can compute it
+        let mut builder = self.get_blob_builder().with_range(&range);
+        if let Some(etag) = etag {
+            builder = builder.with_if_match_condition(IfMatchCondition::Match(etag));
+        }
+
+        let response = builder.finalize().await?;
+
+        // If-Match already protects us but the service is not always
+        // trusted to honour it (the emulator, for example)
+        if let (Some(expected), Some(received)) = (etag, response.blob.etag.as_deref()) {
+            if expected != received {
+                return Err(AzureError::ETagMismatch(
+                    expected.to_owned(),
+                    received.to_owned(),
+                ));
+            }
+        }
+
+        check_consistency(
+            response.consistency.as_ref(),
+            &response.data,
+            range.len() <= MAX_VERIFIED_CHUNK_SIZE,
+        )?;
+        Ok(response)
+    }
+
+    fn get_blob_builder(&self) -> GetBlobBuilder<'a, C, Yes, Yes> {
+        let mut builder = self
+            .client()
+            .get_blob()
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name());
+        if let Some(snapshot) = self.snapshot() {
+            builder = builder.with_snapshot(snapshot);
+        }
+        if let Some(timeout) = self.timeout() {
+            builder = builder.with_timeout(timeout);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            builder = builder.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            builder = builder.with_client_request_id(client_request_id);
+        }
+        builder
+    }
+}
+
+fn total_length(response: &GetBlobResponse) -> u64 {
+    match response.content_range {
+        Some(content_range) => content_range.total_length,
+        None => response.data.len() as u64,
+    }
+}
+
+// the ranges left after the first `downloaded` bytes
+fn split_in_ranges(downloaded: u64, total_length: u64, chunk_size: u64) -> Vec<Range> {
+    (downloaded..total_length)
+        .step_by(chunk_size as usize)
+        .map(|start| Range::new(start, std::cmp::min(start + chunk_size, total_length)))
+        .collect()
+}
+
+// the largest range the service computes the digest of
+const MAX_VERIFIED_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
+
+// `required` fails the check when the service sent no digest
+fn check_consistency(
+    expected: Option<&Consistency>,
+    data: &[u8],
+    required: bool,
+) -> Result<(), AzureError> {
+    let expected = match expected {
+        Some(expected) => expected,
+        None if required => return Err(AzureError::HeadersNotFound(vec![CONTENT_MD5.to_owned()])),
+        None => return Ok(()),
+    };
+
+    let computed = match expected {
+        Consistency::Md5(_) => Consistency::Md5(md5::compute(data).0),
+        Consistency::Crc64(_) => Consistency::Crc64(crc64::checksum(data)),
+    };
+
+    if *expected != computed {
+        Err(AzureError::ConsistencyMismatch(expected.clone(), computed))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_remaining_ranges() {
+        assert_eq!(
+            split_in_ranges(4, 14, 4),
+            vec![Range::new(4, 8), Range::new(8, 12), Range::new(12, 14)]
+        );
+        assert!(split_in_ranges(4, 4, 4).is_empty());
+        assert!(split_in_ranges(3, 3, 4).is_empty());
+    }
+
+    #[test]
+    fn check_chunk_consistency() {
+        let data = b"hello world";
+        let md5 = Consistency::Md5(md5::compute(data).0);
+        let crc64 = Consistency::Crc64(crc64::checksum(data));
+
+        assert!(check_consistency(Some(&md5), data, true).is_ok());
+        assert!(check_consistency(Some(&crc64), data, true).is_ok());
+
+        match check_consistency(Some(&md5), b"hello there", true) {
+            Err(AzureError::ConsistencyMismatch(expected, _)) => assert_eq!(expected, md5),
+            other => panic!("unexpected result {:?}", other),
+        }
+        match check_consistency(Some(&crc64), b"hello there", true) {
+            Err(AzureError::ConsistencyMismatch(expected, _)) => assert_eq!(expected, crc64),
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        assert!(check_consistency(None, data, false).is_ok());
+        assert!(check_consistency(None, data, true).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::blob::responses::GetBlobResponse;
 use crate::blob::{generate_blob_uri, Blob};
+use crate::blob::{IfTagsOption, IfTagsSupport};
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::headers::RANGE_GET_CONTENT_MD5;
 use azure_sdk_core::lease::LeaseId;
@@ -28,6 +29,8 @@ where
     timeout: Option<u64>,
     range: Option<&'a Range>,
     lease_id: Option<&'a LeaseId>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    if_tags: Option<&'a str>,
     client_request_id: Option<&'a str>,
 }
 
@@ -47,6 +50,8 @@ where
             timeout: None,
             range: None,
             lease_id: None,
+            if_match_condition: None,
+            if_tags: None,
             client_request_id: None,
         }
     }
@@ -142,6 +147,32 @@ where
     }
 }
 
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionOption<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfTagsOption<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
 impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
     for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
 where
@@ -174,6 +205,8 @@ where
             timeout: self.timeout,
             range: self.range,
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -198,6 +231,8 @@ where
             timeout: self.timeout,
             range: self.range,
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -224,6 +259,8 @@ where
             timeout: self.timeout,
             range: self.range,
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -250,6 +287,8 @@ where
             timeout: Some(timeout),
             range: self.range,
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -276,6 +315,8 @@ where
             timeout: self.timeout,
             range: Some(range),
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: self.client_request_id,
         }
     }
@@ -302,6 +343,64 @@ where
             timeout: self.timeout,
             range: self.range,
             lease_id: Some(lease_id),
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfMatchConditionSupport<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        GetBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            range: self.range,
+            lease_id: self.lease_id,
+            if_match_condition: Some(if_match_condition),
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> IfTagsSupport<'a>
+    for GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        GetBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            snapshot: self.snapshot,
+            timeout: self.timeout,
+            range: self.range,
+            lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: Some(if_tags),
             client_request_id: self.client_request_id,
         }
     }
@@ -328,6 +427,8 @@ where
             timeout: self.timeout,
             range: self.range,
             lease_id: self.lease_id,
+            if_match_condition: self.if_match_condition,
+            if_tags: self.if_tags,
             client_request_id: Some(client_request_id),
         }
     }
@@ -361,6 +462,8 @@ where
             &uri,
             &Method::GET,
             &|mut request| {
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = IfTagsOption::add_header(&self, request);
                 if let Some(r) = self.range() {
                     request = LeaseIdOption::add_header(&self, request);
                     request = RangeOption::add_header(&self, request);
@@ -0,0 +1,394 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::CopyBlobResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::convert::TryInto;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_source_url: PhantomData<SourceUrlSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    source_url: Option<&'a str>,
+    timeout: Option<u64>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> IncrementalCopyBlobBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> IncrementalCopyBlobBuilder<'a, C, No, No, No> {
+        IncrementalCopyBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_source_url: PhantomData {},
+            source_url: None,
+            timeout: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequired<'a, C>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, SourceUrlSet> ContainerNameRequired<'a>
+    for IncrementalCopyBlobBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet> BlobNameRequired<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SourceUrlRequired<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_url(&self) -> &'a str {
+        self.source_url.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> TimeoutOption
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfSinceConditionOption
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfMatchConditionOption<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequestIdOption<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, SourceUrlSet> ContainerNameSupport<'a>
+    for IncrementalCopyBlobBuilder<'a, C, No, BlobNameSet, SourceUrlSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            timeout: self.timeout,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet> BlobNameSupport<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, No, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            source_url: self.source_url,
+            timeout: self.timeout,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SourceUrlSupport<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_source_url(self, source_url: &'a str) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: Some(source_url),
+            timeout: self.timeout,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> TimeoutSupport
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            timeout: Some(timeout),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfSinceConditionSupport
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            timeout: self.timeout,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> IfMatchConditionSupport<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            timeout: self.timeout,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> ClientRequestIdSupport<'a>
+    for IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = IncrementalCopyBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        IncrementalCopyBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            timeout: self.timeout,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> IncrementalCopyBlobBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CopyBlobResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=incrementalcopy"),
+        );
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = SourceUrlRequired::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        (&headers).try_into()
+    }
+}
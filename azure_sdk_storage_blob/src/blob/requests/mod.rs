@@ -1,40 +1,80 @@
+mod abort_copy_blob_builder;
 mod acquire_blob_lease_builder;
+mod append_block_from_url_builder;
 mod blob_stream_builder;
 mod break_blob_lease_builder;
 mod change_blob_lease_builder;
 mod clear_page_builder;
+mod copy_blob_builder;
 mod copy_blob_from_url_builder;
 mod delete_blob_builder;
 mod delete_blob_snapshot_builder;
+mod download_blob_builder;
 mod get_blob_builder;
+mod get_blob_metadata_builder;
+mod get_blob_properties_builder;
+mod get_blob_tags_builder;
 mod get_block_list_builder;
+mod get_page_ranges_builder;
+mod incremental_copy_blob_builder;
 mod put_append_blob_builder;
 mod put_append_block_builder;
 mod put_block_blob_builder;
 mod put_block_builder;
+mod put_block_from_url_builder;
 mod put_block_list_builder;
 mod put_page_blob_builder;
 mod release_blob_lease_builder;
 mod renew_blob_lease_builder;
+mod resize_page_blob_builder;
+mod set_blob_metadata_builder;
+mod set_blob_properties_builder;
+mod set_blob_sequence_number_builder;
+mod set_blob_tags_builder;
+mod set_blob_tier_builder;
 mod signed_url_builder;
+mod snapshot_blob_builder;
+mod undelete_blob_builder;
 mod update_page_builder;
+mod upload_blob_builder;
+mod wait_for_blob_copy_builder;
+pub use self::abort_copy_blob_builder::AbortCopyBlobBuilder;
 pub use self::acquire_blob_lease_builder::AcquireBlobLeaseBuilder;
+pub use self::append_block_from_url_builder::AppendBlockFromUrlBuilder;
 pub use self::blob_stream_builder::BlobStreamBuilder;
 pub use self::break_blob_lease_builder::BreakBlobLeaseBuilder;
 pub use self::change_blob_lease_builder::ChangeBlobLeaseBuilder;
 pub use self::clear_page_builder::ClearPageBuilder;
+pub use self::copy_blob_builder::CopyBlobBuilder;
 pub use self::delete_blob_builder::DeleteBlobBuilder;
 pub use self::delete_blob_snapshot_builder::DeleteBlobSnapshotBuilder;
+pub use self::download_blob_builder::DownloadBlobBuilder;
 pub use self::get_blob_builder::GetBlobBuilder;
+pub use self::get_blob_metadata_builder::GetBlobMetadataBuilder;
+pub use self::get_blob_properties_builder::GetBlobPropertiesBuilder;
+pub use self::get_blob_tags_builder::GetBlobTagsBuilder;
 pub use self::get_block_list_builder::GetBlockListBuilder;
+pub use self::get_page_ranges_builder::GetPageRangesBuilder;
+pub use self::incremental_copy_blob_builder::IncrementalCopyBlobBuilder;
 pub use self::put_append_blob_builder::PutAppendBlobBuilder;
 pub use self::put_append_block_builder::PutAppendBlockBuilder;
 pub use self::put_block_blob_builder::PutBlockBlobBuilder;
 pub use self::put_block_builder::PutBlockBuilder;
+pub use self::put_block_from_url_builder::PutBlockFromUrlBuilder;
 pub use self::put_block_list_builder::PutBlockListBuilder;
 pub use self::put_page_blob_builder::PutPageBlobBuilder;
 pub use self::release_blob_lease_builder::ReleaseBlobLeaseBuilder;
 pub use self::renew_blob_lease_builder::RenewBlobLeaseBuilder;
+pub use self::resize_page_blob_builder::ResizePageBlobBuilder;
+pub use self::set_blob_metadata_builder::SetBlobMetadataBuilder;
+pub use self::set_blob_properties_builder::SetBlobPropertiesBuilder;
+pub use self::set_blob_sequence_number_builder::SetBlobSequenceNumberBuilder;
+pub use self::set_blob_tags_builder::SetBlobTagsBuilder;
+pub use self::set_blob_tier_builder::SetBlobTierBuilder;
 pub use self::signed_url_builder::SignedUrlBuilder;
+pub use self::snapshot_blob_builder::SnapshotBlobBuilder;
+pub use self::undelete_blob_builder::UndeleteBlobBuilder;
 pub use self::update_page_builder::UpdatePageBuilder;
+pub use self::upload_blob_builder::UploadBlobBuilder;
+pub use self::wait_for_blob_copy_builder::WaitForBlobCopyBuilder;
 pub use copy_blob_from_url_builder::CopyBlobFromUrlBuilder;
@@ -4,6 +4,7 @@ use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::lease::LeaseId;
 use azure_sdk_core::modify_conditions::IfMatchCondition;
 use azure_sdk_core::prelude::*;
+use azure_sdk_core::retry_policy::NonRetriable;
 use azure_sdk_core::{No, ToAssign, Yes};
 use azure_sdk_storage_core::prelude::*;
 use hyper::{Method, StatusCode};
@@ -492,7 +493,8 @@ where
                 request = IfMatchConditionOption::add_header(&self, request);
                 request = ClientRequestIdOption::add_header(&self, request);
                 request = AppendPositionOption::add_header(&self, request);
-                request
+                // a replayed append would write the block twice
+                request.extension(NonRetriable)
             },
             Some(self.body()),
         )?;
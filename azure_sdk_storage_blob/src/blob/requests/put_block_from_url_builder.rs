@@ -0,0 +1,533 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::PutBlockResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_source_url: PhantomData<SourceUrlSet>,
+    p_block_id: PhantomData<BlockIdSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    source_url: Option<&'a str>,
+    block_id: Option<&'a [u8]>,
+    source_range: Option<&'a Range>,
+    source_content_md5: Option<&'a [u8]>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> PutBlockFromUrlBuilder<'a, C, No, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> PutBlockFromUrlBuilder<'a, C, No, No, No, No> {
+        PutBlockFromUrlBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_source_url: PhantomData {},
+            source_url: None,
+            p_block_id: PhantomData {},
+            block_id: None,
+            source_range: None,
+            source_content_md5: None,
+            timeout: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> ClientRequired<'a, C>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, SourceUrlSet, BlockIdSet> ContainerNameRequired<'a>
+    for PutBlockFromUrlBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet, BlockIdSet> BlobNameRequired<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, BlockIdSet> SourceUrlRequired<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_url(&self) -> &'a str {
+        self.source_url.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> BlockIdRequired<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn block_id(&self) -> &'a [u8] {
+        self.block_id.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> SourceRangeOption<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_range(&self) -> Option<&'a Range> {
+        self.source_range
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> SourceContentMD5Option<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn source_content_md5(&self) -> Option<&'a [u8]> {
+        self.source_content_md5
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> TimeoutOption
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> LeaseIdOption<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> ClientRequestIdOption<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, SourceUrlSet, BlockIdSet> ContainerNameSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, No, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, Yes, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, SourceUrlSet, BlockIdSet> BlobNameSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, No, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, Yes, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, BlockIdSet> SourceUrlSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, No, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes, BlockIdSet>;
+
+    #[inline]
+    fn with_source_url(self, source_url: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: Some(source_url),
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet> BlockIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, Yes>;
+
+    #[inline]
+    fn with_block_id(self, block_id: &'a [u8]) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: Some(block_id),
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> SourceRangeSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_range(self, source_range: &'a Range) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: Some(source_range),
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> SourceContentMD5Support<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_source_content_md5(self, source_content_md5: &'a [u8]) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: Some(source_content_md5),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> TimeoutSupport
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> LeaseIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet> ClientRequestIdSupport<'a>
+    for PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SourceUrlSet: ToAssign,
+    BlockIdSet: ToAssign,
+    C: Client,
+{
+    type O = PutBlockFromUrlBuilder<'a, C, ContainerNameSet, BlobNameSet, SourceUrlSet, BlockIdSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutBlockFromUrlBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_source_url: PhantomData {},
+            p_block_id: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            source_url: self.source_url,
+            block_id: self.block_id,
+            source_range: self.source_range,
+            source_content_md5: self.source_content_md5,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> PutBlockFromUrlBuilder<'a, C, Yes, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    pub async fn finalize(self) -> Result<PutBlockResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=block"),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+        uri = format!("{}&{}", uri, BlockIdRequired::to_uri_parameter(&self));
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = SourceUrlRequired::add_header(&self, request);
+                request = SourceRangeOption::add_header(&self, request);
+                request = SourceContentMD5Option::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutBlockResponse::from_headers(&headers)
+    }
+}
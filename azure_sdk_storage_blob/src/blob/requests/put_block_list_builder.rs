@@ -2,6 +2,7 @@ use crate::blob::generate_blob_uri;
 use crate::blob::responses::PutBlockListResponse;
 use crate::blob::BlockList;
 use crate::blob::{BlockListRequired, BlockListSupport};
+use crate::blob::{Tags, TagsOption, TagsSupport};
 use azure_sdk_core::add_content_md5_header;
 use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
 use azure_sdk_core::lease::LeaseId;
@@ -36,6 +37,7 @@ where
     cache_control: Option<&'a str>,
     content_disposition: Option<&'a str>,
     metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    tags: Option<&'a Tags>,
     lease_id: Option<&'a LeaseId>,
     client_request_id: Option<&'a str>,
 }
@@ -62,6 +64,7 @@ where
             cache_control: None,
             content_disposition: None,
             metadata: None,
+            tags: None,
             lease_id: None,
             client_request_id: None,
         }
@@ -233,6 +236,21 @@ where
     }
 }
 
+impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> TagsOption<'a>
+    for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    C: Client,
+    T: Borrow<[u8]> + 'a,
+{
+    #[inline]
+    fn tags(&self) -> Option<&'a Tags> {
+        self.tags
+    }
+}
+
 impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> LeaseIdOption<'a>
     for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
 where
@@ -290,6 +308,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -323,6 +342,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -356,6 +376,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -390,6 +411,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -424,6 +446,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -458,6 +481,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -492,6 +516,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -526,6 +551,7 @@ where
             cache_control: Some(cache_control),
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -560,6 +586,7 @@ where
             cache_control: self.cache_control,
             content_disposition: Some(content_disposition),
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -594,6 +621,42 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: Some(metadata),
+            tags: self.tags,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet> TagsSupport<'a>
+    for PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    BlockListSet: ToAssign,
+    C: Client,
+    T: Borrow<[u8]> + 'a,
+{
+    type O = PutBlockListBuilder<'a, C, T, ContainerNameSet, BlobNameSet, BlockListSet>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a Tags) -> Self::O {
+        PutBlockListBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_block_list: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            block_list: self.block_list,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            tags: Some(tags),
             lease_id: self.lease_id,
             client_request_id: self.client_request_id,
         }
@@ -628,6 +691,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: Some(lease_id),
             client_request_id: self.client_request_id,
         }
@@ -662,6 +726,7 @@ where
             cache_control: self.cache_control,
             content_disposition: self.content_disposition,
             metadata: self.metadata,
+            tags: self.tags,
             lease_id: self.lease_id,
             client_request_id: Some(client_request_id),
         }
@@ -712,6 +777,7 @@ where
                 request = CacheControlOption::add_header(&self, request);
                 request = ContentDispositionOption::add_header(&self, request);
                 request = MetadataOption::add_header(&self, request);
+                request = TagsOption::add_header(&self, request);
                 request = LeaseIdOption::add_header(&self, request);
                 request = ClientRequestIdOption::add_header(&self, request);
                 request
@@ -0,0 +1,447 @@
+use crate::blob::generate_blob_uri;
+use crate::blob::responses::ResizePageBlobResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_content_length: PhantomData<PageBlobLengthSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    content_length: Option<u64>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> ResizePageBlobBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> ResizePageBlobBuilder<'a, C, No, No, No> {
+        ResizePageBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_content_length: PhantomData {},
+            content_length: None,
+            timeout: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> ClientRequired<'a, C>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, PageBlobLengthSet> ContainerNameRequired<'a>
+    for ResizePageBlobBuilder<'a, C, Yes, BlobNameSet, PageBlobLengthSet>
+where
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, PageBlobLengthSet> BlobNameRequired<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, Yes, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> PageBlobLengthRequired
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_length(&self) -> u64 {
+        self.content_length.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> TimeoutOption
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> LeaseIdOption<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> IfSinceConditionOption
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> IfMatchConditionOption<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> ClientRequestIdOption<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, PageBlobLengthSet> ContainerNameSupport<'a>
+    for ResizePageBlobBuilder<'a, C, No, BlobNameSet, PageBlobLengthSet>
+where
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, Yes, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, PageBlobLengthSet> BlobNameSupport<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, No, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, Yes, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> PageBlobLengthSupport
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_content_length(self, content_length: u64) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: Some(content_length),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> TimeoutSupport
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> LeaseIdSupport<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> IfSinceConditionSupport
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> IfMatchConditionSupport<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet> ClientRequestIdSupport<'a>
+    for ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    PageBlobLengthSet: ToAssign,
+    C: Client,
+{
+    type O = ResizePageBlobBuilder<'a, C, ContainerNameSet, BlobNameSet, PageBlobLengthSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ResizePageBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_content_length: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            content_length: self.content_length,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> ResizePageBlobBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<ResizePageBlobResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=properties"),
+        );
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = PageBlobLengthRequired::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        ResizePageBlobResponse::from_headers(&headers)
+    }
+}
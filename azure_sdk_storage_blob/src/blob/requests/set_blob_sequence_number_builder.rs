@@ -0,0 +1,460 @@
+use crate::blob::responses::SetBlobSequenceNumberResponse;
+use crate::blob::{
+    generate_blob_uri, SequenceNumberAction, SequenceNumberActionRequired,
+    SequenceNumberActionSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobSequenceNumberBuilder<
+    'a,
+    C,
+    ContainerNameSet,
+    BlobNameSet,
+    SequenceNumberActionSet,
+> where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_sequence_number_action: PhantomData<SequenceNumberActionSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    sequence_number_action: Option<SequenceNumberAction>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> SetBlobSequenceNumberBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> SetBlobSequenceNumberBuilder<'a, C, No, No, No> {
+        SetBlobSequenceNumberBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_sequence_number_action: PhantomData {},
+            sequence_number_action: None,
+            timeout: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> ClientRequired<'a, C>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, SequenceNumberActionSet> ContainerNameRequired<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, Yes, BlobNameSet, SequenceNumberActionSet>
+where
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, SequenceNumberActionSet> BlobNameRequired<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, Yes, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SequenceNumberActionRequired
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn sequence_number_action(&self) -> SequenceNumberAction {
+        self.sequence_number_action.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> TimeoutOption
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> LeaseIdOption<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> IfSinceConditionOption
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> IfMatchConditionOption<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> ClientRequestIdOption<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, SequenceNumberActionSet> ContainerNameSupport<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, No, BlobNameSet, SequenceNumberActionSet>
+where
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobSequenceNumberBuilder<'a, C, Yes, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, SequenceNumberActionSet> BlobNameSupport<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, No, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, Yes, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> SequenceNumberActionSupport
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_sequence_number_action(self, sequence_number_action: SequenceNumberAction) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: Some(sequence_number_action),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> TimeoutSupport
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O =
+        SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> LeaseIdSupport<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O =
+        SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> IfSinceConditionSupport
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O =
+        SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> IfMatchConditionSupport<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O =
+        SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet> ClientRequestIdSupport<'a>
+    for SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    SequenceNumberActionSet: ToAssign,
+    C: Client,
+{
+    type O =
+        SetBlobSequenceNumberBuilder<'a, C, ContainerNameSet, BlobNameSet, SequenceNumberActionSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobSequenceNumberBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_sequence_number_action: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            sequence_number_action: self.sequence_number_action,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> SetBlobSequenceNumberBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<SetBlobSequenceNumberResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=properties"),
+        );
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = SequenceNumberActionRequired::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetBlobSequenceNumberResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,398 @@
+use crate::blob::responses::SetBlobTagsResponse;
+use crate::blob::{
+    generate_blob_uri, IfTagsOption, IfTagsSupport, Tags, TagsRequired, TagsSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    p_tags: PhantomData<TagsSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    tags: Option<&'a Tags>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    if_tags: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> SetBlobTagsBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> SetBlobTagsBuilder<'a, C, No, No, No> {
+        SetBlobTagsBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            p_tags: PhantomData {},
+            tags: None,
+            timeout: None,
+            lease_id: None,
+            if_tags: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> ClientRequired<'a, C>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet, TagsSet> ContainerNameRequired<'a>
+    for SetBlobTagsBuilder<'a, C, Yes, BlobNameSet, TagsSet>
+where
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, TagsSet> BlobNameRequired<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, Yes, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TagsRequired<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn tags(&self) -> &'a Tags {
+        self.tags.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> TimeoutOption
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> LeaseIdOption<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> IfTagsOption<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_tags(&self) -> Option<&'a str> {
+        self.if_tags
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> ClientRequestIdOption<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet, TagsSet> ContainerNameSupport<'a>
+    for SetBlobTagsBuilder<'a, C, No, BlobNameSet, TagsSet>
+where
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, Yes, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            tags: self.tags,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, TagsSet> BlobNameSupport<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, No, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, Yes, TagsSet>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            tags: self.tags,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TagsSupport<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, Yes>;
+
+    #[inline]
+    fn with_tags(self, tags: &'a Tags) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: Some(tags),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> TimeoutSupport
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> LeaseIdSupport<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            if_tags: self.if_tags,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> IfTagsSupport<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_if_tags(self, if_tags: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: Some(if_tags),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet, TagsSet> ClientRequestIdSupport<'a>
+    for SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    TagsSet: ToAssign,
+    C: Client,
+{
+    type O = SetBlobTagsBuilder<'a, C, ContainerNameSet, BlobNameSet, TagsSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetBlobTagsBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            p_tags: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            tags: self.tags,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            if_tags: self.if_tags,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> SetBlobTagsBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<SetBlobTagsResponse, AzureError> {
+        let mut uri = generate_blob_uri(
+            self.client(),
+            self.container_name(),
+            self.blob_name(),
+            Some("comp=tags"),
+        );
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let body = self.tags().to_xml();
+        trace!("body == {}", body);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfTagsOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        SetBlobTagsResponse::from_headers(&headers)
+    }
+}
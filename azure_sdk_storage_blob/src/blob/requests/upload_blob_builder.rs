@@ -0,0 +1,1297 @@
+use crate::blob::responses::UploadBlobResponse;
+use crate::blob::{BlobBlockType, BlockList, BlockListSupport};
+use crate::blob::{
+    MaxBlockRetriesOption, MaxBlockRetriesSupport, MaxConcurrencyOption, MaxConcurrencySupport,
+};
+use crate::Blob;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::retry_policy::{is_retriable_status, RetryPolicy};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use futures::io::{AsyncRead, AsyncReadExt};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::path::Path;
+use std::time::Duration;
+use tokio_util::compat::Tokio02AsyncReadCompatExt;
+
+#[derive(Debug, Clone)]
+pub struct UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_blob_name: PhantomData<BlobNameSet>,
+    container_name: Option<&'a str>,
+    blob_name: Option<&'a str>,
+    chunk_size: u64,
+    max_concurrency: usize,
+    max_block_retries: u32,
+    timeout: Option<u64>,
+    content_type: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> UploadBlobBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> UploadBlobBuilder<'a, C, No, No> {
+        UploadBlobBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            p_blob_name: PhantomData {},
+            blob_name: None,
+            chunk_size: 4194304,
+            max_concurrency: 4,
+            max_block_retries: 3,
+            timeout: None,
+            content_type: None,
+            content_encoding: None,
+            content_language: None,
+            cache_control: None,
+            content_disposition: None,
+            metadata: None,
+            lease_id: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequired<'a, C>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, BlobNameSet> ContainerNameRequired<'a> for UploadBlobBuilder<'a, C, Yes, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameRequired<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, Yes>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn blob_name(&self) -> &'a str {
+        self.blob_name.unwrap()
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn chunk_size(&self) -> u64 {
+        self.chunk_size
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxConcurrencyOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxBlockRetriesOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_block_retries(&self) -> u32 {
+        self.max_block_retries
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutOption
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentTypeOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentEncodingOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentLanguageOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CacheControlOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentDispositionOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MetadataOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdOption<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, BlobNameSet> ContainerNameSupport<'a> for UploadBlobBuilder<'a, C, No, BlobNameSet>
+where
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, Yes, BlobNameSet>;
+
+    #[inline]
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: Some(container_name),
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet> BlobNameSupport<'a> for UploadBlobBuilder<'a, C, ContainerNameSet, No>
+where
+    ContainerNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, Yes>;
+
+    #[inline]
+    fn with_blob_name(self, blob_name: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: Some(blob_name),
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ChunkSizeSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_chunk_size(self, chunk_size: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxConcurrencySupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_concurrency(self, max_concurrency: usize) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MaxBlockRetriesSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_max_block_retries(self, max_block_retries: u32) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> TimeoutSupport
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: Some(timeout),
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentTypeSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_type(self, content_type: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: Some(content_type),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentEncodingSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_encoding(self, content_encoding: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentLanguageSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_language(self, content_language: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> CacheControlSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_cache_control(self, cache_control: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ContentDispositionSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_content_disposition(self, content_disposition: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> MetadataSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: Some(metadata),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> LeaseIdSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, ContainerNameSet, BlobNameSet> ClientRequestIdSupport<'a>
+    for UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>
+where
+    ContainerNameSet: ToAssign,
+    BlobNameSet: ToAssign,
+    C: Client,
+{
+    type O = UploadBlobBuilder<'a, C, ContainerNameSet, BlobNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        UploadBlobBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_blob_name: PhantomData {},
+            container_name: self.container_name,
+            blob_name: self.blob_name,
+            chunk_size: self.chunk_size,
+            max_concurrency: self.max_concurrency,
+            max_block_retries: self.max_block_retries,
+            timeout: self.timeout,
+            content_type: self.content_type,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            metadata: self.metadata,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> UploadBlobBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    /// Uploads the content of the file at `path`.
+    pub async fn finalize_from_file<P>(self, path: P) -> Result<UploadBlobResponse, AzureError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = tokio::fs::File::open(path).await?;
+        self.finalize_from_reader(file.compat()).await
+    }
+
+    /// Uploads everything `reader` yields. Payloads that fit in a single block
+    /// are sent with one Put Blob call, bigger ones are split in blocks of
+    /// `chunk_size` bytes uploaded in parallel and then committed. A block
+    /// failing with a transient error is retried up to `max_block_retries`
+    /// times, after the wait asked by the service or an exponential backoff.
+    pub async fn finalize_from_reader<R>(self, reader: R) -> Result<UploadBlobResponse, AzureError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let block_size = self.chunk_size() as usize;
+        if block_size == 0 || self.chunk_size() > MAX_BLOCK_SIZE {
+            return Err(AzureError::InputParametersError(format!(
+                "the block size must be between 1 and {} bytes",
+                MAX_BLOCK_SIZE
+            )));
+        }
+        let max_concurrency = std::cmp::max(self.max_concurrency(), 1);
+
+        let mut reader = reader;
+        let block = read_block(&mut reader, block_size).await?;
+        let next_block = read_block(&mut reader, block_size).await?;
+
+        if next_block.is_empty() {
+            return self.put_single_blob(&block).await;
+        }
+
+        // the next block is read while the previous ones are being uploaded,
+        // until max_concurrency uploads are in flight
+        let this = &self;
+        let mut uploads = Box::pin(
+            stream::iter(vec![Ok(block), Ok(next_block)])
+                .chain(read_blocks(reader, block_size))
+                .enumerate()
+                .map(|(index, block)| async move {
+                    if index == MAX_BLOCKS {
+                        return Err(AzureError::InputParametersError(format!(
+                            "a blob cannot have more than {} blocks, increase the block size",
+                            MAX_BLOCKS
+                        )));
+                    }
+                    this.put_block_with_retries(generate_block_id(index), block?)
+                        .await
+                })
+                .buffer_unordered(max_concurrency),
+        );
+
+        let mut block_count = 0;
+        while let Some(result) = uploads.next().await {
+            result?;
+            block_count += 1;
+        }
+
+        let block_list = BlockList {
+            blocks: (0..block_count)
+                .map(|index| BlobBlockType::Latest(generate_block_id(index)))
+                .collect(),
+        };
+
+        let mut builder = self
+            .client()
+            .put_block_list()
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_block_list(&block_list);
+        if let Some(timeout) = self.timeout() {
+            builder = builder.with_timeout(timeout);
+        }
+        if let Some(content_type) = self.content_type() {
+            builder = builder.with_content_type(content_type);
+        }
+        if let Some(content_encoding) = self.content_encoding() {
+            builder = builder.with_content_encoding(content_encoding);
+        }
+        if let Some(content_language) = self.content_language() {
+            builder = builder.with_content_language(content_language);
+        }
+        if let Some(cache_control) = self.cache_control() {
+            builder = builder.with_cache_control(cache_control);
+        }
+        if let Some(content_disposition) = self.content_disposition() {
+            builder = builder.with_content_disposition(content_disposition);
+        }
+        if let Some(metadata) = self.metadata() {
+            builder = builder.with_metadata(metadata);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            builder = builder.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            builder = builder.with_client_request_id(client_request_id);
+        }
+
+        let response = builder.finalize().await?;
+        Ok(UploadBlobResponse::from_put_block_list_response(
+            response,
+            block_count,
+        ))
+    }
+
+    async fn put_single_blob(&self, body: &[u8]) -> Result<UploadBlobResponse, AzureError> {
+        let md5 = md5::compute(body);
+
+        let mut builder = self
+            .client()
+            .put_block_blob()
+            .with_container_name(self.container_name())
+            .with_blob_name(self.blob_name())
+            .with_body(body)
+            .with_content_md5(&md5[..]);
+        if let Some(timeout) = self.timeout() {
+            builder = builder.with_timeout(timeout);
+        }
+        if let Some(content_type) = self.content_type() {
+            builder = builder.with_content_type(content_type);
+        }
+        if let Some(content_encoding) = self.content_encoding() {
+            builder = builder.with_content_encoding(content_encoding);
+        }
+        if let Some(content_language) = self.content_language() {
+            builder = builder.with_content_language(content_language);
+        }
+        if let Some(cache_control) = self.cache_control() {
+            builder = builder.with_cache_control(cache_control);
+        }
+        if let Some(content_disposition) = self.content_disposition() {
+            builder = builder.with_content_disposition(content_disposition);
+        }
+        if let Some(metadata) = self.metadata() {
+            builder = builder.with_metadata(metadata);
+        }
+        if let Some(lease_id) = self.lease_id() {
+            builder = builder.with_lease_id(lease_id);
+        }
+        if let Some(client_request_id) = self.client_request_id() {
+            builder = builder.with_client_request_id(client_request_id);
+        }
+
+        let response = builder.finalize().await?;
+        Ok(UploadBlobResponse::from_put_block_blob_response(response))
+    }
+
+    async fn put_block_with_retries(
+        &self,
+        block_id: Vec<u8>,
+        body: Vec<u8>,
+    ) -> Result<(), AzureError> {
+        let md5 = md5::compute(&body);
+        let retry_policy = RetryPolicy::default();
+
+        let mut retry = 0;
+        loop {
+            let mut builder = self
+                .client()
+                .put_block()
+                .with_container_name(self.container_name())
+                .with_blob_name(self.blob_name())
+                .with_block_id(&block_id)
+                .with_body(&body)
+                .with_content_md5(&md5[..]);
+            if let Some(timeout) = self.timeout() {
+                builder = builder.with_timeout(timeout);
+            }
+            if let Some(lease_id) = self.lease_id() {
+                builder = builder.with_lease_id(lease_id);
+            }
+            if let Some(client_request_id) = self.client_request_id() {
+                builder = builder.with_client_request_id(client_request_id);
+            }
+
+            match builder.finalize().await {
+                Ok(_) => return Ok(()),
+                Err(err) if retry < self.max_block_retries() && is_transient(&err) => {
+                    let delay = retry_delay(&err, &retry_policy, retry);
+                    retry += 1;
+                    warn!(
+                        "put block {} failed ({}), retry {} of {} in {:?}",
+                        base64::encode(&block_id),
+                        err,
+                        retry,
+                        self.max_block_retries(),
+                        delay
+                    );
+                    if delay > Duration::from_secs(0) {
+                        tokio::time::delay_for(delay).await;
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+// the maximum block size for the service version in use
+const MAX_BLOCK_SIZE: u64 = 100 * 1024 * 1024;
+const MAX_BLOCKS: usize = 50_000;
+
+// block ids must all have the same length within a blob
+fn generate_block_id(index: usize) -> Vec<u8> {
+    format!("{:08}", index).into_bytes()
+}
+
+async fn read_block<R>(reader: &mut R, block_size: usize) -> Result<Vec<u8>, AzureError>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut block = vec![0; block_size];
+    let mut filled = 0;
+    while filled < block_size {
+        let read = reader.read(&mut block[filled..]).await?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+    block.truncate(filled);
+    Ok(block)
+}
+
+// the blocks left in `reader`, up to the first read error
+fn read_blocks<R>(reader: R, block_size: usize) -> impl Stream<Item = Result<Vec<u8>, AzureError>>
+where
+    R: AsyncRead + Unpin,
+{
+    stream::unfold(Some(reader), move |reader| async move {
+        let mut reader = reader?;
+        match read_block(&mut reader, block_size).await {
+            Ok(block) if block.is_empty() => None,
+            Ok(block) => Some((Ok(block), Some(reader))),
+            Err(err) => Some((Err(err), None)),
+        }
+    })
+}
+
+// the wait asked by the service, or else the exponential backoff
+fn retry_delay(err: &AzureError, retry_policy: &RetryPolicy, retry: u32) -> Duration {
+    match err {
+        AzureError::UnexpectedHTTPResult(result) => result.retry_after(),
+        _ => None,
+    }
+    .unwrap_or_else(|| retry_policy.delay_with_jitter(retry))
+}
+
+fn is_transient(err: &AzureError) -> bool {
+    match err {
+        AzureError::UnexpectedHTTPResult(result) => is_retriable_status(result.status_code()),
+        AzureError::HyperError(_) | AzureError::IOError(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use azure_sdk_core::errors::UnexpectedHTTPResult;
+    use azure_sdk_core::headers::RETRY_AFTER_MS;
+    use azure_sdk_core::http_client::{HttpClient, ResponseFuture};
+    use futures::executor::block_on;
+    use futures::future::Future;
+    use hyper::{Body, HeaderMap, Request, Response, StatusCode};
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+
+    // completes on the second poll, letting the other uploads make progress
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct MockState {
+        in_flight: usize,
+        max_in_flight: usize,
+        // first byte of the body of every Put Block sent
+        put_blocks: Vec<u8>,
+        block_lists: Vec<String>,
+        // Put Block fails with a 500 this many times for the block starting with the byte
+        failing_block: Option<(u8, u32)>,
+        // for every block read by SlowReader, whether a Put Block completed meanwhile
+        uploaded_while_reading: Vec<bool>,
+    }
+
+    #[derive(Debug, Default)]
+    struct MockHttpClient {
+        state: Arc<Mutex<MockState>>,
+    }
+
+    fn response(status: StatusCode) -> Response<Body> {
+        Response::builder()
+            .status(status)
+            .header("ETag", "\"0x8D7F2A4C0D1E2F3\"")
+            .header("Last-Modified", "Mon, 04 May 2020 10:00:00 GMT")
+            .header("Content-MD5", "1B2M2Y8AsgTpgAmY7PhCfg==")
+            .header("x-ms-request-id", "c9da6455-213d-42c9-9a79-3e9149a57833")
+            .header("Date", "Mon, 04 May 2020 10:00:00 GMT")
+            .header("x-ms-request-server-encrypted", "true")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn request(&self, request: Request<Body>) -> ResponseFuture {
+            let state = self.state.clone();
+            {
+                let mut state = state.lock().unwrap();
+                state.in_flight += 1;
+                state.max_in_flight = std::cmp::max(state.max_in_flight, state.in_flight);
+            }
+            let is_block_list = request.uri().query().unwrap().contains("comp=blocklist");
+
+            Box::pin(async move {
+                let body = hyper::body::to_bytes(request.into_body()).await?;
+                let first_byte = body.first().cloned().unwrap_or_default();
+
+                // the first block is the slowest, so the blocks complete out of order
+                let yields = if is_block_list || first_byte != 0 {
+                    1
+                } else {
+                    3
+                };
+                for _ in 0..yields {
+                    YieldNow(false).await;
+                }
+
+                let mut state = state.lock().unwrap();
+                state.in_flight -= 1;
+                if is_block_list {
+                    state
+                        .block_lists
+                        .push(String::from_utf8(body.to_vec()).unwrap());
+                    return Ok(response(StatusCode::CREATED));
+                }
+
+                state.put_blocks.push(first_byte);
+                match state.failing_block {
+                    Some((block, failures)) if block == first_byte && failures > 0 => {
+                        state.failing_block = Some((block, failures - 1));
+                        // no wait, so that the retry needs no timer
+                        let mut response = response(StatusCode::INTERNAL_SERVER_ERROR);
+                        response
+                            .headers_mut()
+                            .insert(RETRY_AFTER_MS, "0".parse().unwrap());
+                        Ok(response)
+                    }
+                    _ => Ok(response(StatusCode::CREATED)),
+                }
+            })
+        }
+    }
+
+    // a reader staying pending for a few polls before every read
+    struct SlowReader {
+        data: Vec<u8>,
+        polls: u32,
+        put_blocks: Option<usize>,
+        state: Arc<Mutex<MockState>>,
+    }
+
+    impl AsyncRead for SlowReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<std::io::Result<usize>> {
+            let put_blocks = self.state.lock().unwrap().put_blocks.len();
+            if self.polls < 5 {
+                self.polls += 1;
+                self.put_blocks.get_or_insert(put_blocks);
+                cx.waker().wake_by_ref();
+                return Poll::Pending;
+            }
+
+            let uploaded = self.put_blocks.take().unwrap() < put_blocks;
+            self.state
+                .lock()
+                .unwrap()
+                .uploaded_while_reading
+                .push(uploaded);
+            self.polls = 0;
+
+            let read = std::cmp::min(buf.len(), self.data.len());
+            buf[..read].copy_from_slice(&self.data[..read]);
+            self.data.drain(..read);
+            Poll::Ready(Ok(read))
+        }
+    }
+
+    // five blocks of 4 bytes, every byte holding the index of its block
+    fn data() -> Vec<u8> {
+        (0..5u8).flat_map(|block| vec![block; 4]).collect()
+    }
+
+    fn upload(mock: &Arc<MockHttpClient>) -> Result<UploadBlobResponse, AzureError> {
+        upload_from(mock, &data()[..])
+    }
+
+    fn upload_from<R>(
+        mock: &Arc<MockHttpClient>,
+        reader: R,
+    ) -> Result<UploadBlobResponse, AzureError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let client = azure_sdk_storage_core::client::with_access_key("mindrust", "dXNlbGVzcw==")
+            .with_http_client(mock.clone());
+
+        block_on(
+            client
+                .upload_blob()
+                .with_container_name("container")
+                .with_blob_name("blob")
+                .with_chunk_size(4)
+                .with_max_concurrency(2)
+                .with_max_block_retries(1)
+                .finalize_from_reader(reader),
+        )
+    }
+
+    fn block_list_ids(block_list: &str) -> Vec<String> {
+        block_list
+            .split("<Latest>")
+            .skip(1)
+            .map(|latest| latest.split("</Latest>").next().unwrap().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn block_ids_have_the_same_length() {
+        assert_eq!(generate_block_id(0), b"00000000");
+        assert_eq!(generate_block_id(49_999), b"00049999");
+    }
+
+    #[test]
+    fn read_blocks() {
+        let mut reader: &[u8] = &[1, 2, 3, 4, 5, 6, 7];
+
+        assert_eq!(block_on(read_block(&mut reader, 3)).unwrap(), vec![1, 2, 3]);
+        assert_eq!(block_on(read_block(&mut reader, 3)).unwrap(), vec![4, 5, 6]);
+        assert_eq!(block_on(read_block(&mut reader, 3)).unwrap(), vec![7]);
+        assert!(block_on(read_block(&mut reader, 3)).unwrap().is_empty());
+    }
+
+    #[test]
+    fn blocks_are_committed_in_order() {
+        let mock = Arc::new(MockHttpClient::default());
+
+        let response = upload(&mock).unwrap();
+        assert_eq!(response.block_count, 5);
+
+        let state = mock.state.lock().unwrap();
+        // the first block completed last, while the others were uploaded
+        assert_ne!(state.put_blocks.first(), Some(&0));
+        assert_eq!(state.max_in_flight, 2);
+        assert_eq!(state.block_lists.len(), 1);
+        assert_eq!(
+            block_list_ids(&state.block_lists[0]),
+            (0..5)
+                .map(|index| base64::encode(generate_block_id(index)))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn failed_block_is_retried() {
+        let mock = Arc::new(MockHttpClient::default());
+        mock.state.lock().unwrap().failing_block = Some((2, 1));
+
+        assert!(upload(&mock).is_ok());
+
+        let state = mock.state.lock().unwrap();
+        assert_eq!(
+            state.put_blocks.iter().filter(|block| **block == 2).count(),
+            2
+        );
+        assert_eq!(state.block_lists.len(), 1);
+    }
+
+    #[test]
+    fn failed_block_aborts_the_upload() {
+        let mock = Arc::new(MockHttpClient::default());
+        mock.state.lock().unwrap().failing_block = Some((2, u32::MAX));
+
+        match upload(&mock) {
+            Err(AzureError::UnexpectedHTTPResult(result)) => {
+                assert_eq!(result.status_code(), StatusCode::INTERNAL_SERVER_ERROR)
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let state = mock.state.lock().unwrap();
+        // the first attempt and the single retry
+        assert_eq!(
+            state.put_blocks.iter().filter(|block| **block == 2).count(),
+            2
+        );
+        assert!(state.block_lists.is_empty());
+    }
+
+    #[test]
+    fn blocks_are_uploaded_while_reading() {
+        let mock = Arc::new(MockHttpClient::default());
+        let reader = SlowReader {
+            data: data(),
+            polls: 0,
+            put_blocks: None,
+            state: mock.state.clone(),
+        };
+
+        assert_eq!(upload_from(&mock, reader).unwrap().block_count, 5);
+
+        let state = mock.state.lock().unwrap();
+        // the first two blocks are read before uploading anything
+        assert_eq!(state.uploaded_while_reading[..2], [false, false]);
+        assert!(state.uploaded_while_reading[2..]
+            .iter()
+            .any(|uploaded| *uploaded));
+        assert_eq!(state.block_lists.len(), 1);
+    }
+
+    #[test]
+    fn retry_delay_honours_retry_after() {
+        let retry_policy = RetryPolicy::fixed(Duration::from_secs(2));
+        let unexpected = |headers: &HeaderMap| {
+            AzureError::UnexpectedHTTPResult(
+                UnexpectedHTTPResult::new(StatusCode::CREATED, StatusCode::SERVICE_UNAVAILABLE, "")
+                    .with_headers(headers),
+            )
+        };
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            retry_delay(&unexpected(&headers), &retry_policy, 0),
+            Duration::from_secs(2)
+        );
+        headers.insert(RETRY_AFTER_MS, "250".parse().unwrap());
+        assert_eq!(
+            retry_delay(&unexpected(&headers), &retry_policy, 0),
+            Duration::from_millis(250)
+        );
+
+        let exponential = RetryPolicy::exponential(Duration::from_secs(1));
+        let delay = retry_delay(&AzureError::GenericError, &exponential, 2);
+        assert!(delay >= Duration::from_secs(2) && delay <= Duration::from_secs(4));
+    }
+}
@@ -0,0 +1,40 @@
+use crate::blob::{copy_status_from_headers, CopyStatus};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    server_from_headers, version_from_headers, RequestId,
+};
+use azure_sdk_storage_core::copy_id_from_headers;
+use azure_sdk_storage_core::CopyId;
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyBlobResponse {
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    pub server: String,
+    pub request_id: RequestId,
+    pub version: String,
+    pub copy_id: CopyId,
+    pub copy_status: CopyStatus,
+    pub date: DateTime<Utc>,
+}
+
+impl TryFrom<&HeaderMap> for CopyBlobResponse {
+    type Error = AzureError;
+    fn try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
+        debug!("headers == {:#?}", headers);
+        Ok(Self {
+            last_modified: last_modified_from_headers(headers)?,
+            etag: etag_from_headers(headers)?,
+            server: server_from_headers(headers)?.to_owned(),
+            request_id: request_id_from_headers(headers)?,
+            version: version_from_headers(headers)?.to_owned(),
+            copy_id: copy_id_from_headers(headers)?,
+            copy_status: copy_status_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+use crate::blob::responses::GetBlobResponse;
+use crate::blob::Blob;
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone)]
+pub struct DownloadBlobResponse {
+    /// The blob properties as returned with the first chunk.
+    pub blob: Blob,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    /// The number of ranged requests issued.
+    pub chunk_count: usize,
+}
+
+impl DownloadBlobResponse {
+    pub(crate) fn from_first_chunk(
+        response: GetBlobResponse,
+        chunk_count: usize,
+    ) -> DownloadBlobResponse {
+        let mut blob = response.blob;
+        if let Some(content_range) = response.content_range {
+            // a ranged response reports the length and MD5 of the range only
+            blob.content_length = content_range.total_length;
+            blob.content_md5 = None;
+        }
+
+        DownloadBlobResponse {
+            blob,
+            request_id: response.request_id,
+            date: response.date,
+            chunk_count,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::blob::metadata_from_headers;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobMetadataResponse {
+    pub metadata: HashMap<String, String>,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetBlobMetadataResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<GetBlobMetadataResponse, AzureError> {
+        debug!("headers == {:#?}", headers);
+
+        Ok(GetBlobMetadataResponse {
+            metadata: metadata_from_headers(headers)?,
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
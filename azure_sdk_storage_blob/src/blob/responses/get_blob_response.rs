@@ -1,7 +1,13 @@
 use crate::blob::Blob;
 use azure_sdk_core::errors::AzureError;
-use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use azure_sdk_core::range::ContentRange;
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_core::{
+    content_crc64_from_headers_optional, content_md5_from_headers_optional, date_from_headers,
+    request_id_from_headers, Consistency, RequestId,
+};
 use chrono::{DateTime, Utc};
+use http::header::CONTENT_RANGE;
 use http::HeaderMap;
 
 #[derive(Debug, Clone)]
@@ -10,6 +16,8 @@ pub struct GetBlobResponse {
     pub request_id: RequestId,
     pub data: Vec<u8>,
     pub date: DateTime<Utc>,
+    pub content_range: Option<ContentRange>,
+    pub consistency: Option<Consistency>,
 }
 
 impl GetBlobResponse {
@@ -23,11 +31,25 @@ impl GetBlobResponse {
         let request_id = request_id_from_headers(headers)?;
         let date = date_from_headers(headers)?;
 
+        let content_range = match headers.get_as_str(CONTENT_RANGE) {
+            Some(content_range) => Some(content_range.parse::<ContentRange>()?),
+            None => None,
+        };
+
+        // the CRC64 is returned only if explicitly requested so it takes
+        // precedence over the MD5
+        let consistency = match content_crc64_from_headers_optional(headers)? {
+            Some(content_crc64) => Some(Consistency::Crc64(content_crc64)),
+            None => content_md5_from_headers_optional(headers)?.map(Consistency::Md5),
+        };
+
         Ok(GetBlobResponse {
             blob,
             request_id,
             data: body.to_vec(),
             date,
+            content_range,
+            consistency,
         })
     }
 }
@@ -0,0 +1,25 @@
+use crate::blob::Tags;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBlobTagsResponse {
+    pub tags: Tags,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetBlobTagsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetBlobTagsResponse, AzureError> {
+        Ok(GetBlobTagsResponse {
+            tags: Tags::from_xml(std::str::from_utf8(body)?)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
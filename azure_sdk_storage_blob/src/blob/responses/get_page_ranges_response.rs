@@ -0,0 +1,32 @@
+use crate::blob::PageRanges;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetPageRangesResponse {
+    pub page_ranges: PageRanges,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetPageRangesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<GetPageRangesResponse, AzureError> {
+        Ok(GetPageRangesResponse {
+            page_ranges: PageRanges::from_xml(std::str::from_utf8(body)?)?,
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -30,3 +30,35 @@ mod break_blob_lease_response;
 pub use self::break_blob_lease_response::BreakBlobLeaseResponse;
 mod copy_blob_from_url_response;
 pub use copy_blob_from_url_response::CopyBlobFromUrlResponse;
+mod upload_blob_response;
+pub use self::upload_blob_response::UploadBlobResponse;
+mod download_blob_response;
+pub use self::download_blob_response::DownloadBlobResponse;
+mod get_blob_metadata_response;
+pub use self::get_blob_metadata_response::GetBlobMetadataResponse;
+mod get_blob_properties_response;
+pub use self::get_blob_properties_response::GetBlobPropertiesResponse;
+mod set_blob_metadata_response;
+pub use self::set_blob_metadata_response::SetBlobMetadataResponse;
+mod set_blob_properties_response;
+pub use self::set_blob_properties_response::SetBlobPropertiesResponse;
+mod snapshot_blob_response;
+pub use self::snapshot_blob_response::SnapshotBlobResponse;
+mod undelete_blob_response;
+pub use self::undelete_blob_response::UndeleteBlobResponse;
+mod copy_blob_response;
+pub use self::copy_blob_response::CopyBlobResponse;
+mod abort_copy_blob_response;
+pub use self::abort_copy_blob_response::AbortCopyBlobResponse;
+mod set_blob_tier_response;
+pub use self::set_blob_tier_response::SetBlobTierResponse;
+mod set_blob_tags_response;
+pub use self::set_blob_tags_response::SetBlobTagsResponse;
+mod get_blob_tags_response;
+pub use self::get_blob_tags_response::GetBlobTagsResponse;
+mod get_page_ranges_response;
+pub use self::get_page_ranges_response::GetPageRangesResponse;
+mod resize_page_blob_response;
+pub use self::resize_page_blob_response::ResizePageBlobResponse;
+mod set_blob_sequence_number_response;
+pub use self::set_blob_sequence_number_response::SetBlobSequenceNumberResponse;
@@ -0,0 +1,26 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use hyper::StatusCode;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetBlobTierResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    /// The blob is being rehydrated from the archive tier.
+    pub rehydration_pending: bool,
+}
+
+impl SetBlobTierResponse {
+    pub(crate) fn from_headers(
+        status_code: StatusCode,
+        headers: &HeaderMap,
+    ) -> Result<SetBlobTierResponse, AzureError> {
+        Ok(SetBlobTierResponse {
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            rehydration_pending: status_code == StatusCode::ACCEPTED,
+        })
+    }
+}
@@ -0,0 +1,43 @@
+use crate::blob::responses::{PutBlockBlobResponse, PutBlockListResponse};
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadBlobResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub request_server_encrypted: bool,
+    /// The number of blocks committed, zero if the blob was uploaded with a single call.
+    pub block_count: usize,
+}
+
+impl UploadBlobResponse {
+    pub(crate) fn from_put_block_blob_response(
+        response: PutBlockBlobResponse,
+    ) -> UploadBlobResponse {
+        UploadBlobResponse {
+            etag: response.etag,
+            last_modified: response.last_modified,
+            request_id: response.request_id,
+            date: response.date,
+            request_server_encrypted: response.request_server_encrypted,
+            block_count: 0,
+        }
+    }
+
+    pub(crate) fn from_put_block_list_response(
+        response: PutBlockListResponse,
+        block_count: usize,
+    ) -> UploadBlobResponse {
+        UploadBlobResponse {
+            etag: response.etag,
+            last_modified: response.last_modified,
+            request_id: response.request_id,
+            date: response.date,
+            request_server_encrypted: response.request_server_encrypted,
+            block_count,
+        }
+    }
+}
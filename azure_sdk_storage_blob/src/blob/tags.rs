@@ -0,0 +1,129 @@
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, traverse};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+use url::form_urlencoded;
+use xml::Element;
+
+/// The index tags of a blob. The keys are kept sorted so the
+/// serialized form is stable.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tags(BTreeMap<String, String>);
+
+impl Tags {
+    pub fn new() -> Tags {
+        Tags::default()
+    }
+
+    pub fn insert<K, V>(&mut self, key: K, value: V) -> Option<String>
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.0.insert(key.into(), value.into())
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The `x-ms-tags` header value: the tags as an url encoded query string.
+    pub(crate) fn to_header_value(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.iter())
+            .finish()
+    }
+
+    pub(crate) fn to_xml(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<Tags>\n\t<TagSet>\n");
+        for (key, value) in self.iter() {
+            s.push_str(&format!(
+                "\t\t<Tag><Key>{}</Key><Value>{}</Value></Tag>\n",
+                xml::escape(key),
+                xml::escape(value)
+            ));
+        }
+        s.push_str("\t</TagSet>\n</Tags>");
+        s
+    }
+
+    pub(crate) fn from_xml(xml: &str) -> Result<Tags, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+        Tags::parse(&elem)
+    }
+
+    /// Parses a `<Tags>` element.
+    pub(crate) fn parse(elem: &Element) -> Result<Tags, AzureError> {
+        let mut tags = Tags::new();
+        for tag in traverse(elem, &["TagSet", "Tag"], true)? {
+            tags.insert(
+                cast_must::<String>(tag, &["Key"])?,
+                cast_must::<String>(tag, &["Value"])?,
+            );
+        }
+        Ok(tags)
+    }
+}
+
+impl From<BTreeMap<String, String>> for Tags {
+    fn from(map: BTreeMap<String, String>) -> Tags {
+        Tags(map)
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for Tags
+where
+    K: Into<String>,
+    V: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Tags {
+        Tags(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn xml_roundtrip() {
+        let tags: Tags = vec![("tenant", "contoso"), ("stage", "raw & <new>")]
+            .into_iter()
+            .collect();
+
+        let xml = tags.to_xml();
+        assert!(xml.contains("<Key>stage</Key><Value>raw &amp; &lt;new&gt;</Value>"));
+        assert_eq!(Tags::from_xml(&xml).unwrap(), tags);
+    }
+
+    #[test]
+    fn parse_empty_tag_set() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?><Tags><TagSet /></Tags>";
+        assert!(Tags::from_xml(xml).unwrap().is_empty());
+    }
+
+    #[test]
+    fn header_value() {
+        let mut tags = Tags::new();
+        tags.insert("tenant", "contoso");
+        tags.insert("stage", "two words");
+        assert_eq!(tags.to_header_value(), "stage=two+words&tenant=contoso");
+    }
+}
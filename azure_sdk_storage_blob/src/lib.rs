@@ -7,9 +7,12 @@ extern crate log;
 extern crate serde_derive;
 #[macro_use]
 extern crate azure_sdk_core;
+#[macro_use]
+extern crate quick_error;
 pub mod blob;
 pub mod container;
 pub mod prelude;
+pub mod service;
 use azure_sdk_core::No;
 use azure_sdk_storage_core::Client;
 use std::borrow::Borrow;
@@ -19,13 +22,41 @@ where
     C: Client,
 {
     fn get_blob<'a>(&'a self) -> blob::requests::GetBlobBuilder<'a, C, No, No>;
+    fn get_blob_properties<'a>(&'a self)
+        -> blob::requests::GetBlobPropertiesBuilder<'a, C, No, No>;
+    fn set_blob_properties<'a>(&'a self)
+        -> blob::requests::SetBlobPropertiesBuilder<'a, C, No, No>;
+    fn get_blob_metadata<'a>(&'a self) -> blob::requests::GetBlobMetadataBuilder<'a, C, No, No>;
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, C, No, No>;
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, C, No, No, No>;
+    fn get_blob_tags<'a>(&'a self) -> blob::requests::GetBlobTagsBuilder<'a, C, No, No>;
+    fn set_blob_tags<'a>(&'a self) -> blob::requests::SetBlobTagsBuilder<'a, C, No, No, No>;
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, C, No, No>;
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, C, No, No>;
+    fn copy_blob<'a>(&'a self) -> blob::requests::CopyBlobBuilder<'a, C, No, No, No>;
+    fn abort_copy_blob<'a>(&'a self) -> blob::requests::AbortCopyBlobBuilder<'a, C, No, No, No>;
+    fn wait_for_blob_copy<'a>(&'a self) -> blob::requests::WaitForBlobCopyBuilder<'a, C, No, No>;
     fn put_block_blob<'a>(&'a self) -> blob::requests::PutBlockBlobBuilder<'a, C, No, No, No>;
     fn put_page_blob<'a>(&'a self) -> blob::requests::PutPageBlobBuilder<'a, C, No, No, No>;
     fn put_append_blob<'a>(&'a self) -> blob::requests::PutAppendBlobBuilder<'a, C, No, No>;
     fn put_append_block<'a>(&'a self) -> blob::requests::PutAppendBlockBuilder<'a, C, No, No, No>;
+    fn append_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::AppendBlockFromUrlBuilder<'a, C, No, No, No>;
     fn update_page<'a>(&'a self) -> blob::requests::UpdatePageBuilder<'a, C, No, No, No, No>;
     fn clear_page<'a>(&'a self) -> blob::requests::ClearPageBuilder<'a, C, No, No, No>;
+    fn get_page_ranges<'a>(&'a self) -> blob::requests::GetPageRangesBuilder<'a, C, No, No>;
+    fn resize_page_blob<'a>(&'a self) -> blob::requests::ResizePageBlobBuilder<'a, C, No, No, No>;
+    fn set_blob_sequence_number<'a>(
+        &'a self,
+    ) -> blob::requests::SetBlobSequenceNumberBuilder<'a, C, No, No, No>;
+    fn incremental_copy_blob<'a>(
+        &'a self,
+    ) -> blob::requests::IncrementalCopyBlobBuilder<'a, C, No, No, No>;
     fn put_block<'a>(&'a self) -> blob::requests::PutBlockBuilder<'a, C, No, No, No, No>;
+    fn put_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::PutBlockFromUrlBuilder<'a, C, No, No, No, No>;
     fn get_block_list<'a>(&'a self) -> blob::requests::GetBlockListBuilder<'a, C, No, No, No>;
     fn put_block_list<'a, T: Borrow<[u8]> + 'a>(
         &'a self,
@@ -52,6 +83,8 @@ where
     fn generate_signed_blob_url<'a>(
         &'a self,
     ) -> blob::requests::SignedUrlBuilder<'a, C, No, No, No>;
+    fn upload_blob<'a>(&'a self) -> blob::requests::UploadBlobBuilder<'a, C, No, No>;
+    fn download_blob<'a>(&'a self) -> blob::requests::DownloadBlobBuilder<'a, C, No, No>;
 }
 
 pub trait Container<C>
@@ -86,6 +119,58 @@ where
         blob::requests::GetBlobBuilder::new(self)
     }
 
+    fn get_blob_properties<'a>(
+        &'a self,
+    ) -> blob::requests::GetBlobPropertiesBuilder<'a, C, No, No> {
+        blob::requests::GetBlobPropertiesBuilder::new(self)
+    }
+
+    fn set_blob_properties<'a>(
+        &'a self,
+    ) -> blob::requests::SetBlobPropertiesBuilder<'a, C, No, No> {
+        blob::requests::SetBlobPropertiesBuilder::new(self)
+    }
+
+    fn get_blob_metadata<'a>(&'a self) -> blob::requests::GetBlobMetadataBuilder<'a, C, No, No> {
+        blob::requests::GetBlobMetadataBuilder::new(self)
+    }
+
+    fn set_blob_metadata<'a>(&'a self) -> blob::requests::SetBlobMetadataBuilder<'a, C, No, No> {
+        blob::requests::SetBlobMetadataBuilder::new(self)
+    }
+
+    fn set_blob_tier<'a>(&'a self) -> blob::requests::SetBlobTierBuilder<'a, C, No, No, No> {
+        blob::requests::SetBlobTierBuilder::new(self)
+    }
+
+    fn get_blob_tags<'a>(&'a self) -> blob::requests::GetBlobTagsBuilder<'a, C, No, No> {
+        blob::requests::GetBlobTagsBuilder::new(self)
+    }
+
+    fn set_blob_tags<'a>(&'a self) -> blob::requests::SetBlobTagsBuilder<'a, C, No, No, No> {
+        blob::requests::SetBlobTagsBuilder::new(self)
+    }
+
+    fn snapshot_blob<'a>(&'a self) -> blob::requests::SnapshotBlobBuilder<'a, C, No, No> {
+        blob::requests::SnapshotBlobBuilder::new(self)
+    }
+
+    fn undelete_blob<'a>(&'a self) -> blob::requests::UndeleteBlobBuilder<'a, C, No, No> {
+        blob::requests::UndeleteBlobBuilder::new(self)
+    }
+
+    fn copy_blob<'a>(&'a self) -> blob::requests::CopyBlobBuilder<'a, C, No, No, No> {
+        blob::requests::CopyBlobBuilder::new(self)
+    }
+
+    fn abort_copy_blob<'a>(&'a self) -> blob::requests::AbortCopyBlobBuilder<'a, C, No, No, No> {
+        blob::requests::AbortCopyBlobBuilder::new(self)
+    }
+
+    fn wait_for_blob_copy<'a>(&'a self) -> blob::requests::WaitForBlobCopyBuilder<'a, C, No, No> {
+        blob::requests::WaitForBlobCopyBuilder::new(self)
+    }
+
     fn put_block_blob<'a>(&'a self) -> blob::requests::PutBlockBlobBuilder<'a, C, No, No, No> {
         blob::requests::PutBlockBlobBuilder::new(self)
     }
@@ -102,6 +187,12 @@ where
         blob::requests::PutAppendBlockBuilder::new(self)
     }
 
+    fn append_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::AppendBlockFromUrlBuilder<'a, C, No, No, No> {
+        blob::requests::AppendBlockFromUrlBuilder::new(self)
+    }
+
     fn update_page<'a>(&'a self) -> blob::requests::UpdatePageBuilder<'a, C, No, No, No, No> {
         blob::requests::UpdatePageBuilder::new(self)
     }
@@ -110,10 +201,36 @@ where
         blob::requests::ClearPageBuilder::new(self)
     }
 
+    fn get_page_ranges<'a>(&'a self) -> blob::requests::GetPageRangesBuilder<'a, C, No, No> {
+        blob::requests::GetPageRangesBuilder::new(self)
+    }
+
+    fn resize_page_blob<'a>(&'a self) -> blob::requests::ResizePageBlobBuilder<'a, C, No, No, No> {
+        blob::requests::ResizePageBlobBuilder::new(self)
+    }
+
+    fn set_blob_sequence_number<'a>(
+        &'a self,
+    ) -> blob::requests::SetBlobSequenceNumberBuilder<'a, C, No, No, No> {
+        blob::requests::SetBlobSequenceNumberBuilder::new(self)
+    }
+
+    fn incremental_copy_blob<'a>(
+        &'a self,
+    ) -> blob::requests::IncrementalCopyBlobBuilder<'a, C, No, No, No> {
+        blob::requests::IncrementalCopyBlobBuilder::new(self)
+    }
+
     fn put_block<'a>(&'a self) -> blob::requests::PutBlockBuilder<'a, C, No, No, No, No> {
         blob::requests::PutBlockBuilder::new(self)
     }
 
+    fn put_block_from_url<'a>(
+        &'a self,
+    ) -> blob::requests::PutBlockFromUrlBuilder<'a, C, No, No, No, No> {
+        blob::requests::PutBlockFromUrlBuilder::new(self)
+    }
+
     fn get_block_list<'a>(&'a self) -> blob::requests::GetBlockListBuilder<'a, C, No, No, No> {
         blob::requests::GetBlockListBuilder::new(self)
     }
@@ -175,6 +292,14 @@ where
     ) -> blob::requests::SignedUrlBuilder<'a, C, No, No, No> {
         blob::requests::SignedUrlBuilder::new(self)
     }
+
+    fn upload_blob<'a>(&'a self) -> blob::requests::UploadBlobBuilder<'a, C, No, No> {
+        blob::requests::UploadBlobBuilder::new(self)
+    }
+
+    fn download_blob<'a>(&'a self) -> blob::requests::DownloadBlobBuilder<'a, C, No, No> {
+        blob::requests::DownloadBlobBuilder::new(self)
+    }
 }
 
 impl<C> Container<C> for C
@@ -233,3 +358,47 @@ where
         container::requests::BreakLeaseBuilder::new(self)
     }
 }
+
+pub trait BlobService<C>
+where
+    C: Client,
+{
+    fn get_blob_service_properties<'a>(&'a self) -> service::requests::GetPropertiesBuilder<'a, C>;
+    fn set_blob_service_properties<'a>(
+        &'a self,
+    ) -> service::requests::SetPropertiesBuilder<'a, C, No>;
+    fn find_blobs_by_tags<'a>(&'a self) -> service::requests::FindBlobsByTagsBuilder<'a, C, No>;
+    fn get_user_delegation_key<'a>(
+        &'a self,
+    ) -> service::requests::GetUserDelegationKeyBuilder<'a, C, No, No>;
+    fn blob_batch<'a>(&'a self) -> service::requests::BlobBatchBuilder<'a, C, No>;
+}
+
+impl<C> BlobService<C> for C
+where
+    C: Client,
+{
+    fn get_blob_service_properties<'a>(&'a self) -> service::requests::GetPropertiesBuilder<'a, C> {
+        service::requests::GetPropertiesBuilder::new(self)
+    }
+
+    fn set_blob_service_properties<'a>(
+        &'a self,
+    ) -> service::requests::SetPropertiesBuilder<'a, C, No> {
+        service::requests::SetPropertiesBuilder::new(self)
+    }
+
+    fn find_blobs_by_tags<'a>(&'a self) -> service::requests::FindBlobsByTagsBuilder<'a, C, No> {
+        service::requests::FindBlobsByTagsBuilder::new(self)
+    }
+
+    fn get_user_delegation_key<'a>(
+        &'a self,
+    ) -> service::requests::GetUserDelegationKeyBuilder<'a, C, No, No> {
+        service::requests::GetUserDelegationKeyBuilder::new(self)
+    }
+
+    fn blob_batch<'a>(&'a self) -> service::requests::BlobBatchBuilder<'a, C, No> {
+        service::requests::BlobBatchBuilder::new(self)
+    }
+}
@@ -1,10 +1,21 @@
 pub use crate::blob::{
     BlobBlockType, BlockList, BlockListRequired, BlockListSupport, BlockListType,
-    BlockListTypeRequired, BlockListTypeSupport,
+    BlockListTypeRequired, BlockListTypeSupport, CopyIdRequired, CopyIdSupport, IfTagsOption,
+    IfTagsSupport, MaxBlockRetriesOption, MaxBlockRetriesSupport, MaxConcurrencyOption,
+    MaxConcurrencySupport, PageRanges, PollIntervalOption, PollIntervalSupport,
+    SequenceNumberAction, SequenceNumberActionRequired, SequenceNumberActionSupport, Tags,
+    TagsOption, TagsRequired, TagsSupport,
 };
 pub use crate::container::{
     PublicAccess, PublicAccessRequired, PublicAccessSupport, StoredAccessPolicyListOption,
     StoredAccessPolicyListSupport,
 };
+pub use crate::service::{
+    BlobBatch, BlobBatchOperation, BlobBatchRequired, BlobBatchSupport, BlobServiceProperties,
+    BlobServicePropertiesRequired, BlobServicePropertiesSupport, CorsRule, KeyExpiryRequired,
+    KeyExpirySupport, KeyStartRequired, KeyStartSupport, Logging, Metrics, RetentionPolicy,
+    StaticWebsite, TagFilterRequired, TagFilterSupport, TaggedBlob,
+};
 pub use crate::Blob as BlobTrait;
+pub use crate::BlobService as BlobServiceTrait;
 pub use crate::Container as ContainerTrait;
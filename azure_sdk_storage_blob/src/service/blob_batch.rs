@@ -0,0 +1,390 @@
+use crate::blob::generate_blob_uri;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{BLOB_ACCESS_TIER, DELETE_SNAPSHOTS, ERROR_CODE, REQUEST_ID};
+use azure_sdk_core::{AccessTier, DeleteSnapshotsMethod, RequestId};
+use azure_sdk_storage_core::{Client, ServiceType};
+use http::StatusCode;
+use hyper::Method;
+use std::fmt::Write;
+use uuid::Uuid;
+
+const BATCH_MAX_SIZE: usize = 256;
+
+quick_error! {
+    #[derive(Debug)]
+    pub enum BlobBatchError {
+        TooManyOperations {
+            display("a blob batch cannot hold more than {} operations", BATCH_MAX_SIZE)
+        }
+        MixedOperations {
+            display("a blob batch cannot mix delete and set tier operations")
+        }
+        EmptyBatch {
+            display("a blob batch must hold at least one operation")
+        }
+        MalformedResponse(err: String) {
+            display("malformed batch response: {}", err)
+        }
+    }
+}
+
+impl From<BlobBatchError> for AzureError {
+    fn from(err: BlobBatchError) -> Self {
+        AzureError::GenericErrorWithText(err.to_string())
+    }
+}
+
+pub trait BlobBatchSupport<'a> {
+    type O;
+    fn with_blob_batch(self, blob_batch: &'a BlobBatch) -> Self::O;
+}
+
+pub trait BlobBatchRequired<'a> {
+    fn blob_batch(&self) -> &'a BlobBatch;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlobBatchOperation {
+    Delete {
+        container_name: String,
+        blob_name: String,
+        delete_snapshots_method: DeleteSnapshotsMethod,
+    },
+    SetTier {
+        container_name: String,
+        blob_name: String,
+        access_tier: AccessTier,
+    },
+}
+
+impl BlobBatchOperation {
+    fn is_same_kind(&self, other: &BlobBatchOperation) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    fn to_sub_request<C>(&self, client: &C) -> Result<hyper::Request<()>, AzureError>
+    where
+        C: Client,
+    {
+        match self {
+            BlobBatchOperation::Delete {
+                container_name,
+                blob_name,
+                delete_snapshots_method,
+            } => {
+                let delete_snapshots_method: &'static str = (*delete_snapshots_method).into();
+                client.prepare_sub_request(
+                    &generate_blob_uri(client, container_name, blob_name, None),
+                    &Method::DELETE,
+                    &|request| request.header(DELETE_SNAPSHOTS, delete_snapshots_method),
+                    ServiceType::Blob,
+                )
+            }
+            BlobBatchOperation::SetTier {
+                container_name,
+                blob_name,
+                access_tier,
+            } => {
+                let access_tier: &'static str = (*access_tier).into();
+                client.prepare_sub_request(
+                    &generate_blob_uri(client, container_name, blob_name, Some("comp=tier")),
+                    &Method::PUT,
+                    &|request| request.header(BLOB_ACCESS_TIER, access_tier),
+                    ServiceType::Blob,
+                )
+            }
+        }
+    }
+}
+
+/// Up to 256 delete or set tier operations sent in a single request.
+/// A batch holds operations of one kind only.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlobBatch {
+    operations: Vec<BlobBatchOperation>,
+}
+
+impl BlobBatch {
+    pub fn new() -> BlobBatch {
+        BlobBatch::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.operations.len() >= BATCH_MAX_SIZE
+    }
+
+    pub fn operations(&self) -> &[BlobBatchOperation] {
+        &self.operations
+    }
+
+    pub fn add_operation(
+        &mut self,
+        operation: BlobBatchOperation,
+    ) -> Result<&mut Self, BlobBatchError> {
+        if self.is_full() {
+            return Err(BlobBatchError::TooManyOperations);
+        }
+        if let Some(first) = self.operations.first() {
+            if !first.is_same_kind(&operation) {
+                return Err(BlobBatchError::MixedOperations);
+            }
+        }
+        self.operations.push(operation);
+        Ok(self)
+    }
+
+    /// Deletes the blob along with its snapshots.
+    pub fn add_delete(
+        &mut self,
+        container_name: &str,
+        blob_name: &str,
+    ) -> Result<&mut Self, BlobBatchError> {
+        self.add_operation(BlobBatchOperation::Delete {
+            container_name: container_name.to_owned(),
+            blob_name: blob_name.to_owned(),
+            delete_snapshots_method: DeleteSnapshotsMethod::Include,
+        })
+    }
+
+    pub fn add_set_tier(
+        &mut self,
+        container_name: &str,
+        blob_name: &str,
+        access_tier: AccessTier,
+    ) -> Result<&mut Self, BlobBatchError> {
+        self.add_operation(BlobBatchOperation::SetTier {
+            container_name: container_name.to_owned(),
+            blob_name: blob_name.to_owned(),
+            access_tier,
+        })
+    }
+
+    /// Every sub-request is signed on its own, as if it was sent alone.
+    pub(crate) fn to_payload<C>(&self, client: &C, boundary: &str) -> Result<String, AzureError>
+    where
+        C: Client,
+    {
+        let mut payload = String::new();
+
+        for (content_id, operation) in self.operations.iter().enumerate() {
+            let request = operation.to_sub_request(client)?;
+            let path = request
+                .uri()
+                .path_and_query()
+                .map(|path| path.as_str())
+                .unwrap_or("/");
+
+            write!(
+                payload,
+                "--{}\r\nContent-Type: application/http\r\nContent-Transfer-Encoding: binary\r\nContent-ID: {}\r\n\r\n{} {} HTTP/1.1\r\n",
+                boundary,
+                content_id,
+                request.method(),
+                path
+            )
+            .unwrap();
+
+            for (name, value) in request.headers() {
+                write!(payload, "{}: {}\r\n", name, value.to_str()?).unwrap();
+            }
+            payload.push_str("\r\n");
+        }
+
+        write!(payload, "--{}--\r\n", boundary).unwrap();
+
+        Ok(payload)
+    }
+}
+
+pub(crate) fn generate_batch_boundary() -> String {
+    format!("batch_{}", Uuid::new_v4())
+}
+
+/// The outcome of a single operation of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobBatchResult {
+    pub status_code: StatusCode,
+    pub request_id: Option<RequestId>,
+    pub error_code: Option<String>,
+}
+
+fn boundary_from_content_type(content_type: &str) -> Result<&str, BlobBatchError> {
+    content_type
+        .split(';')
+        .map(|param| param.trim())
+        .find(|param| param.starts_with("boundary="))
+        .map(|param| param["boundary=".len()..].trim_matches('"'))
+        .ok_or_else(|| BlobBatchError::MalformedResponse(content_type.to_owned()))
+}
+
+/// Splits `s` at the first empty line, returning the header lines and
+/// what follows them.
+fn split_headers(s: &str) -> (Vec<&str>, &str) {
+    let s = s.trim_start_matches(&['\r', '\n'][..]);
+    let (head, rest) = match s.find("\r\n\r\n") {
+        Some(pos) => (&s[..pos], &s[pos + 4..]),
+        None => (s, ""),
+    };
+    (head.lines().collect(), rest)
+}
+
+fn header_value<'b>(lines: &[&'b str], name: &str) -> Option<&'b str> {
+    lines.iter().find_map(|line| {
+        let mut split = line.splitn(2, ':');
+        match (split.next(), split.next()) {
+            (Some(key), Some(value)) if key.trim().eq_ignore_ascii_case(name) => Some(value.trim()),
+            _ => None,
+        }
+    })
+}
+
+/// Parses the `multipart/mixed` body of a batch response. The results are
+/// returned in the same order as the operations of the batch.
+pub(crate) fn batch_results_from_response(
+    content_type: &str,
+    body: &str,
+) -> Result<Vec<BlobBatchResult>, AzureError> {
+    let delimiter = format!("--{}", boundary_from_content_type(content_type)?);
+
+    let mut results = Vec::new();
+
+    // the first chunk is the (empty) preamble, the last one starts with
+    // the closing "--"
+    for part in body.split(&delimiter as &str).skip(1) {
+        if part.starts_with("--") {
+            break;
+        }
+
+        let (part_headers, http_response) = split_headers(part);
+        let content_id = match header_value(&part_headers, "Content-ID") {
+            Some(content_id) => content_id.parse::<usize>()?,
+            None => results.len(),
+        };
+
+        let (response_lines, _body) = split_headers(http_response);
+        let status_code = response_lines
+            .first()
+            .and_then(|status_line| status_line.split_whitespace().nth(1))
+            .ok_or_else(|| BlobBatchError::MalformedResponse(http_response.to_owned()))?;
+        let status_code = StatusCode::from_bytes(status_code.as_bytes())
+            .map_err(|_| BlobBatchError::MalformedResponse(status_code.to_owned()))?;
+
+        let request_id = match header_value(&response_lines, REQUEST_ID) {
+            Some(request_id) => Some(Uuid::parse_str(request_id)?),
+            None => None,
+        };
+
+        results.push((
+            content_id,
+            BlobBatchResult {
+                status_code,
+                request_id,
+                error_code: header_value(&response_lines, ERROR_CODE).map(|s| s.to_owned()),
+            },
+        ));
+    }
+
+    results.sort_by_key(|(content_id, _)| *content_id);
+    Ok(results.into_iter().map(|(_, result)| result).collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn batch_rejects_mixed_and_too_many_operations() {
+        let mut batch = BlobBatch::new();
+        for i in 0..BATCH_MAX_SIZE {
+            batch
+                .add_delete("container", &format!("blob{}", i))
+                .unwrap();
+        }
+        assert!(batch.is_full());
+        assert!(batch.add_delete("container", "one_too_many").is_err());
+
+        let mut batch = BlobBatch::new();
+        batch
+            .add_set_tier("container", "blob", AccessTier::Cool)
+            .unwrap();
+        assert!(batch.add_delete("container", "blob").is_err());
+        assert_eq!(batch.len(), 1);
+    }
+
+    #[test]
+    fn batch_payload() {
+        let client =
+            azure_sdk_storage_core::client::with_access_key("myaccount", &base64::encode("key"));
+
+        let mut batch = BlobBatch::new();
+        batch
+            .add_delete("container", "blob0")
+            .unwrap()
+            .add_delete("container", "dir/blob1")
+            .unwrap();
+
+        let payload = batch.to_payload(&client, "batch_boundary").unwrap();
+        let parts = payload.split("--batch_boundary").collect::<Vec<_>>();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[3], "--\r\n");
+
+        assert!(parts[1].starts_with(
+            "\r\nContent-Type: application/http\r\nContent-Transfer-Encoding: binary\r\nContent-ID: 0\r\n\r\nDELETE /container/blob0 HTTP/1.1\r\n"
+        ));
+        assert!(parts[1].contains("\r\nx-ms-delete-snapshots: include\r\n"));
+        assert!(parts[1].contains("\r\nauthorization: SharedKey myaccount:"));
+        assert!(parts[1].ends_with("\r\n\r\n"));
+        assert!(
+            parts[2].contains("Content-ID: 1\r\n\r\nDELETE /container/dir%2Fblob1 HTTP/1.1\r\n")
+        );
+    }
+
+    #[test]
+    fn parse_batch_response() {
+        let body = "--batchresponse_66925647\r\n\
+            Content-Type: application/http\r\n\
+            Content-ID: 1\r\n\
+            \r\n\
+            HTTP/1.1 404 The specified blob does not exist.\r\n\
+            x-ms-error-code: BlobNotFound\r\n\
+            x-ms-request-id: 778fdc83-801e-0000-62ff-0334671e2852\r\n\
+            x-ms-version: 2019-12-12\r\n\
+            Content-Length: 216\r\n\
+            Content-Type: application/xml\r\n\
+            \r\n\
+            <?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n\
+            <Error><Code>BlobNotFound</Code><Message>The specified blob does not exist.</Message></Error>\r\n\
+            --batchresponse_66925647\r\n\
+            Content-Type: application/http\r\n\
+            Content-ID: 0\r\n\
+            \r\n\
+            HTTP/1.1 202 Accepted\r\n\
+            x-ms-delete-type-permanent: true\r\n\
+            x-ms-request-id: 778fdc83-801e-0000-62ff-0334671e284f\r\n\
+            x-ms-version: 2019-12-12\r\n\
+            \r\n\
+            --batchresponse_66925647--\r\n";
+
+        let results =
+            batch_results_from_response("multipart/mixed; boundary=batchresponse_66925647", body)
+                .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].status_code, StatusCode::ACCEPTED);
+        assert_eq!(results[0].error_code, None);
+        assert_eq!(
+            results[0].request_id,
+            Some(Uuid::parse_str("778fdc83-801e-0000-62ff-0334671e284f").unwrap())
+        );
+        assert_eq!(results[1].status_code, StatusCode::NOT_FOUND);
+        assert_eq!(results[1].error_code.as_deref(), Some("BlobNotFound"));
+    }
+}
@@ -0,0 +1,559 @@
+mod blob_batch;
+pub(crate) use self::blob_batch::{batch_results_from_response, generate_batch_boundary};
+pub use self::blob_batch::{
+    BlobBatch, BlobBatchError, BlobBatchOperation, BlobBatchRequired, BlobBatchResult,
+    BlobBatchSupport,
+};
+pub mod requests;
+pub mod responses;
+
+use crate::blob::Tags;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{
+    cast_must, cast_optional, traverse, traverse_single_must, traverse_single_optional,
+};
+use azure_sdk_storage_core::UserDelegationKey;
+use chrono::{DateTime, SecondsFormat, Utc};
+use url::form_urlencoded;
+use xml::Element;
+
+pub trait BlobServicePropertiesSupport<'a> {
+    type O;
+    fn with_blob_service_properties(self, properties: &'a BlobServiceProperties) -> Self::O;
+}
+
+pub trait BlobServicePropertiesRequired<'a> {
+    fn blob_service_properties(&self) -> &'a BlobServiceProperties;
+}
+
+pub trait TagFilterSupport<'a> {
+    type O;
+    fn with_tag_filter(self, tag_filter: &'a str) -> Self::O;
+}
+
+/// The filter is a SQL-like expression on the blob tags,
+/// for example `"tenant" = 'contoso' AND "stage" >= '2'`.
+pub trait TagFilterRequired<'a> {
+    fn tag_filter(&self) -> &'a str;
+
+    fn to_uri_parameter(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .append_pair("where", self.tag_filter())
+            .finish()
+    }
+}
+
+pub trait KeyStartSupport {
+    type O;
+    fn with_key_start(self, key_start: DateTime<Utc>) -> Self::O;
+}
+
+pub trait KeyStartRequired {
+    fn key_start(&self) -> DateTime<Utc>;
+}
+
+pub trait KeyExpirySupport {
+    type O;
+    fn with_key_expiry(self, key_expiry: DateTime<Utc>) -> Self::O;
+}
+
+/// The service rejects keys valid for more than seven days.
+pub trait KeyExpiryRequired {
+    fn key_expiry(&self) -> DateTime<Utc>;
+}
+
+/// The `KeyInfo` body of Get User Delegation Key.
+pub(crate) fn key_info_to_xml(start: DateTime<Utc>, expiry: DateTime<Utc>) -> String {
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<KeyInfo>\n\t<Start>{}</Start>\n\t<Expiry>{}</Expiry>\n</KeyInfo>",
+        start.to_rfc3339_opts(SecondsFormat::Secs, true),
+        expiry.to_rfc3339_opts(SecondsFormat::Secs, true)
+    )
+}
+
+pub(crate) fn user_delegation_key_from_xml(xml: &str) -> Result<UserDelegationKey, AzureError> {
+    let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+    // the key dates are ISO 8601, not RFC 1123 like the other dates
+    let date = |name: &str| -> Result<DateTime<Utc>, AzureError> {
+        let s = cast_must::<String>(&elem, &[name])?;
+        Ok(DateTime::parse_from_rfc3339(&s)?.with_timezone(&Utc))
+    };
+
+    Ok(UserDelegationKey {
+        signed_oid: cast_must::<String>(&elem, &["SignedOid"])?,
+        signed_tid: cast_must::<String>(&elem, &["SignedTid"])?,
+        signed_start: date("SignedStart")?,
+        signed_expiry: date("SignedExpiry")?,
+        signed_service: cast_must::<String>(&elem, &["SignedService"])?,
+        signed_version: cast_must::<String>(&elem, &["SignedVersion"])?,
+        value: cast_must::<String>(&elem, &["Value"])?,
+    })
+}
+
+/// A blob returned by Find Blobs by Tags.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedBlob {
+    pub name: String,
+    pub container_name: String,
+    /// Only the tags matching the filter are returned.
+    pub tags: Option<Tags>,
+}
+
+impl TaggedBlob {
+    fn parse(elem: &Element) -> Result<TaggedBlob, AzureError> {
+        Ok(TaggedBlob {
+            name: cast_must::<String>(elem, &["Name"])?,
+            container_name: cast_must::<String>(elem, &["ContainerName"])?,
+            tags: match traverse_single_optional(elem, &["Tags"])? {
+                Some(elem) => Some(Tags::parse(elem)?),
+                None => None,
+            },
+        })
+    }
+
+    pub(crate) fn incomplete_vector_from_xml(
+        xml: &str,
+    ) -> Result<IncompleteVector<TaggedBlob>, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        let next_marker = match cast_optional::<String>(&elem, &["NextMarker"])? {
+            Some(ref nm) if nm.is_empty() => None,
+            Some(nm) => Some(nm),
+            None => None,
+        };
+
+        let blobs = traverse(&elem, &["Blobs", "Blob"], true)?
+            .into_iter()
+            .map(TaggedBlob::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(IncompleteVector::new(next_marker, blobs))
+    }
+}
+
+/// The Blob service properties of a storage account. When setting them,
+/// the elements left to `None` are not changed by the service.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlobServiceProperties {
+    pub logging: Option<Logging>,
+    pub hour_metrics: Option<Metrics>,
+    pub minute_metrics: Option<Metrics>,
+    /// `Some` with an empty vector removes every CORS rule.
+    pub cors: Option<Vec<CorsRule>>,
+    pub default_service_version: Option<String>,
+    pub delete_retention_policy: Option<RetentionPolicy>,
+    pub static_website: Option<StaticWebsite>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logging {
+    pub version: String,
+    pub delete: bool,
+    pub read: bool,
+    pub write: bool,
+    pub retention_policy: RetentionPolicy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    pub version: String,
+    pub enabled: bool,
+    /// Must be `Some` if and only if the metrics are enabled.
+    pub include_apis: Option<bool>,
+    pub retention_policy: RetentionPolicy,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetentionPolicy {
+    pub enabled: bool,
+    pub days: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorsRule {
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_headers: Vec<String>,
+    pub exposed_headers: Vec<String>,
+    pub max_age_in_seconds: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticWebsite {
+    pub enabled: bool,
+    pub index_document: Option<String>,
+    pub error_document_404_path: Option<String>,
+    pub default_index_document_path: Option<String>,
+}
+
+impl BlobServiceProperties {
+    pub fn from_xml(xml: &str) -> Result<BlobServiceProperties, AzureError> {
+        let elem: Element = xml.trim_start_matches('\u{feff}').parse()?;
+
+        let logging = match traverse_single_optional(&elem, &["Logging"])? {
+            Some(elem) => Some(Logging::parse(elem)?),
+            None => None,
+        };
+        let hour_metrics = match traverse_single_optional(&elem, &["HourMetrics"])? {
+            Some(elem) => Some(Metrics::parse(elem)?),
+            None => None,
+        };
+        let minute_metrics = match traverse_single_optional(&elem, &["MinuteMetrics"])? {
+            Some(elem) => Some(Metrics::parse(elem)?),
+            None => None,
+        };
+        let cors = match traverse_single_optional(&elem, &["Cors"])? {
+            Some(elem) => Some(
+                traverse(elem, &["CorsRule"], true)?
+                    .into_iter()
+                    .map(CorsRule::parse)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+            None => None,
+        };
+        let default_service_version = cast_optional::<String>(&elem, &["DefaultServiceVersion"])?;
+        let delete_retention_policy =
+            match traverse_single_optional(&elem, &["DeleteRetentionPolicy"])? {
+                Some(elem) => Some(RetentionPolicy::parse(elem)?),
+                None => None,
+            };
+        let static_website = match traverse_single_optional(&elem, &["StaticWebsite"])? {
+            Some(elem) => Some(StaticWebsite::parse(elem)?),
+            None => None,
+        };
+
+        Ok(BlobServiceProperties {
+            logging,
+            hour_metrics,
+            minute_metrics,
+            cors,
+            default_service_version,
+            delete_retention_policy,
+            static_website,
+        })
+    }
+
+    pub fn to_xml(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<StorageServiceProperties>\n");
+
+        if let Some(logging) = &self.logging {
+            s.push_str("\t<Logging>\n");
+            s.push_str(&format!(
+                "\t\t<Version>{}</Version>\n",
+                xml::escape(&logging.version)
+            ));
+            s.push_str(&format!("\t\t<Delete>{}</Delete>\n", logging.delete));
+            s.push_str(&format!("\t\t<Read>{}</Read>\n", logging.read));
+            s.push_str(&format!("\t\t<Write>{}</Write>\n", logging.write));
+            logging
+                .retention_policy
+                .push_xml(&mut s, "RetentionPolicy", "\t\t");
+            s.push_str("\t</Logging>\n");
+        }
+        if let Some(hour_metrics) = &self.hour_metrics {
+            hour_metrics.push_xml(&mut s, "HourMetrics");
+        }
+        if let Some(minute_metrics) = &self.minute_metrics {
+            minute_metrics.push_xml(&mut s, "MinuteMetrics");
+        }
+        if let Some(cors) = &self.cors {
+            s.push_str("\t<Cors>\n");
+            for rule in cors {
+                rule.push_xml(&mut s);
+            }
+            s.push_str("\t</Cors>\n");
+        }
+        if let Some(default_service_version) = &self.default_service_version {
+            s.push_str(&format!(
+                "\t<DefaultServiceVersion>{}</DefaultServiceVersion>\n",
+                xml::escape(default_service_version)
+            ));
+        }
+        if let Some(delete_retention_policy) = &self.delete_retention_policy {
+            delete_retention_policy.push_xml(&mut s, "DeleteRetentionPolicy", "\t");
+        }
+        if let Some(static_website) = &self.static_website {
+            static_website.push_xml(&mut s);
+        }
+
+        s.push_str("</StorageServiceProperties>");
+        s
+    }
+}
+
+impl Logging {
+    fn parse(elem: &Element) -> Result<Logging, AzureError> {
+        Ok(Logging {
+            version: cast_must::<String>(elem, &["Version"])?,
+            delete: cast_must::<bool>(elem, &["Delete"])?,
+            read: cast_must::<bool>(elem, &["Read"])?,
+            write: cast_must::<bool>(elem, &["Write"])?,
+            retention_policy: RetentionPolicy::parse(traverse_single_must(
+                elem,
+                &["RetentionPolicy"],
+            )?)?,
+        })
+    }
+}
+
+impl Metrics {
+    fn parse(elem: &Element) -> Result<Metrics, AzureError> {
+        Ok(Metrics {
+            version: cast_must::<String>(elem, &["Version"])?,
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            include_apis: cast_optional::<bool>(elem, &["IncludeAPIs"])?,
+            retention_policy: RetentionPolicy::parse(traverse_single_must(
+                elem,
+                &["RetentionPolicy"],
+            )?)?,
+        })
+    }
+
+    fn push_xml(&self, s: &mut String, name: &str) {
+        s.push_str(&format!("\t<{}>\n", name));
+        s.push_str(&format!(
+            "\t\t<Version>{}</Version>\n",
+            xml::escape(&self.version)
+        ));
+        s.push_str(&format!("\t\t<Enabled>{}</Enabled>\n", self.enabled));
+        if let Some(include_apis) = self.include_apis {
+            s.push_str(&format!(
+                "\t\t<IncludeAPIs>{}</IncludeAPIs>\n",
+                include_apis
+            ));
+        }
+        self.retention_policy.push_xml(s, "RetentionPolicy", "\t\t");
+        s.push_str(&format!("\t</{}>\n", name));
+    }
+}
+
+impl RetentionPolicy {
+    fn parse(elem: &Element) -> Result<RetentionPolicy, AzureError> {
+        Ok(RetentionPolicy {
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            days: cast_optional::<u64>(elem, &["Days"])?,
+        })
+    }
+
+    fn push_xml(&self, s: &mut String, name: &str, indent: &str) {
+        s.push_str(&format!("{}<{}>\n", indent, name));
+        s.push_str(&format!(
+            "{}\t<Enabled>{}</Enabled>\n",
+            indent, self.enabled
+        ));
+        if let Some(days) = self.days {
+            s.push_str(&format!("{}\t<Days>{}</Days>\n", indent, days));
+        }
+        s.push_str(&format!("{}</{}>\n", indent, name));
+    }
+}
+
+impl CorsRule {
+    fn parse(elem: &Element) -> Result<CorsRule, AzureError> {
+        let list = |name: &str| -> Result<Vec<String>, AzureError> {
+            Ok(cast_optional::<String>(elem, &[name])?
+                .map(|list| {
+                    list.split(',')
+                        .map(|item| item.trim().to_owned())
+                        .filter(|item| !item.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default())
+        };
+
+        Ok(CorsRule {
+            allowed_origins: list("AllowedOrigins")?,
+            allowed_methods: list("AllowedMethods")?,
+            allowed_headers: list("AllowedHeaders")?,
+            exposed_headers: list("ExposedHeaders")?,
+            max_age_in_seconds: cast_must::<u64>(elem, &["MaxAgeInSeconds"])?,
+        })
+    }
+
+    fn push_xml(&self, s: &mut String) {
+        s.push_str("\t\t<CorsRule>\n");
+        for (name, list) in &[
+            ("AllowedOrigins", &self.allowed_origins),
+            ("AllowedMethods", &self.allowed_methods),
+            ("AllowedHeaders", &self.allowed_headers),
+            ("ExposedHeaders", &self.exposed_headers),
+        ] {
+            s.push_str(&format!(
+                "\t\t\t<{}>{}</{}>\n",
+                name,
+                xml::escape(&list.join(",")),
+                name
+            ));
+        }
+        s.push_str(&format!(
+            "\t\t\t<MaxAgeInSeconds>{}</MaxAgeInSeconds>\n",
+            self.max_age_in_seconds
+        ));
+        s.push_str("\t\t</CorsRule>\n");
+    }
+}
+
+impl StaticWebsite {
+    fn parse(elem: &Element) -> Result<StaticWebsite, AzureError> {
+        Ok(StaticWebsite {
+            enabled: cast_must::<bool>(elem, &["Enabled"])?,
+            index_document: cast_optional::<String>(elem, &["IndexDocument"])?,
+            error_document_404_path: cast_optional::<String>(elem, &["ErrorDocument404Path"])?,
+            default_index_document_path: cast_optional::<String>(
+                elem,
+                &["DefaultIndexDocumentPath"],
+            )?,
+        })
+    }
+
+    fn push_xml(&self, s: &mut String) {
+        s.push_str("\t<StaticWebsite>\n");
+        s.push_str(&format!("\t\t<Enabled>{}</Enabled>\n", self.enabled));
+        for (name, value) in &[
+            ("IndexDocument", &self.index_document),
+            ("ErrorDocument404Path", &self.error_document_404_path),
+            (
+                "DefaultIndexDocumentPath",
+                &self.default_index_document_path,
+            ),
+        ] {
+            if let Some(value) = value {
+                s.push_str(&format!(
+                    "\t\t<{}>{}</{}>\n",
+                    name,
+                    xml::escape(value),
+                    name
+                ));
+            }
+        }
+        s.push_str("\t</StaticWebsite>\n");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PROPERTIES: &str = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+        <StorageServiceProperties>\
+        <Logging><Version>1.0</Version><Read>false</Read><Write>true</Write><Delete>true</Delete>\
+        <RetentionPolicy><Enabled>true</Enabled><Days>7</Days></RetentionPolicy></Logging>\
+        <HourMetrics><Version>1.0</Version><Enabled>true</Enabled><IncludeAPIs>false</IncludeAPIs>\
+        <RetentionPolicy><Enabled>false</Enabled></RetentionPolicy></HourMetrics>\
+        <MinuteMetrics><Version>1.0</Version><Enabled>false</Enabled>\
+        <RetentionPolicy><Enabled>false</Enabled></RetentionPolicy></MinuteMetrics>\
+        <Cors><CorsRule><AllowedOrigins>http://contoso.com,http://fabrikam.com</AllowedOrigins>\
+        <AllowedMethods>GET,PUT</AllowedMethods><MaxAgeInSeconds>500</MaxAgeInSeconds>\
+        <ExposedHeaders>x-ms-meta-*</ExposedHeaders><AllowedHeaders></AllowedHeaders></CorsRule></Cors>\
+        <DefaultServiceVersion>2019-12-12</DefaultServiceVersion>\
+        <DeleteRetentionPolicy><Enabled>true</Enabled><Days>5</Days></DeleteRetentionPolicy>\
+        <StaticWebsite><Enabled>true</Enabled><IndexDocument>index.html</IndexDocument>\
+        <ErrorDocument404Path>404.html</ErrorDocument404Path></StaticWebsite>\
+        </StorageServiceProperties>";
+
+    #[test]
+    fn parse_service_properties() {
+        let properties = BlobServiceProperties::from_xml(PROPERTIES).unwrap();
+
+        let logging = properties.logging.as_ref().unwrap();
+        assert!(!logging.read);
+        assert!(logging.write && logging.delete);
+        assert_eq!(logging.retention_policy.days, Some(7));
+
+        let hour_metrics = properties.hour_metrics.as_ref().unwrap();
+        assert!(hour_metrics.enabled);
+        assert_eq!(hour_metrics.include_apis, Some(false));
+        assert_eq!(
+            properties.minute_metrics.as_ref().unwrap().include_apis,
+            None
+        );
+
+        let cors = properties.cors.as_ref().unwrap();
+        assert_eq!(cors.len(), 1);
+        assert_eq!(
+            cors[0].allowed_origins,
+            vec!["http://contoso.com", "http://fabrikam.com"]
+        );
+        assert_eq!(cors[0].allowed_methods, vec!["GET", "PUT"]);
+        assert!(cors[0].allowed_headers.is_empty());
+        assert_eq!(cors[0].max_age_in_seconds, 500);
+
+        assert_eq!(
+            properties.default_service_version.as_deref(),
+            Some("2019-12-12")
+        );
+        assert_eq!(
+            properties.delete_retention_policy,
+            Some(RetentionPolicy {
+                enabled: true,
+                days: Some(5)
+            })
+        );
+
+        let static_website = properties.static_website.as_ref().unwrap();
+        assert_eq!(static_website.index_document.as_deref(), Some("index.html"));
+        assert_eq!(static_website.default_index_document_path, None);
+    }
+
+    #[test]
+    fn service_properties_roundtrip() {
+        let properties = BlobServiceProperties::from_xml(PROPERTIES).unwrap();
+        let xml = properties.to_xml();
+
+        assert_eq!(BlobServiceProperties::from_xml(&xml).unwrap(), properties);
+        assert_eq!(
+            BlobServiceProperties::default().to_xml(),
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<StorageServiceProperties>\n</StorageServiceProperties>"
+        );
+    }
+
+    #[test]
+    fn parse_find_blobs_by_tags() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <EnumerationResults ServiceEndpoint=\"https://account.blob.core.windows.net/\">\
+            <Where>\"tenant\" = 'contoso'</Where>\
+            <Blobs>\
+            <Blob><Name>a.txt</Name><ContainerName>first</ContainerName>\
+            <Tags><TagSet><Tag><Key>tenant</Key><Value>contoso</Value></Tag></TagSet></Tags></Blob>\
+            <Blob><Name>b.txt</Name><ContainerName>second</ContainerName></Blob>\
+            </Blobs>\
+            <NextMarker>2!84!MDAwMDIx</NextMarker>\
+            </EnumerationResults>";
+
+        let blobs = TaggedBlob::incomplete_vector_from_xml(xml).unwrap();
+        assert_eq!(blobs.token(), Some("2!84!MDAwMDIx"));
+        assert_eq!(blobs.vector.len(), 2);
+        assert_eq!(blobs.vector[0].name, "a.txt");
+        assert_eq!(blobs.vector[0].container_name, "first");
+        assert_eq!(
+            blobs.vector[0].tags.as_ref().unwrap().get("tenant"),
+            Some("contoso")
+        );
+        assert_eq!(blobs.vector[1].tags, None);
+    }
+
+    #[test]
+    fn parse_user_delegation_key() {
+        let xml = "\u{feff}<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <UserDelegationKey>\
+            <SignedOid>a1b2c3d4-0000-0000-0000-000000000001</SignedOid>\
+            <SignedTid>72f988bf-0000-0000-0000-000000000002</SignedTid>\
+            <SignedStart>2020-06-01T00:00:00Z</SignedStart>\
+            <SignedExpiry>2020-06-02T00:00:00Z</SignedExpiry>\
+            <SignedService>b</SignedService>\
+            <SignedVersion>2019-12-12</SignedVersion>\
+            <Value>c2VjcmV0</Value>\
+            </UserDelegationKey>";
+
+        let key = user_delegation_key_from_xml(xml).unwrap();
+        assert_eq!(key.signed_tid, "72f988bf-0000-0000-0000-000000000002");
+        assert_eq!(key.signed_expiry.to_rfc3339(), "2020-06-02T00:00:00+00:00");
+        assert_eq!(key.signed_service, "b");
+        assert_eq!(key.value, "c2VjcmV0");
+        assert!(key_info_to_xml(key.signed_start, key.signed_expiry)
+            .contains("<Start>2020-06-01T00:00:00Z</Start>"));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::service::responses::BlobBatchResponse;
+use crate::service::{
+    generate_batch_boundary, BlobBatch, BlobBatchError, BlobBatchRequired, BlobBatchSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{header, Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_blob_batch: PhantomData<BlobBatchSet>,
+    blob_batch: Option<&'a BlobBatch>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> BlobBatchBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> BlobBatchBuilder<'a, C, No> {
+        BlobBatchBuilder {
+            client,
+            p_blob_batch: PhantomData {},
+            blob_batch: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, BlobBatchSet> ClientRequired<'a, C> for BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> BlobBatchRequired<'a> for BlobBatchBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn blob_batch(&self) -> &'a BlobBatch {
+        self.blob_batch.unwrap()
+    }
+}
+
+impl<'a, C, BlobBatchSet> TimeoutOption for BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, BlobBatchSet> ClientRequestIdOption<'a> for BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C> BlobBatchSupport<'a> for BlobBatchBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = BlobBatchBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_blob_batch(self, blob_batch: &'a BlobBatch) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: Some(blob_batch),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, BlobBatchSet> TimeoutSupport for BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    type O = BlobBatchBuilder<'a, C, BlobBatchSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: self.blob_batch,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, BlobBatchSet> ClientRequestIdSupport<'a> for BlobBatchBuilder<'a, C, BlobBatchSet>
+where
+    BlobBatchSet: ToAssign,
+    C: Client,
+{
+    type O = BlobBatchBuilder<'a, C, BlobBatchSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        BlobBatchBuilder {
+            client: self.client,
+            p_blob_batch: PhantomData {},
+            blob_batch: self.blob_batch,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> BlobBatchBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<BlobBatchResponse, AzureError> {
+        if self.blob_batch().is_empty() {
+            return Err(BlobBatchError::EmptyBatch.into());
+        }
+
+        let boundary = generate_batch_boundary();
+        let body = self.blob_batch().to_payload(self.client(), &boundary)?;
+
+        let mut uri = format!("{}/?comp=batch", self.client().blob_uri());
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let content_type = format!("multipart/mixed; boundary={}", boundary);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            &|mut request| {
+                request = request.header(header::CONTENT_TYPE, &content_type as &str);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        BlobBatchResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,290 @@
+use crate::service::responses::FindBlobsByTagsResponse;
+use crate::service::{TagFilterRequired, TagFilterSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use futures::stream::{unfold, Stream};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_tag_filter: PhantomData<TagFilterSet>,
+    tag_filter: Option<&'a str>,
+    next_marker: Option<&'a str>,
+    max_results: Option<u32>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> FindBlobsByTagsBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> FindBlobsByTagsBuilder<'a, C, No> {
+        FindBlobsByTagsBuilder {
+            client,
+            p_tag_filter: PhantomData {},
+            tag_filter: None,
+            next_marker: None,
+            max_results: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, TagFilterSet> ClientRequired<'a, C> for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> TagFilterRequired<'a> for FindBlobsByTagsBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn tag_filter(&self) -> &'a str {
+        self.tag_filter.unwrap()
+    }
+}
+
+impl<'a, C, TagFilterSet> NextMarkerOption<'a> for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn next_marker(&self) -> Option<&'a str> {
+        self.next_marker
+    }
+}
+
+impl<'a, C, TagFilterSet> MaxResultsOption for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, C, TagFilterSet> TimeoutOption for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, TagFilterSet> ClientRequestIdOption<'a> for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C> TagFilterSupport<'a> for FindBlobsByTagsBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = FindBlobsByTagsBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_tag_filter(self, tag_filter: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: Some(tag_filter),
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, TagFilterSet> NextMarkerSupport<'a> for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    type O = FindBlobsByTagsBuilder<'a, C, TagFilterSet>;
+
+    #[inline]
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: Some(next_marker),
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, TagFilterSet> MaxResultsSupport for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    type O = FindBlobsByTagsBuilder<'a, C, TagFilterSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: Some(max_results),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, TagFilterSet> TimeoutSupport for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    type O = FindBlobsByTagsBuilder<'a, C, TagFilterSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, TagFilterSet> ClientRequestIdSupport<'a> for FindBlobsByTagsBuilder<'a, C, TagFilterSet>
+where
+    TagFilterSet: ToAssign,
+    C: Client,
+{
+    type O = FindBlobsByTagsBuilder<'a, C, TagFilterSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        FindBlobsByTagsBuilder {
+            client: self.client,
+            p_tag_filter: PhantomData {},
+            tag_filter: self.tag_filter,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> FindBlobsByTagsBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<FindBlobsByTagsResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?comp=blobs&{}",
+            self.client().blob_uri(),
+            TagFilterRequired::to_uri_parameter(&self)
+        );
+
+        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = MaxResultsOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        FindBlobsByTagsResponse::from_response(&headers, &body)
+    }
+}
+
+impl<'a, C> FindBlobsByTagsBuilder<'a, C, Yes>
+where
+    C: Client + Clone,
+{
+    pub fn stream(self) -> impl Stream<Item = Result<FindBlobsByTagsResponse, AzureError>> + 'a {
+        #[derive(Debug, Clone, PartialEq)]
+        enum States {
+            Init,
+            NextMarker(String),
+        }
+
+        unfold(Some(States::Init), move |next_marker: Option<States>| {
+            let req = self.clone();
+            async move {
+                debug!("next_marker == {:?}", &next_marker);
+                let response = match next_marker {
+                    Some(States::Init) => req.finalize().await,
+                    Some(States::NextMarker(next_marker)) => {
+                        req.with_next_marker(&next_marker).finalize().await
+                    }
+                    None => return None,
+                };
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(err) => return Some((Err(err), None)),
+                };
+
+                let next_marker = response
+                    .incomplete_vector
+                    .token()
+                    .map(|ct| States::NextMarker(ct.to_owned()));
+
+                Some((Ok(response), next_marker))
+            }
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use crate::service::responses::GetPropertiesResponse;
+use crate::service::BlobServiceProperties;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::request_id_from_headers;
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+
+#[derive(Debug, Clone)]
+pub struct GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    client: &'a C,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> GetPropertiesBuilder<'a, C> {
+        GetPropertiesBuilder {
+            client,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C> ClientRequired<'a, C> for GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> TimeoutOption for GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> ClientRequestIdOption<'a> for GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C> TimeoutSupport for GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    type O = GetPropertiesBuilder<'a, C>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetPropertiesBuilder {
+            client: self.client,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C> ClientRequestIdSupport<'a> for GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    type O = GetPropertiesBuilder<'a, C>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetPropertiesBuilder {
+            client: self.client,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> GetPropertiesBuilder<'a, C>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<GetPropertiesResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=properties",
+            self.client().blob_uri()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        let body = std::str::from_utf8(&body)?;
+        Ok(GetPropertiesResponse {
+            blob_service_properties: BlobServiceProperties::from_xml(body)?,
+            request_id: request_id_from_headers(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,236 @@
+use crate::service::responses::GetUserDelegationKeyResponse;
+use crate::service::{
+    key_info_to_xml, user_delegation_key_from_xml, KeyExpiryRequired, KeyExpirySupport,
+    KeyStartRequired, KeyStartSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{date_from_headers, request_id_from_headers};
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use chrono::{DateTime, Utc};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_key_start: PhantomData<KeyStartSet>,
+    p_key_expiry: PhantomData<KeyExpirySet>,
+    key_start: Option<DateTime<Utc>>,
+    key_expiry: Option<DateTime<Utc>>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> GetUserDelegationKeyBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> GetUserDelegationKeyBuilder<'a, C, No, No> {
+        GetUserDelegationKeyBuilder {
+            client,
+            p_key_start: PhantomData {},
+            key_start: None,
+            p_key_expiry: PhantomData {},
+            key_expiry: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, KeyStartSet, KeyExpirySet> ClientRequired<'a, C>
+    for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, KeyExpirySet> KeyStartRequired for GetUserDelegationKeyBuilder<'a, C, Yes, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn key_start(&self) -> DateTime<Utc> {
+        self.key_start.unwrap()
+    }
+}
+
+impl<'a, C, KeyStartSet> KeyExpiryRequired for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, Yes>
+where
+    KeyStartSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn key_expiry(&self) -> DateTime<Utc> {
+        self.key_expiry.unwrap()
+    }
+}
+
+impl<'a, C, KeyStartSet, KeyExpirySet> TimeoutOption
+    for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, KeyStartSet, KeyExpirySet> ClientRequestIdOption<'a>
+    for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, KeyExpirySet> KeyStartSupport for GetUserDelegationKeyBuilder<'a, C, No, KeyExpirySet>
+where
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    type O = GetUserDelegationKeyBuilder<'a, C, Yes, KeyExpirySet>;
+
+    #[inline]
+    fn with_key_start(self, key_start: DateTime<Utc>) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_start: PhantomData {},
+            p_key_expiry: PhantomData {},
+            key_start: Some(key_start),
+            key_expiry: self.key_expiry,
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, KeyStartSet> KeyExpirySupport for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, No>
+where
+    KeyStartSet: ToAssign,
+    C: Client,
+{
+    type O = GetUserDelegationKeyBuilder<'a, C, KeyStartSet, Yes>;
+
+    #[inline]
+    fn with_key_expiry(self, key_expiry: DateTime<Utc>) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_start: PhantomData {},
+            p_key_expiry: PhantomData {},
+            key_start: self.key_start,
+            key_expiry: Some(key_expiry),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, KeyStartSet, KeyExpirySet> TimeoutSupport
+    for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    type O = GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_start: PhantomData {},
+            p_key_expiry: PhantomData {},
+            key_start: self.key_start,
+            key_expiry: self.key_expiry,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, KeyStartSet, KeyExpirySet> ClientRequestIdSupport<'a>
+    for GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>
+where
+    KeyStartSet: ToAssign,
+    KeyExpirySet: ToAssign,
+    C: Client,
+{
+    type O = GetUserDelegationKeyBuilder<'a, C, KeyStartSet, KeyExpirySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetUserDelegationKeyBuilder {
+            client: self.client,
+            p_key_start: PhantomData {},
+            p_key_expiry: PhantomData {},
+            key_start: self.key_start,
+            key_expiry: self.key_expiry,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> GetUserDelegationKeyBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<GetUserDelegationKeyResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=userdelegationkey",
+            self.client().blob_uri()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let xml = key_info_to_xml(self.key_start(), self.key_expiry());
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            Some(xml.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        let body = std::str::from_utf8(&body)?;
+        Ok(GetUserDelegationKeyResponse {
+            user_delegation_key: user_delegation_key_from_xml(body)?,
+            request_id: request_id_from_headers(&headers)?,
+            date: date_from_headers(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+mod blob_batch_builder;
+mod find_blobs_by_tags_builder;
+mod get_properties_builder;
+mod get_user_delegation_key_builder;
+mod set_properties_builder;
+pub use self::blob_batch_builder::BlobBatchBuilder;
+pub use self::find_blobs_by_tags_builder::FindBlobsByTagsBuilder;
+pub use self::get_properties_builder::GetPropertiesBuilder;
+pub use self::get_user_delegation_key_builder::GetUserDelegationKeyBuilder;
+pub use self::set_properties_builder::SetPropertiesBuilder;
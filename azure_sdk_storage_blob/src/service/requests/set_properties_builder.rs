@@ -0,0 +1,181 @@
+use crate::service::responses::SetPropertiesResponse;
+use crate::service::{
+    BlobServiceProperties, BlobServicePropertiesRequired, BlobServicePropertiesSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_blob_service_properties: PhantomData<BlobServicePropertiesSet>,
+    blob_service_properties: Option<&'a BlobServiceProperties>,
+    timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+}
+
+impl<'a, C> SetPropertiesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> SetPropertiesBuilder<'a, C, No> {
+        SetPropertiesBuilder {
+            client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: None,
+            timeout: None,
+            client_request_id: None,
+        }
+    }
+}
+
+impl<'a, C, BlobServicePropertiesSet> ClientRequired<'a, C>
+    for SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> BlobServicePropertiesRequired<'a> for SetPropertiesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn blob_service_properties(&self) -> &'a BlobServiceProperties {
+        self.blob_service_properties.unwrap()
+    }
+}
+
+impl<'a, C, BlobServicePropertiesSet> TimeoutOption
+    for SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, BlobServicePropertiesSet> ClientRequestIdOption<'a>
+    for SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C> BlobServicePropertiesSupport<'a> for SetPropertiesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = SetPropertiesBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_blob_service_properties(
+        self,
+        blob_service_properties: &'a BlobServiceProperties,
+    ) -> Self::O {
+        SetPropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: Some(blob_service_properties),
+            timeout: self.timeout,
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, BlobServicePropertiesSet> TimeoutSupport
+    for SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    type O = SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetPropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: self.blob_service_properties,
+            timeout: Some(timeout),
+            client_request_id: self.client_request_id,
+        }
+    }
+}
+
+impl<'a, C, BlobServicePropertiesSet> ClientRequestIdSupport<'a>
+    for SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>
+where
+    BlobServicePropertiesSet: ToAssign,
+    C: Client,
+{
+    type O = SetPropertiesBuilder<'a, C, BlobServicePropertiesSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetPropertiesBuilder {
+            client: self.client,
+            p_blob_service_properties: PhantomData {},
+            blob_service_properties: self.blob_service_properties,
+            timeout: self.timeout,
+            client_request_id: Some(client_request_id),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> SetPropertiesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<SetPropertiesResponse, AzureError> {
+        let mut uri = format!(
+            "{}/?restype=service&comp=properties",
+            self.client().blob_uri()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let xml = self.blob_service_properties().to_xml();
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            Some(xml.as_bytes()),
+        )?;
+
+        let (headers, _) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        SetPropertiesResponse::from_headers(&headers)
+    }
+}
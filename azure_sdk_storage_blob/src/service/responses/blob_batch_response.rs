@@ -0,0 +1,39 @@
+use crate::service::{batch_results_from_response, BlobBatchResult};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use hyper::header::CONTENT_TYPE;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobBatchResponse {
+    /// One result per operation, in the order they were added to the batch.
+    pub results: Vec<BlobBatchResult>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl BlobBatchResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<BlobBatchResponse, AzureError> {
+        let content_type = headers
+            .get_as_str(CONTENT_TYPE)
+            .ok_or_else(|| AzureError::HeaderNotFound(CONTENT_TYPE.as_str().to_owned()))?;
+
+        Ok(BlobBatchResponse {
+            results: batch_results_from_response(content_type, std::str::from_utf8(body)?)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+
+    /// `true` if every operation of the batch succeeded.
+    pub fn is_success(&self) -> bool {
+        self.results
+            .iter()
+            .all(|result| result.status_code.is_success())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::service::TaggedBlob;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindBlobsByTagsResponse {
+    pub incomplete_vector: IncompleteVector<TaggedBlob>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl FindBlobsByTagsResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<FindBlobsByTagsResponse, AzureError> {
+        Ok(FindBlobsByTagsResponse {
+            incomplete_vector: TaggedBlob::incomplete_vector_from_xml(std::str::from_utf8(body)?)?,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+        })
+    }
+}
@@ -0,0 +1,8 @@
+use crate::service::BlobServiceProperties;
+use azure_sdk_core::RequestId;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetPropertiesResponse {
+    pub blob_service_properties: BlobServiceProperties,
+    pub request_id: RequestId,
+}
@@ -0,0 +1,10 @@
+use azure_sdk_core::RequestId;
+use azure_sdk_storage_core::UserDelegationKey;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetUserDelegationKeyResponse {
+    pub user_delegation_key: UserDelegationKey,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
@@ -0,0 +1,10 @@
+mod get_properties_response;
+pub use self::get_properties_response::GetPropertiesResponse;
+mod set_properties_response;
+pub use self::set_properties_response::SetPropertiesResponse;
+mod find_blobs_by_tags_response;
+pub use self::find_blobs_by_tags_response::FindBlobsByTagsResponse;
+mod get_user_delegation_key_response;
+pub use self::get_user_delegation_key_response::GetUserDelegationKeyResponse;
+mod blob_batch_response;
+pub use self::blob_batch_response::BlobBatchResponse;
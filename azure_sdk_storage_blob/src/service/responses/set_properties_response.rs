@@ -0,0 +1,5 @@
+use azure_sdk_core::RequestId;
+
+response_from_headers!(SetPropertiesResponse ,
+               request_id_from_headers -> request_id: RequestId
+);
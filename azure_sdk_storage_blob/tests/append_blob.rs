@@ -59,3 +59,130 @@ async fn put_append_blob() {
 
     trace!("created {:?}", blob_name);
 }
+
+#[tokio::test]
+async fn compose_blobs_from_url() {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    let source_name: &'static str = "compose_source.txt";
+    let block_blob_name: &'static str = "compose_block.txt";
+    let append_blob_name: &'static str = "compose_append.txt";
+    let container_name: &'static str = "rust-upload-test";
+    let data = b"0123456789abcdef";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(source_name)
+        .with_body(&data[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    // the container allows anonymous reads so the source
+    // can be read without a SAS token
+    let source_url = format!("{}/{}/{}", client.blob_uri(), container_name, source_name);
+    let digest = md5::compute(&data[10..]);
+
+    // build a block blob out of the two halves of the source, swapped
+    let tail = Range::new(10, 16);
+    client
+        .put_block_from_url()
+        .with_container_name(container_name)
+        .with_blob_name(block_blob_name)
+        .with_source_url(&source_url)
+        .with_block_id(b"block1")
+        .with_source_range(&tail)
+        .with_source_content_md5(&digest[..])
+        .finalize()
+        .await
+        .unwrap();
+    let head = Range::new(0, 10);
+    client
+        .put_block_from_url()
+        .with_container_name(container_name)
+        .with_blob_name(block_blob_name)
+        .with_source_url(&source_url)
+        .with_block_id(b"block2")
+        .with_source_range(&head)
+        .finalize()
+        .await
+        .unwrap();
+
+    let block_list = BlockList {
+        blocks: vec![
+            BlobBlockType::Uncommitted(&b"block1"[..]),
+            BlobBlockType::Uncommitted(&b"block2"[..]),
+        ],
+    };
+    client
+        .put_block_list()
+        .with_container_name(container_name)
+        .with_blob_name(block_blob_name)
+        .with_block_list(&block_list)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_blob()
+        .with_container_name(container_name)
+        .with_blob_name(block_blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.data, b"abcdef0123456789");
+
+    client
+        .put_append_blob()
+        .with_container_name(container_name)
+        .with_blob_name(append_blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    for (position, range) in [head, tail].iter().enumerate() {
+        client
+            .append_block_from_url()
+            .with_container_name(container_name)
+            .with_blob_name(append_blob_name)
+            .with_source_url(&source_url)
+            .with_source_range(range)
+            .with_append_position(position as u32 * 10)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let response = client
+        .get_blob()
+        .with_container_name(container_name)
+        .with_blob_name(append_blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(&response.data[..], &data[..]);
+}
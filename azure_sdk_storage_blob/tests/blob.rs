@@ -5,12 +5,14 @@ extern crate log;
 use azure_sdk_core::prelude::*;
 use azure_sdk_core::{Consistency, DeleteSnapshotsMethod};
 use azure_sdk_storage_blob::{
-    blob::BlockListType,
+    blob::{ArchiveStatus, BlockListType},
     container::{Container, PublicAccess, PublicAccessSupport},
     prelude::*,
 };
 use azure_sdk_storage_core::prelude::*;
 use chrono::{Duration, FixedOffset, Utc};
+use hyper::StatusCode;
+use std::collections::HashMap;
 use std::ops::Add;
 use std::ops::Deref;
 use uuid::Uuid;
@@ -340,6 +342,431 @@ async fn put_block_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn upload_and_download_blob() {
+    let client = initialize();
+
+    let blob_name: &'static str = "chunked";
+    let container_name: &'static str = "rust-upload-test";
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let uploaded = client
+        .upload_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_chunk_size(1024)
+        .with_max_concurrency(3)
+        .finalize_from_reader(&data[..])
+        .await
+        .unwrap();
+    assert_eq!(uploaded.block_count, 10);
+
+    let mut downloaded = Vec::new();
+    let response = client
+        .download_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_chunk_size(1000)
+        .with_max_concurrency(3)
+        .finalize_to_writer(&mut downloaded)
+        .await
+        .unwrap();
+
+    assert_eq!(response.chunk_count, 10);
+    assert_eq!(response.blob.content_length, data.len() as u64);
+    assert_eq!(downloaded, data);
+
+    client
+        .delete_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_and_get_blob_metadata_and_properties() {
+    let client = initialize();
+
+    let blob_name: &'static str = "properties";
+    let container_name: &'static str = "rust-upload-test";
+    let data = b"abcdef";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let put = client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(&data[..])
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("color", "red");
+    let set = client
+        .set_blob_metadata()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_metadata(&metadata)
+        .with_if_match_condition(IfMatchCondition::Match(&put.etag))
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_blob_metadata()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.etag, set.etag);
+    assert_eq!(
+        response.metadata.get("color").map(|v| v.as_str()),
+        Some("red")
+    );
+
+    client
+        .set_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("text/plain")
+        .with_cache_control("no-cache")
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob.content_type.as_deref(), Some("text/plain"));
+    assert_eq!(response.blob.cache_control.as_deref(), Some("no-cache"));
+    assert_eq!(response.blob.content_length, data.len() as u64);
+    assert_eq!(
+        response.blob.metadata.get("color").map(|v| v.as_str()),
+        Some("red")
+    );
+
+    // a stale ETag must be refused
+    assert!(client
+        .set_blob_metadata()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_if_match_condition(IfMatchCondition::Match(&put.etag))
+        .finalize()
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn set_blob_tier() {
+    let client = initialize();
+
+    let blob_name: &'static str = "tiered";
+    let container_name: &'static str = "rust-upload-test";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(b"abcdef")
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .set_blob_tier()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_access_tier(AccessTier::Cool)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(!response.rehydration_pending);
+
+    let response = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob.access_tier, Some(AccessTier::Cool));
+    assert_eq!(response.blob.archive_status, None);
+
+    client
+        .set_blob_tier()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_access_tier(AccessTier::Archive)
+        .finalize()
+        .await
+        .unwrap();
+
+    // leaving the archive tier requires a rehydration
+    let response = client
+        .set_blob_tier()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_access_tier(AccessTier::Hot)
+        .with_rehydrate_priority(RehydratePriority::Standard)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(response.rehydration_pending);
+
+    let response = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob.access_tier, Some(AccessTier::Archive));
+    assert_eq!(
+        response.blob.archive_status,
+        Some(ArchiveStatus::RehydratePendingToHot)
+    );
+}
+
+#[tokio::test]
+async fn set_get_and_find_blob_tags() {
+    let client = initialize();
+
+    let blob_name: &'static str = "tagged";
+    let container_name: &'static str = "rust-upload-test";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let mut tags = Tags::new();
+    tags.insert("tenant", "contoso");
+    tags.insert("stage", "raw");
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_body(b"abcdef")
+        .with_tags(&tags)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_blob_tags()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.tags, tags);
+
+    tags.insert("stage", "processed");
+    client
+        .set_blob_tags()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_tags(&tags)
+        .with_if_tags("\"stage\" = 'raw'")
+        .finalize()
+        .await
+        .unwrap();
+
+    // the condition does not hold anymore
+    assert!(client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_if_tags("\"stage\" = 'raw'")
+        .finalize()
+        .await
+        .is_err());
+
+    // the tag index is eventually consistent so we cannot expect the
+    // blob to be found right away
+    let response = client
+        .find_blobs_by_tags()
+        .with_tag_filter("\"tenant\" = 'contoso'")
+        .with_max_results(10)
+        .finalize()
+        .await
+        .unwrap();
+    for blob in response.incomplete_vector.iter() {
+        assert_eq!(
+            blob.tags.as_ref().and_then(|tags| tags.get("tenant")),
+            Some("contoso")
+        );
+    }
+}
+
+#[tokio::test]
+async fn blob_batch() {
+    let client = initialize();
+
+    let container_name: &'static str = "rust-batch-test";
+
+    client
+        .create_container()
+        .with_container_name(container_name)
+        .with_public_access(PublicAccess::None)
+        .finalize()
+        .await
+        .unwrap();
+
+    let blob_names = (0..10).map(|i| format!("batched{}", i)).collect::<Vec<_>>();
+
+    for blob_name in blob_names.iter() {
+        client
+            .put_block_blob()
+            .with_container_name(container_name)
+            .with_blob_name(blob_name)
+            .with_body(b"abcdef")
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    let mut batch = BlobBatch::new();
+    for blob_name in blob_names.iter() {
+        batch
+            .add_set_tier(container_name, blob_name, AccessTier::Cool)
+            .unwrap();
+    }
+
+    let response = client
+        .blob_batch()
+        .with_blob_batch(&batch)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.results.len(), blob_names.len());
+    assert!(response.is_success());
+
+    let response = client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(&blob_names[3])
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob.access_tier, Some(AccessTier::Cool));
+
+    let mut batch = BlobBatch::new();
+    for blob_name in blob_names.iter() {
+        batch.add_delete(container_name, blob_name).unwrap();
+    }
+    batch.add_delete(container_name, "missing").unwrap();
+
+    let response = client
+        .blob_batch()
+        .with_blob_batch(&batch)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.results.len(), blob_names.len() + 1);
+    assert!(response.results[..blob_names.len()]
+        .iter()
+        .all(|result| result.status_code == StatusCode::ACCEPTED));
+    let missing = response.results.last().unwrap();
+    assert_eq!(missing.status_code, StatusCode::NOT_FOUND);
+    assert_eq!(missing.error_code.as_deref(), Some("BlobNotFound"));
+
+    assert!(client
+        .get_blob_properties()
+        .with_container_name(container_name)
+        .with_blob_name(&blob_names[0])
+        .finalize()
+        .await
+        .is_err());
+
+    client
+        .delete_container()
+        .with_container_name(container_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
 fn initialize() -> Box<dyn Client> {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
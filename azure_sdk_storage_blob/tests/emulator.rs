@@ -0,0 +1,185 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::DeleteSnapshotsMethod;
+use azure_sdk_storage_blob::{blob::CopyStatus, container::PublicAccess, prelude::*};
+use azure_sdk_storage_core::prelude::*;
+use std::collections::HashMap;
+use std::time::Duration;
+use url::Url;
+
+#[tokio::test]
+async fn snapshot_and_undelete_blob() {
+    let container_name = "emulsnapshot";
+    let blob_name = "snapshotted.txt";
+
+    let client = initialize();
+
+    client
+        .create_container()
+        .with_container_name(container_name)
+        .with_public_access(PublicAccess::None)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("text/plain")
+        .with_body(b"first version")
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("version", "1");
+
+    let snapshot = client
+        .snapshot_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_type("text/plain")
+        .with_body(b"second version")
+        .finalize()
+        .await
+        .unwrap();
+
+    let snapshot_content = client
+        .get_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_snapshot(snapshot.snapshot)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(snapshot_content.data, b"first version");
+    assert_eq!(
+        snapshot_content
+            .blob
+            .metadata
+            .get("version")
+            .map(String::as_str),
+        Some("1")
+    );
+
+    client
+        .delete_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+        .finalize()
+        .await
+        .unwrap();
+
+    // undelete only succeeds if soft delete is enabled on the account,
+    // otherwise the service answers with 404
+    if client
+        .undelete_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .is_ok()
+    {
+        let content = client
+            .get_blob()
+            .with_container_name(container_name)
+            .with_blob_name(blob_name)
+            .finalize()
+            .await
+            .unwrap();
+        assert_eq!(content.data, b"second version");
+    }
+
+    client
+        .delete_container()
+        .with_container_name(container_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn copy_blob_and_wait() {
+    let container_name = "emulcopy";
+    let source_name = "source.txt";
+    let destination_name = "destination.txt";
+
+    let client = initialize();
+
+    client
+        .create_container()
+        .with_container_name(container_name)
+        .with_public_access(PublicAccess::None)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_block_blob()
+        .with_container_name(container_name)
+        .with_blob_name(source_name)
+        .with_content_type("text/plain")
+        .with_body(b"copy me")
+        .finalize()
+        .await
+        .unwrap();
+
+    let source_url = format!("{}/{}/{}", client.blob_uri(), container_name, source_name);
+    let copy = client
+        .copy_blob()
+        .with_container_name(container_name)
+        .with_blob_name(destination_name)
+        .with_source_url(&source_url)
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut polls = 0;
+    let properties = client
+        .wait_for_blob_copy()
+        .with_container_name(container_name)
+        .with_blob_name(destination_name)
+        .with_poll_interval(Duration::from_millis(100))
+        .finalize_with_progress(|_| polls += 1)
+        .await
+        .unwrap();
+    assert!(polls > 0);
+    assert_eq!(properties.blob.copy_status, Some(CopyStatus::Success));
+    assert_eq!(properties.blob.copy_id, Some(copy.copy_id.to_string()));
+
+    // a finished copy cannot be aborted anymore
+    assert!(client
+        .abort_copy_blob()
+        .with_container_name(container_name)
+        .with_blob_name(destination_name)
+        .with_copy_id(&copy.copy_id)
+        .finalize()
+        .await
+        .is_err());
+
+    client
+        .delete_container()
+        .with_container_name(container_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> Box<dyn Client> {
+    Box::new(client::with_emulator(
+        &Url::parse("http://127.0.0.1:10000").unwrap(),
+        &Url::parse("http://127.0.0.1:10002").unwrap(),
+        &Url::parse("http://127.0.0.1:10001").unwrap(),
+    ))
+}
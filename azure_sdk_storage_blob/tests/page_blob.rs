@@ -2,7 +2,8 @@
 #[macro_use]
 extern crate log;
 use azure_sdk_core::prelude::*;
-use azure_sdk_storage_blob::prelude::*;
+use azure_sdk_core::DeleteSnapshotsMethod;
+use azure_sdk_storage_blob::{blob::CopyStatus, prelude::*};
 use azure_sdk_storage_core::prelude::*;
 use std::collections::HashMap;
 
@@ -54,6 +55,168 @@ async fn put_page_blob() {
     trace!("created {:?}", blob_name);
 }
 
+#[tokio::test]
+async fn page_ranges_diff_and_incremental_copy() {
+    let client = initialize();
+
+    let blob_name: &'static str = "disk.vhd";
+    let backup_name: &'static str = "disk-backup.vhd";
+    let container_name: &'static str = "rust-upload-test";
+
+    if client
+        .list_containers()
+        .finalize()
+        .await
+        .unwrap()
+        .incomplete_vector
+        .iter()
+        .find(|x| x.name == container_name)
+        .is_none()
+    {
+        client
+            .create_container()
+            .with_container_name(container_name)
+            .with_public_access(PublicAccess::Blob)
+            .finalize()
+            .await
+            .unwrap();
+    }
+
+    for name in &[blob_name, backup_name] {
+        let _ = client
+            .delete_blob()
+            .with_container_name(container_name)
+            .with_blob_name(name)
+            .with_delete_snapshots_method(DeleteSnapshotsMethod::Include)
+            .finalize()
+            .await;
+    }
+
+    client
+        .put_page_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_length(1024 * 64)
+        .finalize()
+        .await
+        .unwrap();
+
+    let data = vec![b'a'; 512];
+    let first_page = BA512Range::new(0, 511).unwrap();
+    client
+        .update_page()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_ba512_range(&first_page)
+        .with_body(&data)
+        .finalize()
+        .await
+        .unwrap();
+
+    let snapshot = client
+        .snapshot_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap()
+        .snapshot;
+
+    let third_page = BA512Range::new(1024, 1535).unwrap();
+    client
+        .update_page()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_ba512_range(&third_page)
+        .with_body(&data)
+        .finalize()
+        .await
+        .unwrap();
+    client
+        .clear_page()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_ba512_range(&first_page)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_page_ranges()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.page_ranges.page_ranges, vec![third_page]);
+
+    let response = client
+        .get_page_ranges()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_prev_snapshot(snapshot)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.page_ranges.page_ranges, vec![third_page]);
+    assert_eq!(response.page_ranges.clear_ranges, vec![first_page]);
+
+    let response = client
+        .resize_page_blob()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_content_length(1024 * 128)
+        .finalize()
+        .await
+        .unwrap();
+    trace!("resize_page_blob == {:?}", response);
+
+    let response = client
+        .set_blob_sequence_number()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_sequence_number_action(SequenceNumberAction::Update(7))
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.sequence_number, 7);
+    let response = client
+        .set_blob_sequence_number()
+        .with_container_name(container_name)
+        .with_blob_name(blob_name)
+        .with_sequence_number_action(SequenceNumberAction::Increment)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.sequence_number, 8);
+
+    // the container allows anonymous reads so the snapshot url
+    // can be used as copy source without a SAS token
+    let source_url = format!(
+        "{}/{}/{}?snapshot={}",
+        client.blob_uri(),
+        container_name,
+        blob_name,
+        snapshot.format("%Y-%m-%dT%H:%M:%S%.7fZ")
+    );
+    client
+        .incremental_copy_blob()
+        .with_container_name(container_name)
+        .with_blob_name(backup_name)
+        .with_source_url(&source_url)
+        .finalize()
+        .await
+        .unwrap();
+    let response = client
+        .wait_for_blob_copy()
+        .with_container_name(container_name)
+        .with_blob_name(backup_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob.copy_status, Some(CopyStatus::Success));
+}
+
 fn initialize() -> Box<dyn Client> {
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
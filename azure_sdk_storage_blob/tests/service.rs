@@ -0,0 +1,39 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_storage_blob::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+
+#[tokio::test]
+async fn get_and_set_service_properties() {
+    let client = initialize();
+
+    let response = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap();
+    let properties = response.blob_service_properties;
+
+    // writing back what we read must not change anything
+    client
+        .set_blob_service_properties()
+        .with_blob_service_properties(&properties)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_blob_service_properties()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.blob_service_properties, properties);
+}
+
+fn initialize() -> Box<dyn Client> {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    Box::new(client::with_access_key(&account, &master_key))
+}
@@ -47,4 +47,18 @@ pub fn main() {
         .finalize();
 
     println!("SAS == {}", sas);
+
+    let path = Url::parse("https://azureskdforrust.table.core.windows.net/test").unwrap();
+
+    let sas = TableSASBuilder::new(&path)
+        .with_key(&master_key)
+        .with_validity_start(&start)
+        .with_validity_end(&end)
+        .with_starting_partition_key("2020")
+        .with_ending_partition_key("2020")
+        .allow_read()
+        .allow_update()
+        .finalize();
+
+    println!("SAS == {}", sas);
 }
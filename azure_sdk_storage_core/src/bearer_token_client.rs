@@ -1,19 +1,25 @@
+use crate::client::check_service_uri;
 use crate::prelude::*;
-use crate::rest_client::{AZURE_VERSION, HEADER_DATE, HEADER_VERSION};
+use crate::rest_client::{ServiceType, AZURE_VERSION, HEADER_DATE, HEADER_VERSION};
 use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::http_client::{HttpClient, ResponseFuture};
+use azure_sdk_core::retry_policy::{RetryHttpClient, RetryPolicy};
 use azure_sdk_core::util::{format_header_value, RequestBuilderExt};
 use http::request::Builder;
 use hyper::{header, Method};
-use hyper_rustls::HttpsConnector;
 use std::borrow::Cow;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub struct BearerTokenClient<'a> {
     account: Cow<'a, str>,
     bearer_token: Cow<'a, str>,
-    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    http_client: RetryHttpClient,
     blob_uri: String,
     table_uri: String,
+    queue_uri: String,
+    file_uri: String,
+    dfs_uri: String,
 }
 
 impl<'a> BearerTokenClient<'a> {
@@ -21,17 +27,40 @@ impl<'a> BearerTokenClient<'a> {
     pub(crate) fn new(
         account: Cow<'a, str>,
         bearer_token: Cow<'a, str>,
-        hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+        http_client: Arc<dyn HttpClient>,
     ) -> Self {
         let blob_uri = format!("https://{}.blob.core.windows.net", account);
         let table_uri = format!("https://{}.table.core.windows.net", account);
+        let queue_uri = format!("https://{}.queue.core.windows.net", account);
+        let file_uri = format!("https://{}.file.core.windows.net", account);
+        let dfs_uri = format!("https://{}.dfs.core.windows.net", account);
 
         Self {
             account,
             bearer_token,
-            hc,
+            http_client: RetryHttpClient::new(http_client, RetryPolicy::no_retry()),
             blob_uri,
             table_uri,
+            queue_uri,
+            file_uri,
+            dfs_uri,
+        }
+    }
+
+    /// Returns a copy of this client that sends its requests through `http_client`.
+    pub fn with_http_client(&self, http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            http_client: self.http_client.with_http_client(http_client),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this client that retries its requests according to `retry_policy`.
+    /// By default requests are not retried.
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            http_client: self.http_client.with_retry_policy(retry_policy),
+            ..self.clone()
         }
     }
 
@@ -41,7 +70,8 @@ impl<'a> BearerTokenClient<'a> {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
+        check_service_uri(uri)?;
         let dt = chrono::Utc::now();
         let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
 
@@ -75,7 +105,35 @@ impl<'a> BearerTokenClient<'a> {
             format_header_value(format!("Bearer {}", self.bearer_token))?,
         );
 
-        Ok(self.hc.request(request))
+        Ok(self.http_client.request(request))
+    }
+
+    fn prepare_sub_request_internal(
+        &self,
+        uri: &str,
+        method: &Method,
+        http_header_adder: &dyn Fn(Builder) -> Builder,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        check_service_uri(uri)?;
+        let dt = chrono::Utc::now();
+        let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
+
+        let mut request = hyper::Request::builder();
+        request = request
+            .method(method)
+            .uri(uri)
+            .header_static(header::CONTENT_LENGTH, "0");
+        request = http_header_adder(request);
+        request = request.header_bytes(HEADER_DATE, time);
+
+        let mut request = request.body(())?;
+
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            format_header_value(format!("Bearer {}", self.bearer_token))?,
+        );
+
+        Ok(request)
     }
 }
 
@@ -90,6 +148,21 @@ impl<'a> Client for BearerTokenClient<'a> {
         &self.table_uri
     }
 
+    #[inline]
+    fn queue_uri(&self) -> &str {
+        &self.queue_uri
+    }
+
+    #[inline]
+    fn file_uri(&self) -> &str {
+        &self.file_uri
+    }
+
+    #[inline]
+    fn dfs_uri(&self) -> &str {
+        &self.dfs_uri
+    }
+
     #[inline]
     fn perform_request(
         &self,
@@ -97,7 +170,7 @@ impl<'a> Client for BearerTokenClient<'a> {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.perform_request_internal(uri, method, http_header_adder, request_body)
     }
 
@@ -108,7 +181,19 @@ impl<'a> Client for BearerTokenClient<'a> {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.perform_request_internal(segment, method, http_header_adder, request_body)
     }
+
+    // the bearer token is valid for every service
+    #[inline]
+    fn prepare_sub_request(
+        &self,
+        uri: &str,
+        method: &Method,
+        http_header_adder: &dyn Fn(Builder) -> Builder,
+        _service_type: ServiceType,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        self.prepare_sub_request_internal(uri, method, http_header_adder)
+    }
 }
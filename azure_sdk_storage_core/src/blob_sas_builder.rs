@@ -1,6 +1,6 @@
 use crate::rest_client::{generate_storage_sas, SASType};
 use crate::ClientEndpoint;
-use crate::IPRange;
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
@@ -52,6 +52,7 @@ where
     p_validity_end: PhantomData<ValidityEndSet>,
     at_least_one_permission: PhantomData<AtLeastOnePermission>,
     key: Option<&'a str>,
+    user_delegation_key: Option<&'a UserDelegationKey>,
     identifier: Option<&'a str>,
     ip_range: Option<&'a IPRange>,
     validity_start: Option<&'a DateTime<Utc>>,
@@ -76,6 +77,7 @@ impl<'a> BlobSASBuilder<'a, No, No, No> {
             path,
             p_key: PhantomData {},
             key: None,
+            user_delegation_key: None,
             p_validity_end: PhantomData {},
             at_least_one_permission: PhantomData {},
             validity_end: None,
@@ -113,6 +115,39 @@ where
             path: self.path,
             p_key: PhantomData {},
             key: Some(key),
+            user_delegation_key: None,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+        }
+    }
+
+    /// Signs the SAS with a user delegation key instead of the
+    /// account key, for callers authenticated with Azure AD.
+    #[inline]
+    pub fn with_user_delegation_key(
+        &self,
+        user_delegation_key: &'a UserDelegationKey,
+    ) -> BlobSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        BlobSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(&user_delegation_key.value),
+            user_delegation_key: Some(user_delegation_key),
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -162,6 +197,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: PhantomData {},
             at_least_one_permission: self.at_least_one_permission,
             validity_end: Some(validity_end),
@@ -207,6 +243,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -233,6 +270,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -259,6 +297,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -285,6 +324,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -311,6 +351,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -349,6 +390,11 @@ where
         self.identifier
     }
 
+    #[inline]
+    pub fn user_delegation_key(&self) -> Option<&'a UserDelegationKey> {
+        self.user_delegation_key
+    }
+
     #[inline]
     pub fn ip_range(&self) -> Option<&'a IPRange> {
         self.ip_range
@@ -416,6 +462,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -442,6 +489,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -468,6 +516,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -494,6 +543,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -520,6 +570,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -546,6 +597,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -572,6 +624,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -598,6 +651,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -624,6 +678,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -691,6 +746,7 @@ impl<'a> BlobSASBuilder<'a, Yes, Yes, Yes> {
             "",
             "",
             "",
+            self.user_delegation_key(),
         );
 
         if self.path().query().is_some() {
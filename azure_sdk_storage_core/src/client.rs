@@ -3,12 +3,25 @@ use crate::key_client::get_sas_token_parms;
 use crate::rest_client::ServiceType;
 use crate::{ConnectionString, KeyClient};
 use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::http_client::{default_http_client, ResponseFuture};
 use http::request::Builder;
 use hyper::{self, Method};
-use hyper_rustls::HttpsConnector;
 use std::borrow::Cow;
 use url::Url;
 
+/// Fails for a uri built on an empty service endpoint, i.e. a service the
+/// client does not provide, instead of letting the uri parsing fail later.
+pub(crate) fn check_service_uri(uri: &str) -> Result<(), AzureError> {
+    if uri.is_empty() || uri.starts_with('/') {
+        Err(AzureError::GenericErrorWithText(format!(
+            "the client does not provide the service requested by {:?}",
+            uri
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 pub trait HttpHeaderAdder {
     fn add_headers(&self, builder: ::http::request::Builder) -> ::http::request::Builder;
 }
@@ -17,11 +30,30 @@ pub trait Client: Send + Sync {
     fn blob_uri(&self) -> &str;
     fn table_uri(&self) -> &str;
 
+    /// Clients without a queue endpoint keep the default, an empty uri:
+    /// requests built on it fail with an error naming the missing service.
+    fn queue_uri(&self) -> &str {
+        ""
+    }
+
+    /// Defaults to an empty uri, like `queue_uri`.
+    fn file_uri(&self) -> &str {
+        ""
+    }
+
+    /// Defaults to an empty uri, like `queue_uri`.
+    fn dfs_uri(&self) -> &str {
+        ""
+    }
+
     /// Uri scheme + authority e.g. http://myaccount.table.core.windows.net/
     #[inline]
     fn get_uri_prefix(&self, service_type: ServiceType) -> String {
         match service_type {
             ServiceType::Blob => format!("{}/", self.blob_uri()),
+            ServiceType::Queue => format!("{}/", self.queue_uri()),
+            ServiceType::File => format!("{}/", self.file_uri()),
+            ServiceType::Dfs => format!("{}/", self.dfs_uri()),
             ServiceType::Table => format!("{}/", self.table_uri()),
         }
     }
@@ -32,7 +64,7 @@ pub trait Client: Send + Sync {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>;
+    ) -> Result<ResponseFuture, AzureError>;
 
     fn perform_table_request(
         &self,
@@ -40,7 +72,22 @@ pub trait Client: Send + Sync {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError>;
+    ) -> Result<ResponseFuture, AzureError>;
+
+    /// Builds, without sending it, a request to embed in a batch, signed for
+    /// `service_type`. Clients that cannot sign sub-requests keep the default,
+    /// which fails.
+    fn prepare_sub_request(
+        &self,
+        _uri: &str,
+        _method: &Method,
+        _http_header_adder: &dyn Fn(Builder) -> Builder,
+        _service_type: ServiceType,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        Err(AzureError::GenericErrorWithText(
+            "this client does not support batch sub-requests".to_owned(),
+        ))
+    }
 }
 
 impl<C> Client for Box<C>
@@ -53,6 +100,15 @@ where
     fn table_uri(&self) -> &str {
         self.as_ref().table_uri()
     }
+    fn queue_uri(&self) -> &str {
+        self.as_ref().queue_uri()
+    }
+    fn file_uri(&self) -> &str {
+        self.as_ref().file_uri()
+    }
+    fn dfs_uri(&self) -> &str {
+        self.as_ref().dfs_uri()
+    }
 
     fn perform_request(
         &self,
@@ -60,7 +116,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_request(uri, method, http_header_adder, request_body)
     }
@@ -71,10 +127,21 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_table_request(segment, method, http_header_adder, request_str)
     }
+
+    fn prepare_sub_request(
+        &self,
+        uri: &str,
+        method: &Method,
+        http_header_adder: &dyn Fn(Builder) -> Builder,
+        service_type: ServiceType,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        self.as_ref()
+            .prepare_sub_request(uri, method, http_header_adder, service_type)
+    }
 }
 
 impl<C> Client for std::sync::Arc<C>
@@ -87,6 +154,15 @@ where
     fn table_uri(&self) -> &str {
         self.as_ref().table_uri()
     }
+    fn queue_uri(&self) -> &str {
+        self.as_ref().queue_uri()
+    }
+    fn file_uri(&self) -> &str {
+        self.as_ref().file_uri()
+    }
+    fn dfs_uri(&self) -> &str {
+        self.as_ref().dfs_uri()
+    }
 
     fn perform_request(
         &self,
@@ -94,7 +170,7 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_request(uri, method, http_header_adder, request_body)
     }
@@ -105,10 +181,21 @@ where
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         self.as_ref()
             .perform_table_request(segment, method, http_header_adder, request_str)
     }
+
+    fn prepare_sub_request(
+        &self,
+        uri: &str,
+        method: &Method,
+        http_header_adder: &dyn Fn(Builder) -> Builder,
+        service_type: ServiceType,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        self.as_ref()
+            .prepare_sub_request(uri, method, http_header_adder, service_type)
+    }
 }
 
 //
@@ -123,7 +210,7 @@ pub fn new(account: &str, key: &str) -> KeyClient {
 }
 
 pub fn with_azure_sas(account: &str, sas_token: &str) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let client = default_http_client();
     let params = get_sas_token_parms(sas_token);
 
     KeyClient::new(
@@ -133,11 +220,14 @@ pub fn with_azure_sas(account: &str, sas_token: &str) -> KeyClient {
         client,
         format!("https://{}.blob.core.windows.net", account),
         format!("https://{}.table.core.windows.net", account),
+        format!("https://{}.queue.core.windows.net", account),
+        format!("https://{}.file.core.windows.net", account),
+        format!("https://{}.dfs.core.windows.net", account),
     )
 }
 
 pub fn with_access_key(account: &str, key: &str) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let client = default_http_client();
 
     KeyClient::new(
         account.to_owned(),
@@ -146,11 +236,14 @@ pub fn with_access_key(account: &str, key: &str) -> KeyClient {
         client,
         format!("https://{}.blob.core.windows.net", account),
         format!("https://{}.table.core.windows.net", account),
+        format!("https://{}.queue.core.windows.net", account),
+        format!("https://{}.file.core.windows.net", account),
+        format!("https://{}.dfs.core.windows.net", account),
     )
 }
 
 pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, AzureError> {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let client = default_http_client();
 
     match ConnectionString::new(connection_string)? {
             ConnectionString {
@@ -166,7 +259,10 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                     Some(get_sas_token_parms(sas_token)),
                     client,
                     format!("https://{}.blob.core.windows.net", account),
-                    format!("https://{}.table.core.windows.net", account), 
+                    format!("https://{}.table.core.windows.net", account),
+                    format!("https://{}.queue.core.windows.net", account),
+                    format!("https://{}.file.core.windows.net", account),
+                    format!("https://{}.dfs.core.windows.net", account),
                 ))
             }
             ConnectionString {
@@ -179,7 +275,10 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                 Some(get_sas_token_parms(sas_token)),
                 client,
                 format!("https://{}.blob.core.windows.net", account),
-                format!("https://{}.table.core.windows.net", account), 
+                format!("https://{}.table.core.windows.net", account),
+                format!("https://{}.queue.core.windows.net", account),
+                format!("https://{}.file.core.windows.net", account),
+                format!("https://{}.dfs.core.windows.net", account),
             )),
             ConnectionString {
                 account_name: Some(account),
@@ -191,7 +290,10 @@ pub fn from_connection_string(connection_string: &str) -> Result<KeyClient, Azur
                 None,
                 client,
                 format!("https://{}.blob.core.windows.net", account),
-                format!("https://{}.table.core.windows.net", account), 
+                format!("https://{}.table.core.windows.net", account),
+                format!("https://{}.queue.core.windows.net", account),
+                format!("https://{}.file.core.windows.net", account),
+                format!("https://{}.dfs.core.windows.net", account),
             )),
             _ => {
                 Err(AzureError::GenericErrorWithText(
@@ -207,18 +309,28 @@ where
     A: Into<Cow<'a, str>>,
     BT: Into<Cow<'a, str>>,
 {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+    let client = default_http_client();
 
     BearerTokenClient::new(account.into(), bearer_token.into(), client)
 }
 
-pub fn with_emulator(blob_storage_url: &Url, table_storage_url: &Url) -> KeyClient {
-    let client = hyper::Client::builder().build(HttpsConnector::new());
+pub fn with_emulator(
+    blob_storage_url: &Url,
+    table_storage_url: &Url,
+    queue_storage_url: &Url,
+) -> KeyClient {
+    let client = default_http_client();
 
     let blob_uri = format!("{}devstoreaccount1", blob_storage_url.as_str());
     debug!("blob_uri == {}", blob_uri);
     let table_uri = format!("{}devstoreaccount1", table_storage_url.as_str());
     debug!("table_uri == {}", table_uri);
+    let queue_uri = format!("{}devstoreaccount1", queue_storage_url.as_str());
+    debug!("queue_uri == {}", queue_uri);
+    // the storage emulator does not provide the file and the dfs services:
+    // requests to them fail as for any client without these endpoints
+    let file_uri = String::new();
+    let dfs_uri = String::new();
 
     KeyClient::new(
         "devstoreaccount1".to_owned(),
@@ -228,5 +340,112 @@ pub fn with_emulator(blob_storage_url: &Url, table_storage_url: &Url) -> KeyClie
         client,
         blob_uri,
         table_uri,
+        queue_uri,
+        file_uri,
+        dfs_uri,
     )
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a client implemented outside of this crate, unaware of batches
+    struct MinimalClient;
+
+    impl Client for MinimalClient {
+        fn blob_uri(&self) -> &str {
+            "https://myaccount.blob.core.windows.net"
+        }
+        fn table_uri(&self) -> &str {
+            "https://myaccount.table.core.windows.net"
+        }
+
+        fn perform_request(
+            &self,
+            _uri: &str,
+            _method: &Method,
+            _http_header_adder: &dyn Fn(Builder) -> Builder,
+            _request_body: Option<&[u8]>,
+        ) -> Result<ResponseFuture, AzureError> {
+            Err(AzureError::GenericErrorWithText(
+                "MinimalClient sends no request".to_owned(),
+            ))
+        }
+
+        fn perform_table_request(
+            &self,
+            _segment: &str,
+            _method: &Method,
+            _http_header_adder: &dyn Fn(Builder) -> Builder,
+            _request_str: Option<&[u8]>,
+        ) -> Result<ResponseFuture, AzureError> {
+            Err(AzureError::GenericErrorWithText(
+                "MinimalClient sends no request".to_owned(),
+            ))
+        }
+    }
+
+    #[test]
+    fn prepare_sub_request_defaults_to_an_error() {
+        assert!(MinimalClient
+            .prepare_sub_request(
+                "https://myaccount.blob.core.windows.net/container/blob",
+                &Method::DELETE,
+                &|request| request,
+                ServiceType::Blob,
+            )
+            .is_err());
+
+        let client = with_access_key("myaccount", "dXNlbGVzcw==");
+        let request = client
+            .prepare_sub_request(
+                "https://myaccount.blob.core.windows.net/container/blob",
+                &Method::DELETE,
+                &|request| request,
+                ServiceType::Blob,
+            )
+            .unwrap();
+        assert!(request.headers()[hyper::header::AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .starts_with("SharedKey myaccount:"));
+    }
+
+    #[test]
+    fn missing_service_endpoint() {
+        assert_eq!(MinimalClient.queue_uri(), "");
+
+        let uri = format!("{}/myqueue/messages", MinimalClient.queue_uri());
+        match check_service_uri(&uri) {
+            Err(AzureError::GenericErrorWithText(text)) => {
+                assert!(text.contains("does not provide the service"))
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert!(check_service_uri("https://myaccount.queue.core.windows.net/myqueue").is_ok());
+    }
+
+    #[test]
+    fn emulator_has_no_file_nor_dfs_service() {
+        let url = Url::parse("http://127.0.0.1:10000").unwrap();
+        let client = with_emulator(&url, &url, &url);
+        assert_eq!(client.file_uri(), "");
+        assert_eq!(client.dfs_uri(), "");
+        assert_eq!(MinimalClient.file_uri(), "");
+        assert_eq!(MinimalClient.dfs_uri(), "");
+
+        for uri in &[
+            format!("{}/myshare?restype=share", client.file_uri()),
+            format!("{}/myfilesystem?resource=filesystem", client.dfs_uri()),
+        ] {
+            match client.perform_request(uri, &Method::PUT, &|request| request, None) {
+                Err(AzureError::GenericErrorWithText(text)) => {
+                    assert!(text.contains("does not provide the service"))
+                }
+                Err(err) => panic!("unexpected error {}", err),
+                Ok(_) => panic!("the request was sent"),
+            }
+        }
+    }
+}
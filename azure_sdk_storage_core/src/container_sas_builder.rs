@@ -1,6 +1,6 @@
 use crate::rest_client::{generate_storage_sas, SASType};
 use crate::ClientEndpoint;
-use crate::IPRange;
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::{No, ToAssign, Yes};
 use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
@@ -52,6 +52,7 @@ where
     p_validity_end: PhantomData<ValidityEndSet>,
     at_least_one_permission: PhantomData<AtLeastOnePermission>,
     key: Option<&'a str>,
+    user_delegation_key: Option<&'a UserDelegationKey>,
     identifier: Option<&'a str>,
     ip_range: Option<&'a IPRange>,
     validity_start: Option<&'a DateTime<Utc>>,
@@ -77,6 +78,7 @@ impl<'a> ContainerSASBuilder<'a, No, No, No> {
             path,
             p_key: PhantomData {},
             key: None,
+            user_delegation_key: None,
             p_validity_end: PhantomData {},
             at_least_one_permission: PhantomData {},
             validity_end: None,
@@ -115,6 +117,40 @@ where
             path: self.path,
             p_key: PhantomData {},
             key: Some(key),
+            user_delegation_key: None,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            snapshot_time: self.snapshot_time,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_add: self.allow_add,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    /// Signs the SAS with a user delegation key instead of the
+    /// account key, for callers authenticated with Azure AD.
+    #[inline]
+    pub fn with_user_delegation_key(
+        &self,
+        user_delegation_key: &'a UserDelegationKey,
+    ) -> ContainerSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        ContainerSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(&user_delegation_key.value),
+            user_delegation_key: Some(user_delegation_key),
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -165,6 +201,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: PhantomData {},
             at_least_one_permission: self.at_least_one_permission,
             validity_end: Some(validity_end),
@@ -211,6 +248,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -238,6 +276,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -265,6 +304,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -292,6 +332,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -319,6 +360,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -346,6 +388,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: PhantomData {},
             validity_end: self.validity_end,
@@ -385,6 +428,11 @@ where
         self.identifier
     }
 
+    #[inline]
+    pub fn user_delegation_key(&self) -> Option<&'a UserDelegationKey> {
+        self.user_delegation_key
+    }
+
     #[inline]
     pub fn ip_range(&self) -> Option<&'a IPRange> {
         self.ip_range
@@ -456,6 +504,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -483,6 +532,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -510,6 +560,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -537,6 +588,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -564,6 +616,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -591,6 +644,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -618,6 +672,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -645,6 +700,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -672,6 +728,7 @@ where
             path: self.path,
             p_key: self.p_key,
             key: self.key,
+            user_delegation_key: self.user_delegation_key,
             p_validity_end: self.p_validity_end,
             at_least_one_permission: self.at_least_one_permission,
             validity_end: self.validity_end,
@@ -740,6 +797,7 @@ impl<'a> ContainerSASBuilder<'a, Yes, Yes, Yes> {
             "",
             "",
             "",
+            self.user_delegation_key(),
         );
 
         if self.path().query().is_some() {
@@ -0,0 +1,656 @@
+use crate::rest_client::{generate_storage_sas, SASType};
+use crate::ClientEndpoint;
+use crate::IPRange;
+use azure_sdk_core::{No, ToAssign, Yes};
+use chrono::{DateTime, Utc};
+use std::marker::PhantomData;
+use url::Url;
+
+impl<'a, ValidityEndSet, AtLeastOnePermission> ClientEndpoint
+    for FileSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission>
+where
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    fn account(&self) -> &str {
+        match self.path.host().unwrap().clone() {
+            url::Host::Domain(dm) => {
+                let first_dot = dm.find('.').unwrap();
+                &dm[0..first_dot]
+            }
+            url::Host::Ipv4(_) => {
+                panic!("IP addresses are not supported in SAS tokens right now");
+            }
+            _ => panic!("only Domains are supported in canonicalized_resource"),
+        }
+    }
+
+    fn key(&self) -> &str {
+        self.key()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    path: &'a Url,
+    p_key: PhantomData<KeySet>,
+    p_validity_end: PhantomData<ValidityEndSet>,
+    at_least_one_permission: PhantomData<AtLeastOnePermission>,
+    key: Option<&'a str>,
+    identifier: Option<&'a str>,
+    ip_range: Option<&'a IPRange>,
+    validity_start: Option<&'a DateTime<Utc>>,
+    validity_end: Option<&'a DateTime<Utc>>,
+    cache_control: Option<&'a str>,
+    content_disposition: Option<&'a str>,
+    content_encoding: Option<&'a str>,
+    content_language: Option<&'a str>,
+    content_type: Option<&'a str>,
+    allow_read: bool,
+    allow_create: bool,
+    allow_write: bool,
+    allow_delete: bool,
+    allow_list: bool,
+}
+
+impl<'a> FileSASBuilder<'a, No, No, No> {
+    #[inline]
+    pub fn new(path: &'a Url) -> FileSASBuilder<'a, No, No, No> {
+        FileSASBuilder {
+            path,
+            p_key: PhantomData {},
+            key: None,
+            p_validity_end: PhantomData {},
+            at_least_one_permission: PhantomData {},
+            validity_end: None,
+            identifier: None,
+            ip_range: None,
+            validity_start: None,
+            cache_control: None,
+            content_disposition: None,
+            content_encoding: None,
+            content_language: None,
+            content_type: None,
+            allow_read: false,
+            allow_create: false,
+            allow_write: false,
+            allow_delete: false,
+            allow_list: false,
+        }
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+    FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn with_key(
+        &self,
+        key: &'a str,
+    ) -> FileSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: PhantomData {},
+            key: Some(key),
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+}
+
+impl<'a, ValidityEndSet, AtLeastOnePermission>
+    FileSASBuilder<'a, Yes, ValidityEndSet, AtLeastOnePermission>
+where
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.key.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+    FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn with_validity_end(
+        &self,
+        validity_end: &'a DateTime<Utc>,
+    ) -> FileSASBuilder<'a, KeySet, Yes, AtLeastOnePermission> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: PhantomData {},
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: Some(validity_end),
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+}
+
+impl<'a, KeySet, AtLeastOnePermission> FileSASBuilder<'a, KeySet, Yes, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn validity_end(&self) -> &'a DateTime<Utc> {
+        self.validity_end.unwrap()
+    }
+}
+
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+    FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    #[inline]
+    pub fn allow_read(&self) -> FileSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: true,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn allow_create(&self) -> FileSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: true,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn allow_write(&self) -> FileSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: true,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn allow_delete(&self) -> FileSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: true,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn allow_list(&self) -> FileSASBuilder<'a, KeySet, ValidityEndSet, Yes> {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: PhantomData {},
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: true,
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+    FileSASBuilder<'a, KeySet, ValidityEndSet, AtLeastOnePermission>
+where
+    KeySet: ToAssign,
+    ValidityEndSet: ToAssign,
+    AtLeastOnePermission: ToAssign,
+{
+    fn path(&self) -> &'a Url {
+        self.path
+    }
+
+    /// A path with a single segment points to a share, anything
+    /// deeper points to a file.
+    #[inline]
+    pub fn is_share(&self) -> bool {
+        self.path
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).count() == 1)
+            .unwrap_or(false)
+    }
+
+    #[inline]
+    pub fn identifier(&self) -> Option<&'a str> {
+        self.identifier
+    }
+
+    #[inline]
+    pub fn ip_range(&self) -> Option<&'a IPRange> {
+        self.ip_range
+    }
+
+    #[inline]
+    pub fn validity_start(&self) -> Option<&'a DateTime<Utc>> {
+        self.validity_start
+    }
+
+    #[inline]
+    pub fn cache_control(&self) -> Option<&'a str> {
+        self.cache_control
+    }
+
+    #[inline]
+    pub fn content_disposition(&self) -> Option<&'a str> {
+        self.content_disposition
+    }
+
+    #[inline]
+    pub fn content_encoding(&self) -> Option<&'a str> {
+        self.content_encoding
+    }
+
+    #[inline]
+    pub fn content_language(&self) -> Option<&'a str> {
+        self.content_language
+    }
+
+    #[inline]
+    pub fn content_type(&self) -> Option<&'a str> {
+        self.content_type
+    }
+
+    #[inline]
+    pub fn can_read(&self) -> bool {
+        self.allow_read
+    }
+    #[inline]
+    pub fn can_create(&self) -> bool {
+        self.allow_create
+    }
+    #[inline]
+    pub fn can_write(&self) -> bool {
+        self.allow_write
+    }
+    #[inline]
+    pub fn can_delete(&self) -> bool {
+        self.allow_delete
+    }
+    #[inline]
+    pub fn can_list(&self) -> bool {
+        self.allow_list
+    }
+
+    #[inline]
+    pub fn with_identifier(self, identifier: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: Some(identifier),
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_ip_range(self, ip_range: &'a IPRange) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: Some(ip_range),
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_validity_start(self, validity_start: &'a DateTime<Utc>) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: Some(validity_start),
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_cache_control(self, cache_control: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: Some(cache_control),
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_content_disposition(self, content_disposition: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: Some(content_disposition),
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_content_encoding(self, content_encoding: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: Some(content_encoding),
+            content_language: self.content_language,
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_content_language(self, content_language: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: Some(content_language),
+            content_type: self.content_type,
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+
+    #[inline]
+    pub fn with_content_type(self, content_type: &'a str) -> Self {
+        FileSASBuilder {
+            path: self.path,
+            p_key: self.p_key,
+            key: self.key,
+            p_validity_end: self.p_validity_end,
+            at_least_one_permission: self.at_least_one_permission,
+            validity_end: self.validity_end,
+            identifier: self.identifier,
+            ip_range: self.ip_range,
+            validity_start: self.validity_start,
+            cache_control: self.cache_control,
+            content_disposition: self.content_disposition,
+            content_encoding: self.content_encoding,
+            content_language: self.content_language,
+            content_type: Some(content_type),
+            allow_read: self.allow_read,
+            allow_create: self.allow_create,
+            allow_write: self.allow_write,
+            allow_delete: self.allow_delete,
+            allow_list: self.allow_list,
+        }
+    }
+}
+
+// methods callable only when fully constructed
+impl<'a> FileSASBuilder<'a, Yes, Yes, Yes> {
+    pub fn finalize(self) -> Url {
+        let sas = generate_storage_sas(
+            &self,
+            self.validity_start(),
+            self.validity_end(),
+            self.path(),
+            &self.permission_string(),
+            self.identifier().unwrap_or(""),
+            self.ip_range(),
+            if self.is_share() {
+                SASType::FileShare
+            } else {
+                SASType::File
+            },
+            None,
+            self.cache_control().unwrap_or(""),
+            self.content_disposition().unwrap_or(""),
+            self.content_encoding().unwrap_or(""),
+            self.content_language().unwrap_or(""),
+            self.content_type().unwrap_or(""),
+            "",
+            "",
+            "",
+            "",
+            None,
+        );
+
+        if self.path().query().is_some() {
+            Url::parse(&format!("{}&{}", self.path(), &sas)).unwrap()
+        } else {
+            Url::parse(&format!("{}?{}", self.path(), &sas)).unwrap()
+        }
+    }
+
+    pub fn permission_string(&self) -> String {
+        let mut s = String::with_capacity(5);
+        if self.can_read() {
+            s.push('r')
+        }
+        if self.can_create() {
+            s.push('c')
+        }
+        if self.can_write() {
+            s.push('w')
+        }
+        if self.can_delete() {
+            s.push('d')
+        }
+        if self.can_list() {
+            s.push('l')
+        }
+
+        s
+    }
+}
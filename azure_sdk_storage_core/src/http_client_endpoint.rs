@@ -0,0 +1,6 @@
+use crate::ClientEndpoint;
+use azure_sdk_core::http_client::HttpClient;
+
+pub trait HttpClientEndpoint: ClientEndpoint {
+    fn http_client(&self) -> &dyn HttpClient;
+}
@@ -1,9 +1,12 @@
-use crate::rest_client::{perform_request, ServiceType};
-use crate::{Client, ClientEndpoint, HyperClientEndpoint};
+use crate::client::check_service_uri;
+use crate::rest_client::{perform_request, prepare_sub_request, ServiceType};
+use crate::{Client, ClientEndpoint, HttpClientEndpoint};
 use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::http_client::{HttpClient, ResponseFuture};
+use azure_sdk_core::retry_policy::{RetryHttpClient, RetryPolicy};
 use http::request::Builder;
 use hyper::{self, Method};
-use hyper_rustls::HttpsConnector;
+use std::sync::Arc;
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -11,9 +14,12 @@ pub struct KeyClient {
     account: String,
     key: String,
     sas_token: Option<Vec<(String, String)>>,
-    hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+    http_client: RetryHttpClient,
     blob_uri: String,
     table_uri: String,
+    queue_uri: String,
+    file_uri: String,
+    dfs_uri: String,
 }
 
 pub(crate) fn get_sas_token_parms(sas_token: &str) -> Vec<(String, String)> {
@@ -30,21 +36,45 @@ pub(crate) fn get_sas_token_parms(sas_token: &str) -> Vec<(String, String)> {
 
 impl KeyClient {
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         account: String,
         key: String,
         sas_token: Option<Vec<(String, String)>>,
-        hc: hyper::Client<HttpsConnector<hyper::client::HttpConnector>>,
+        http_client: Arc<dyn HttpClient>,
         blob_uri: String,
         table_uri: String,
+        queue_uri: String,
+        file_uri: String,
+        dfs_uri: String,
     ) -> Self {
         Self {
             account,
             key,
             sas_token,
-            hc,
+            http_client: RetryHttpClient::new(http_client, RetryPolicy::no_retry()),
             blob_uri,
             table_uri,
+            queue_uri,
+            file_uri,
+            dfs_uri,
+        }
+    }
+
+    /// Returns a copy of this client that sends its requests through `http_client`.
+    pub fn with_http_client(&self, http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            http_client: self.http_client.with_http_client(http_client),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of this client that retries its requests according to `retry_policy`.
+    /// By default requests are not retried.
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            http_client: self.http_client.with_retry_policy(retry_policy),
+            ..self.clone()
         }
     }
 
@@ -67,13 +97,29 @@ impl Client for KeyClient {
         &self.table_uri
     }
 
+    #[inline]
+    fn queue_uri(&self) -> &str {
+        &self.queue_uri
+    }
+
+    #[inline]
+    fn file_uri(&self) -> &str {
+        &self.file_uri
+    }
+
+    #[inline]
+    fn dfs_uri(&self) -> &str {
+        &self.dfs_uri
+    }
+
     fn perform_request(
         &self,
         uri: &str,
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_body: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
+        check_service_uri(uri)?;
         let uri = self.add_sas_token_to_uri(uri);
 
         perform_request(
@@ -92,7 +138,7 @@ impl Client for KeyClient {
         method: &Method,
         http_header_adder: &dyn Fn(Builder) -> Builder,
         request_str: Option<&[u8]>,
-    ) -> Result<hyper::client::ResponseFuture, AzureError> {
+    ) -> Result<ResponseFuture, AzureError> {
         debug!("segment: {}, method: {:?}", segment, method,);
 
         let uri =
@@ -107,6 +153,19 @@ impl Client for KeyClient {
             ServiceType::Table,
         )
     }
+
+    fn prepare_sub_request(
+        &self,
+        uri: &str,
+        method: &Method,
+        http_header_adder: &dyn Fn(Builder) -> Builder,
+        service_type: ServiceType,
+    ) -> Result<hyper::Request<()>, AzureError> {
+        check_service_uri(uri)?;
+        let uri = self.add_sas_token_to_uri(uri);
+
+        prepare_sub_request(self, &uri, method, http_header_adder, service_type)
+    }
 }
 
 impl ClientEndpoint for KeyClient {
@@ -119,8 +178,67 @@ impl ClientEndpoint for KeyClient {
     }
 }
 
-impl HyperClientEndpoint for KeyClient {
-    fn hyper_client(&self) -> &hyper::Client<HttpsConnector<hyper::client::HttpConnector>> {
-        &self.hc
+impl HttpClientEndpoint for KeyClient {
+    fn http_client(&self) -> &dyn HttpClient {
+        &self.http_client
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use azure_sdk_core::errors::check_status_extract_body;
+    use futures::executor::block_on;
+    use hyper::{header, Body, Request, Response, StatusCode};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct MockHttpClient {
+        requests: Mutex<Vec<Request<Body>>>,
+    }
+
+    impl HttpClient for MockHttpClient {
+        fn request(&self, request: Request<Body>) -> ResponseFuture {
+            self.requests.lock().unwrap().push(request);
+            Box::pin(async {
+                Ok(Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Body::from("mocked"))
+                    .unwrap())
+            })
+        }
+    }
+
+    #[test]
+    fn perform_request_uses_http_client() {
+        let mock = Arc::new(MockHttpClient::default());
+        let client = crate::client::with_access_key("mindrust", "dXNlbGVzcw==")
+            .with_http_client(mock.clone());
+
+        let body = block_on(check_status_extract_body(
+            client
+                .perform_request(
+                    "https://mindrust.blob.core.windows.net/container?restype=container",
+                    &Method::GET,
+                    &|request| request,
+                    None,
+                )
+                .unwrap(),
+            StatusCode::OK,
+        ))
+        .unwrap();
+        assert_eq!(body, "mocked");
+
+        let requests = mock.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].uri(),
+            "https://mindrust.blob.core.windows.net/container?restype=container"
+        );
+        assert!(requests[0].headers()[header::AUTHORIZATION]
+            .to_str()
+            .unwrap()
+            .starts_with("SharedKey mindrust:"));
+        assert!(requests[0].headers().contains_key("x-ms-version"));
     }
 }
@@ -11,12 +11,16 @@ mod client_endpoint;
 mod connection_string;
 mod connection_string_builder;
 mod container_sas_builder;
-mod hyper_client_endpoint;
+mod file_sas_builder;
+mod http_client_endpoint;
 mod into_azure_path;
 pub mod key_client;
 pub mod prelude;
 mod rest_client;
+mod sas_token;
 pub mod shared_access_signature;
+mod table_sas_builder;
+mod user_delegation_key;
 pub use self::connection_string::{ConnectionString, EndpointProtocol};
 pub use self::connection_string_builder::ConnectionStringBuilder;
 pub use self::into_azure_path::IntoAzurePath;
@@ -24,6 +28,8 @@ pub use self::rest_client::{
     get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata, perform_request,
     ServiceType,
 };
+pub use self::sas_token::{SasToken, SasTokenError};
+pub use self::user_delegation_key::UserDelegationKey;
 use crate::key_client::KeyClient;
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::headers::COPY_ID;
@@ -31,7 +37,7 @@ use azure_sdk_core::util::HeaderMapExt;
 pub use client::Client;
 pub use client_endpoint::ClientEndpoint;
 use http::HeaderMap;
-pub use hyper_client_endpoint::HyperClientEndpoint;
+pub use http_client_endpoint::HttpClientEndpoint;
 
 pub trait ClientRequired<'a, C>
 where
@@ -1,13 +1,16 @@
 pub use crate::blob_sas_builder::BlobSASBuilder;
 pub use crate::client::HttpHeaderAdder;
 pub use crate::container_sas_builder::ContainerSASBuilder;
-pub use crate::{client, CopyId, IPRange};
+pub use crate::file_sas_builder::FileSASBuilder;
+pub use crate::table_sas_builder::TableSASBuilder;
+pub use crate::{client, CopyId, IPRange, SasToken, UserDelegationKey};
 pub use crate::{Client, ClientRequired, KeyClientRequired};
 
 pub use crate::SharedAccessSignatureSupport;
 
 pub use crate::shared_access_signature::{
-    ClientSharedAccessSignature, SasExpirySupport, SasIpSupport, SasPermissions,
-    SasPermissionsSupport, SasProtocol, SasProtocolSupport, SasResource, SasResourceSupport,
-    SasResourceType, SasResourceTypeSupport, SasService, SasStartSupport, SasVersion,
+    ClientSharedAccessSignature, SasExpirySupport, SasIpSupport, SasPermissionListSupport,
+    SasPermissions, SasPermissionsSupport, SasProtocol, SasProtocolSupport, SasResource,
+    SasResourceSupport, SasResourceType, SasResourceTypeSupport, SasResourceTypesSupport,
+    SasService, SasServicesSupport, SasStartSupport, SasVersion,
 };
@@ -1,7 +1,8 @@
-use crate::IPRange;
-use crate::{ClientEndpoint, HyperClientEndpoint};
+use crate::{ClientEndpoint, HttpClientEndpoint};
+use crate::{IPRange, UserDelegationKey};
 use azure_sdk_core::errors::AzureError;
 use azure_sdk_core::headers;
+use azure_sdk_core::http_client::ResponseFuture;
 use azure_sdk_core::util::{format_header_value, HeaderMapExt, RequestBuilderExt};
 use chrono::{DateTime, Utc};
 use http::request::Builder;
@@ -13,12 +14,13 @@ use url::form_urlencoded;
 #[derive(Debug, Clone, Copy)]
 pub enum ServiceType {
     Blob,
-    // Queue,
-    // File,
+    Queue,
+    File,
     Table,
+    Dfs,
 }
 
-pub(crate) const AZURE_VERSION: &str = "2019-07-07";
+pub(crate) const AZURE_VERSION: &str = "2019-12-12";
 pub(crate) const SAS_VERSION: &str = "2019-02-02";
 
 pub(crate) const HEADER_VERSION: &str = "x-ms-version"; //=> [String] }
@@ -91,6 +93,7 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
     ending_pk: &str,
     starting_rk: &str,
     ending_rk: &str,
+    user_delegation_key: Option<&UserDelegationKey>,
 ) -> String {
     let canonicalized_resource = canonicalized_resource(client_endpoint, path);
     debug!("canonicalized_resource == {}", canonicalized_resource);
@@ -119,8 +122,8 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
     let resource_char = match resource {
         SASType::Blob => "b",
         SASType::Container => "c",
-        SASType::FileShare => "s", // this is guessed... can and most likely will be wrong
-        SASType::File => "f",      // this is guessed... can and most likely will be wrong
+        SASType::FileShare => "s",
+        SASType::File => "f",
         SASType::Queue => "q",
         SASType::Table => "t",
     };
@@ -153,11 +156,35 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
         "".to_owned()
     };
 
-    let string_to_sign = match resource {
-        // File and FileShare are guessed. The documentation is
-        // incosistent
-        // (https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#version-2018-11-09-and-later)
-        SASType::Blob | SASType::Container | SASType::File | SASType::FileShare => format!(
+    let string_to_sign = match (resource, user_delegation_key) {
+        // a user delegation SAS signs the key fields in place of the
+        // stored access policy identifier
+        (SASType::Blob, Some(key)) | (SASType::Container, Some(key)) => format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            permission,
+            start_string,
+            end_string,
+            type_canonicalized_resource,
+            key.signed_oid,
+            key.signed_tid,
+            key.signed_start
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            key.signed_expiry
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            key.signed_service,
+            key.signed_version,
+            ip_range_string,
+            protocol,
+            SAS_VERSION,
+            resource_char,
+            snapshot_time_string,
+            cache_control,
+            content_disposition,
+            content_encoding,
+            content_language,
+            content_type
+        ),
+        (SASType::Blob, None) | (SASType::Container, None) => format!(
             "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
             permission,
             start_string,
@@ -175,8 +202,10 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
             content_language,
             content_type
         ),
-        SASType::Table => format!(
-            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+        // the file service does not sign the resource type
+        // nor the snapshot time
+        (SASType::File, _) | (SASType::FileShare, _) => format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
             permission,
             start_string,
             end_string,
@@ -185,12 +214,29 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
             ip_range_string,
             protocol,
             SAS_VERSION,
+            cache_control,
+            content_disposition,
+            content_encoding,
+            content_language,
+            content_type
+        ),
+        // the table name is signed in lowercase
+        (SASType::Table, _) => format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            permission,
+            start_string,
+            end_string,
+            type_canonicalized_resource.to_lowercase(),
+            identifier,
+            ip_range_string,
+            protocol,
+            SAS_VERSION,
             starting_pk,
             starting_rk,
             ending_pk,
             ending_rk,
         ),
-        SASType::Queue => format!(
+        (SASType::Queue, _) => format!(
             "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
             permission,
             start_string,
@@ -213,8 +259,40 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
 
     let result = base64::encode(sig.as_ref());
 
+    // a table SAS names the table instead of the signed resource
+    let resource_parameter = match resource {
+        SASType::Table => format!(
+            "tn={}",
+            path.path_segments()
+                .and_then(|mut segments| segments.next_back())
+                .unwrap_or("")
+        ),
+        _ => format!("sr={}", resource_char),
+    };
+
     let token = format!(
-        "{}{}{}{}{}{}{}{}{}{}{}{}se={}&sp={}&sr={}&spr={}&sv={}&sig={}",
+        "{}{}{}{}{}{}{}{}{}{}{}{}{}se={}&sp={}&{}&spr={}&sv={}&sig={}",
+        if let Some(key) = user_delegation_key {
+            form_urlencoded::Serializer::new(String::new())
+                .append_pair("skoid", &key.signed_oid)
+                .append_pair("sktid", &key.signed_tid)
+                .append_pair(
+                    "skt",
+                    &key.signed_start
+                        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                )
+                .append_pair(
+                    "ske",
+                    &key.signed_expiry
+                        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                )
+                .append_pair("sks", &key.signed_service)
+                .append_pair("skv", &key.signed_version)
+                .finish()
+                + "&"
+        } else {
+            "".to_owned()
+        },
         if start.is_some() {
             format!(
                 "st={}&",
@@ -316,7 +394,7 @@ pub(crate) fn generate_storage_sas<CE: ClientEndpoint>(
         },
         form_urlencoded::byte_serialize(end_string.as_bytes()).collect::<String>(),
         permission,
-        resource_char,
+        resource_parameter,
         protocol,
         SAS_VERSION,
         form_urlencoded::byte_serialize(result.as_bytes()).collect::<String>()
@@ -419,7 +497,11 @@ fn canonicalize_header(h: &HeaderMap) -> String {
 
 // For table
 fn canonicalized_resource_table<CE: ClientEndpoint>(client_endpoint: &CE, u: &url::Url) -> String {
-    format!("/{}{}", client_endpoint.account(), u.path())
+    // the comp parameter is the only one that must be signed
+    match u.query_pairs().find(|(key, _)| key == "comp") {
+        Some((_, comp)) => format!("/{}{}?comp={}", client_endpoint.account(), u.path(), comp),
+        None => format!("/{}{}", client_endpoint.account(), u.path()),
+    }
 }
 
 fn canonicalized_resource<CE: ClientEndpoint>(client_endpoint: &CE, u: &url::Url) -> String {
@@ -493,14 +575,14 @@ fn lexy_sort(vec: &url::form_urlencoded::Parse, query_param: &str) -> Vec<String
 }
 
 #[allow(unknown_lints)]
-pub fn perform_request<HCE: HyperClientEndpoint>(
-    hyper_client_endpoint: &HCE,
+pub fn perform_request<HCE: HttpClientEndpoint>(
+    http_client_endpoint: &HCE,
     uri: &str,
     http_method: &Method,
     http_header_adder: &dyn Fn(Builder) -> Builder,
     request_body: Option<&[u8]>,
     service_type: ServiceType,
-) -> Result<hyper::client::ResponseFuture, AzureError> {
+) -> Result<ResponseFuture, AzureError> {
     let dt = chrono::Utc::now();
     let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
 
@@ -539,7 +621,49 @@ pub fn perform_request<HCE: HyperClientEndpoint>(
     // SAS token for example)
     if url.query_pairs().find(|p| p.0 == "sig").is_none() {
         let auth = generate_authorization(
-            hyper_client_endpoint,
+            http_client_endpoint,
+            request.headers(),
+            &url,
+            http_method,
+            service_type,
+        );
+        request
+            .headers_mut()
+            .insert(header::AUTHORIZATION, format_header_value(auth)?);
+    }
+
+    Ok(http_client_endpoint.http_client().request(request))
+}
+
+/// Builds a sub-request of a batch. The request is not sent: its
+/// headers are signed as if it was, so it can be embedded in the body of
+/// the batch. Sub-requests do not carry `x-ms-version` since the service
+/// takes it from the batch request.
+pub(crate) fn prepare_sub_request<CE: ClientEndpoint>(
+    client_endpoint: &CE,
+    uri: &str,
+    http_method: &Method,
+    http_header_adder: &dyn Fn(Builder) -> Builder,
+    service_type: ServiceType,
+) -> Result<hyper::Request<()>, AzureError> {
+    let dt = chrono::Utc::now();
+    let time = format!("{}", dt.format("%a, %d %h %Y %T GMT"));
+
+    let url = url::Url::parse(uri)?;
+
+    let mut request = hyper::Request::builder();
+    request = request
+        .method(http_method.clone())
+        .uri(uri)
+        .header_static(header::CONTENT_LENGTH, "0");
+    request = http_header_adder(request);
+    request = request.header_bytes(HEADER_DATE, time);
+
+    let mut request = request.body(())?;
+
+    if url.query_pairs().find(|p| p.0 == "sig").is_none() {
+        let auth = generate_authorization(
+            client_endpoint,
             request.headers(),
             &url,
             http_method,
@@ -550,7 +674,7 @@ pub fn perform_request<HCE: HyperClientEndpoint>(
             .insert(header::AUTHORIZATION, format_header_value(auth)?);
     }
 
-    Ok(hyper_client_endpoint.hyper_client().request(request))
+    Ok(request)
 }
 
 #[inline]
@@ -672,6 +796,29 @@ Wed, 03 May 2017 14:04:56 GMT
         );
     }
 
+    #[test]
+    fn test_canonicalize_resource_table() {
+        let client_endpoint = MockClientEndpoint {
+            account: "mindrust".to_owned(),
+            key: "useless".to_owned(),
+        };
+
+        let url =
+            url::Url::parse("https://mindrust.table.core.windows.net/mytable?comp=acl&timeout=20")
+                .unwrap();
+        assert_eq!(
+            super::canonicalized_resource_table(&client_endpoint, &url),
+            "/mindrust/mytable?comp=acl"
+        );
+
+        let url =
+            url::Url::parse("https://mindrust.table.core.windows.net/mytable()?$top=5").unwrap();
+        assert_eq!(
+            super::canonicalized_resource_table(&client_endpoint, &url),
+            "/mindrust/mytable()"
+        );
+    }
+
     #[test]
     fn test_canonicalize_resource_1() {
         let url = url::Url::parse(
@@ -769,4 +916,200 @@ Wed, 03 May 2017 14:04:56 GMT
             "YuKoXELO9M9HXeeGaSXBr4Nk+CgPAEQhcwJ6tVtBRCw=".to_owned()
         );
     }
+
+    #[test]
+    fn test_file_sas() {
+        let client_endpoint = MockClientEndpoint {
+            account: "myaccount".to_owned(),
+            key: "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
+                .to_owned(),
+        };
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let url = url::Url::parse("https://myaccount.file.core.windows.net/myshare/dir/file.txt")
+            .unwrap();
+        assert_eq!(
+            generate_storage_sas(
+                &client_endpoint,
+                None,
+                &end,
+                &url,
+                "r",
+                "",
+                None,
+                SASType::File,
+                None,
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                None,
+            ),
+            "se=2020-01-01T00%3A00%3A00Z&sp=r&sr=f&spr=https&sv=2019-02-02&\
+             sig=MOZqjGWvR1VmMXtq%2Bgls6u1uqRHb8jsnUxQ7J3Xqoxw%3D"
+        );
+
+        let url = url::Url::parse("https://myaccount.file.core.windows.net/myshare").unwrap();
+        assert_eq!(
+            generate_storage_sas(
+                &client_endpoint,
+                None,
+                &end,
+                &url,
+                "rl",
+                "",
+                None,
+                SASType::FileShare,
+                None,
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                None,
+            ),
+            "se=2020-01-01T00%3A00%3A00Z&sp=rl&sr=s&spr=https&sv=2019-02-02&\
+             sig=%2FLPw9J8wYubD%2F97qmeo1DfMeil0WEJGXQXzZvMQBh%2F4%3D"
+        );
+    }
+
+    #[test]
+    fn test_table_sas() {
+        let client_endpoint = MockClientEndpoint {
+            account: "myaccount".to_owned(),
+            key: "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
+                .to_owned(),
+        };
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let url = url::Url::parse("https://myaccount.table.core.windows.net/MyTable").unwrap();
+        assert_eq!(
+            generate_storage_sas(
+                &client_endpoint,
+                None,
+                &end,
+                &url,
+                "raud",
+                "",
+                None,
+                SASType::Table,
+                None,
+                "",
+                "",
+                "",
+                "",
+                "",
+                "pk1",
+                "pk9",
+                "",
+                "",
+                None,
+            ),
+            "spk=pk1&epk=pk9&se=2020-01-01T00%3A00%3A00Z&sp=raud&tn=MyTable&spr=https&sv=2019-02-02&\
+             sig=kqWKdwM4%2Fi%2FotHBi%2F9VG%2B1A9Rrae6ar8reQuujNygPA%3D"
+        );
+    }
+
+    #[test]
+    fn test_user_delegation_sas() {
+        let user_delegation_key = UserDelegationKey {
+            signed_oid: "a1b2c3d4-0000-0000-0000-000000000001".to_owned(),
+            signed_tid: "72f988bf-0000-0000-0000-000000000002".to_owned(),
+            signed_start: chrono::DateTime::parse_from_rfc3339("2019-12-31T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            signed_expiry: chrono::DateTime::parse_from_rfc3339("2020-01-02T00:00:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            signed_service: "b".to_owned(),
+            signed_version: "2019-02-02".to_owned(),
+            value: "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw=="
+                .to_owned(),
+        };
+        let client_endpoint = MockClientEndpoint {
+            account: "myaccount".to_owned(),
+            key: user_delegation_key.value.clone(),
+        };
+        let end = chrono::DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let url = url::Url::parse("https://myaccount.blob.core.windows.net/mycontainer/blob.txt")
+            .unwrap();
+        assert_eq!(
+            generate_storage_sas(
+                &client_endpoint,
+                None,
+                &end,
+                &url,
+                "r",
+                "",
+                None,
+                SASType::Blob,
+                None,
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                "",
+                Some(&user_delegation_key),
+            ),
+            "skoid=a1b2c3d4-0000-0000-0000-000000000001&\
+             sktid=72f988bf-0000-0000-0000-000000000002&\
+             skt=2019-12-31T00%3A00%3A00Z&ske=2020-01-02T00%3A00%3A00Z&sks=b&skv=2019-02-02&\
+             se=2020-01-01T00%3A00%3A00Z&sp=r&sr=b&spr=https&sv=2019-02-02&\
+             sig=IMfBW5xErbxb6vxIDlw%2FijYJQtgqHBjpr5cBd8VWG18%3D"
+        );
+    }
+
+    #[test]
+    fn test_prepare_sub_request() {
+        let client_endpoint = MockClientEndpoint {
+            account: "myaccount".to_owned(),
+            key: base64::encode("key"),
+        };
+
+        let request = prepare_sub_request(
+            &client_endpoint,
+            "https://myaccount.blob.core.windows.net/container/blob?comp=tier",
+            &Method::PUT,
+            &|request| request.header("x-ms-access-tier", "Cool"),
+            ServiceType::Blob,
+        )
+        .unwrap();
+
+        let headers = request.headers();
+        assert_eq!(headers.get_as_str(header::CONTENT_LENGTH), Some("0"));
+        assert!(headers.get(HEADER_DATE).is_some());
+        assert!(headers.get(HEADER_VERSION).is_none());
+
+        let url = url::Url::parse(&request.uri().to_string()).unwrap();
+        assert_eq!(
+            headers.get_as_str(header::AUTHORIZATION).unwrap(),
+            generate_authorization(
+                &client_endpoint,
+                headers,
+                &url,
+                &Method::PUT,
+                ServiceType::Blob
+            )
+        );
+    }
 }
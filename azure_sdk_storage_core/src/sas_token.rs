@@ -0,0 +1,355 @@
+use crate::key_client::get_sas_token_parms;
+use crate::shared_access_signature::{SasPermissions, SasProtocol, SasResourceType, SasService};
+use crate::IPRange;
+use azure_sdk_core::errors::AzureError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::str::FromStr;
+use url::Url;
+
+quick_error! {
+    #[derive(Debug)]
+    pub enum SasTokenError {
+        MissingParameter { name: &'static str } {
+            display("Missing SAS parameter '{}'", name)
+        }
+        InvalidValue { name: String, value: String } {
+            display("Invalid value '{}' for SAS parameter '{}'", value, name)
+        }
+        NotYetValid(start: DateTime<Utc>) {
+            display("SAS is not valid before {}", start)
+        }
+        Expired(expiry: DateTime<Utc>) {
+            display("SAS expired at {}", expiry)
+        }
+        MissingPermission(permission: SasPermissions) {
+            display("SAS does not grant the '{}' permission", permission)
+        }
+    }
+}
+
+impl From<SasTokenError> for AzureError {
+    fn from(err: SasTokenError) -> Self {
+        AzureError::GenericErrorWithText(err.to_string())
+    }
+}
+
+/// A SAS token parsed from its query string, for example one received
+/// from a third party. It can be inspected and validated but, without
+/// the signing key, not verified.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SasToken {
+    /// `sv`
+    pub version: String,
+    /// `ss`, account SAS only.
+    pub services: Vec<SasService>,
+    /// `srt`, account SAS only.
+    pub resource_types: Vec<SasResourceType>,
+    /// `sr`, service SAS only: `b`, `c`, `bs`, `s`, `f`...
+    pub resource: Option<String>,
+    /// `tn`, table SAS only.
+    pub table_name: Option<String>,
+    pub permissions: Vec<SasPermissions>,
+    pub start: Option<DateTime<Utc>>,
+    /// Missing when the expiry comes from a stored access policy.
+    pub expiry: Option<DateTime<Utc>>,
+    pub ip_range: Option<IPRange>,
+    pub protocol: Option<SasProtocol>,
+    /// `si`, the stored access policy.
+    pub identifier: Option<String>,
+    pub signature: String,
+}
+
+impl SasToken {
+    /// Parses the SAS in the query string of a signed url.
+    pub fn from_url(url: &Url) -> Result<SasToken, SasTokenError> {
+        url.query().unwrap_or("").parse()
+    }
+
+    pub fn allows(&self, permission: SasPermissions) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    /// Checks that the token is usable at `now` for an operation
+    /// needing `permissions`.
+    pub fn validate(
+        &self,
+        now: DateTime<Utc>,
+        permissions: &[SasPermissions],
+    ) -> Result<(), SasTokenError> {
+        if let Some(start) = self.start {
+            if now < start {
+                return Err(SasTokenError::NotYetValid(start));
+            }
+        }
+        if let Some(expiry) = self.expiry {
+            if now >= expiry {
+                return Err(SasTokenError::Expired(expiry));
+            }
+        }
+        match permissions.iter().find(|p| !self.allows(**p)) {
+            Some(permission) => Err(SasTokenError::MissingPermission(*permission)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for SasToken {
+    type Err = SasTokenError;
+
+    /// Accepts the token with or without the leading `?`.
+    fn from_str(s: &str) -> Result<SasToken, SasTokenError> {
+        let s = if s.starts_with('?') {
+            s.to_owned()
+        } else {
+            format!("?{}", s)
+        };
+
+        let mut version = None;
+        let mut services = Vec::new();
+        let mut resource_types = Vec::new();
+        let mut resource = None;
+        let mut table_name = None;
+        let mut permissions = Vec::new();
+        let mut start = None;
+        let mut expiry = None;
+        let mut ip_range = None;
+        let mut protocol = None;
+        let mut identifier = None;
+        let mut signature = None;
+
+        for (name, value) in get_sas_token_parms(&s) {
+            match name.as_str() {
+                "sv" => version = Some(value),
+                "ss" => services = parse_letters(&name, &value, service_from_char)?,
+                "srt" => resource_types = parse_letters(&name, &value, resource_type_from_char)?,
+                "sr" => resource = Some(value),
+                "tn" => table_name = Some(value),
+                "sp" => permissions = parse_letters(&name, &value, permission_from_char)?,
+                "st" => start = Some(parse_time(&name, &value)?),
+                "se" => expiry = Some(parse_time(&name, &value)?),
+                "sip" => ip_range = Some(parse_ip_range(&name, &value)?),
+                "spr" => {
+                    protocol = Some(match value.as_str() {
+                        "https" => SasProtocol::Https,
+                        "https,http" | "http,https" => SasProtocol::HttpHttps,
+                        _ => return Err(SasTokenError::InvalidValue { name, value }),
+                    })
+                }
+                "si" => identifier = Some(value),
+                "sig" => signature = Some(value),
+                // response headers overrides, user delegation key fields...
+                _ => {}
+            }
+        }
+
+        Ok(SasToken {
+            version: version.ok_or(SasTokenError::MissingParameter { name: "sv" })?,
+            services,
+            resource_types,
+            resource,
+            table_name,
+            permissions,
+            start,
+            expiry,
+            ip_range,
+            protocol,
+            identifier,
+            signature: signature.ok_or(SasTokenError::MissingParameter { name: "sig" })?,
+        })
+    }
+}
+
+fn parse_letters<T>(
+    name: &str,
+    value: &str,
+    from_char: fn(char) -> Option<T>,
+) -> Result<Vec<T>, SasTokenError> {
+    value
+        .chars()
+        .map(|c| {
+            from_char(c).ok_or_else(|| SasTokenError::InvalidValue {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            })
+        })
+        .collect()
+}
+
+fn service_from_char(c: char) -> Option<SasService> {
+    match c {
+        'b' => Some(SasService::Blob),
+        'q' => Some(SasService::Queue),
+        't' => Some(SasService::Table),
+        'f' => Some(SasService::File),
+        _ => None,
+    }
+}
+
+fn resource_type_from_char(c: char) -> Option<SasResourceType> {
+    match c {
+        's' => Some(SasResourceType::Service),
+        'c' => Some(SasResourceType::Container),
+        'o' => Some(SasResourceType::Object),
+        _ => None,
+    }
+}
+
+fn permission_from_char(c: char) -> Option<SasPermissions> {
+    match c {
+        'r' => Some(SasPermissions::Read),
+        'w' => Some(SasPermissions::Write),
+        'd' => Some(SasPermissions::Delete),
+        'x' => Some(SasPermissions::DeleteVersion),
+        'l' => Some(SasPermissions::List),
+        'a' => Some(SasPermissions::Add),
+        'c' => Some(SasPermissions::Create),
+        'u' => Some(SasPermissions::Update),
+        'p' => Some(SasPermissions::Process),
+        't' => Some(SasPermissions::Tags),
+        'f' => Some(SasPermissions::Filter),
+        _ => None,
+    }
+}
+
+/// The service accepts `YYYY-MM-DD`, `YYYY-MM-DDThh:mmZ` and
+/// `YYYY-MM-DDThh:mm:ssZ`, all in UTC.
+fn parse_time(name: &str, value: &str) -> Result<DateTime<Utc>, SasTokenError> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%MZ") {
+        return Ok(Utc.from_utc_datetime(&dt));
+    }
+    if let Some(dt) = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+    {
+        return Ok(Utc.from_utc_datetime(&dt));
+    }
+    Err(SasTokenError::InvalidValue {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    })
+}
+
+fn parse_ip_range(name: &str, value: &str) -> Result<IPRange, SasTokenError> {
+    let invalid = || SasTokenError::InvalidValue {
+        name: name.to_owned(),
+        value: value.to_owned(),
+    };
+
+    let mut ips = value.splitn(2, '-');
+    let start = ips
+        .next()
+        .and_then(|ip| ip.parse().ok())
+        .ok_or_else(invalid)?;
+    let end = match ips.next() {
+        Some(ip) => ip.parse().map_err(|_| invalid())?,
+        None => start,
+    };
+    Ok(IPRange { start, end })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use chrono::Duration;
+
+    const KEY: &str =
+        "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+    #[test]
+    fn blob_sas_roundtrip() {
+        let path =
+            Url::parse("https://myaccount.blob.core.windows.net/container/blob.txt").unwrap();
+        let start = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let end = start + Duration::hours(1);
+        let ip_range = IPRange {
+            start: "10.0.0.1".parse().unwrap(),
+            end: "10.0.0.255".parse().unwrap(),
+        };
+
+        let url = BlobSASBuilder::new(&path)
+            .with_key(KEY)
+            .with_validity_start(&start)
+            .with_validity_end(&end)
+            .with_ip_range(&ip_range)
+            .with_content_type("text/plain")
+            .allow_read()
+            .allow_write()
+            .finalize();
+
+        let sas = SasToken::from_url(&url).unwrap();
+        assert_eq!(sas.version, "2019-02-02");
+        assert_eq!(sas.resource.as_deref(), Some("b"));
+        assert_eq!(
+            sas.permissions,
+            vec![SasPermissions::Read, SasPermissions::Write]
+        );
+        assert_eq!(sas.start, Some(start));
+        assert_eq!(sas.expiry, Some(end));
+        assert_eq!(sas.ip_range, Some(ip_range));
+        assert_eq!(sas.protocol, Some(SasProtocol::Https));
+        assert!(!sas.signature.is_empty());
+
+        assert!(sas
+            .validate(start + Duration::minutes(5), &[SasPermissions::Read])
+            .is_ok());
+        match sas.validate(start - Duration::minutes(5), &[]) {
+            Err(SasTokenError::NotYetValid(t)) => assert_eq!(t, start),
+            r => panic!("unexpected {:?}", r),
+        }
+        match sas.validate(end, &[]) {
+            Err(SasTokenError::Expired(t)) => assert_eq!(t, end),
+            r => panic!("unexpected {:?}", r),
+        }
+        match sas.validate(start, &[SasPermissions::Read, SasPermissions::Delete]) {
+            Err(SasTokenError::MissingPermission(p)) => assert_eq!(p, SasPermissions::Delete),
+            r => panic!("unexpected {:?}", r),
+        }
+    }
+
+    #[test]
+    fn parse_account_sas() {
+        let sas: SasToken =
+            "?sv=2018-11-09&ss=bf&srt=sco&sp=rwdlac&se=2020-06-01&st=2020-05-01T08:30Z\
+            &spr=https,http&sip=168.1.5.65&sig=c2lnbmF0dXJl"
+                .parse()
+                .unwrap();
+
+        assert_eq!(sas.services, vec![SasService::Blob, SasService::File]);
+        assert_eq!(
+            sas.resource_types,
+            vec![
+                SasResourceType::Service,
+                SasResourceType::Container,
+                SasResourceType::Object
+            ]
+        );
+        assert!(sas.allows(SasPermissions::Create));
+        assert!(!sas.allows(SasPermissions::Update));
+        assert_eq!(
+            sas.expiry.unwrap().to_rfc3339(),
+            "2020-06-01T00:00:00+00:00"
+        );
+        assert_eq!(sas.start.unwrap().to_rfc3339(), "2020-05-01T08:30:00+00:00");
+        assert_eq!(sas.protocol, Some(SasProtocol::HttpHttps));
+        let ip_range = sas.ip_range.unwrap();
+        assert_eq!(ip_range.start, ip_range.end);
+    }
+
+    #[test]
+    fn reject_invalid_sas() {
+        match "sv=2019-02-02&sp=rz&sig=abc".parse::<SasToken>() {
+            Err(SasTokenError::InvalidValue { name, .. }) => assert_eq!(name, "sp"),
+            r => panic!("unexpected {:?}", r),
+        }
+        match "sv=2019-02-02&sp=r".parse::<SasToken>() {
+            Err(SasTokenError::MissingParameter { name }) => assert_eq!(name, "sig"),
+            r => panic!("unexpected {:?}", r),
+        }
+    }
+}
@@ -28,7 +28,7 @@ impl fmt::Display for SasVersion {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SasService {
     Blob,
     Queue,
@@ -47,8 +47,30 @@ impl fmt::Display for SasService {
     }
 }
 
+impl From<SasService> for SasResource {
+    fn from(service: SasService) -> SasResource {
+        match service {
+            SasService::Blob => SasResource::Blob,
+            SasService::Queue => SasResource::Queue,
+            SasService::Table => SasResource::Table,
+            SasService::File => SasResource::File,
+        }
+    }
+}
+
+impl From<SasResource> for SasService {
+    fn from(resource: SasResource) -> SasService {
+        match resource {
+            SasResource::Blob => SasService::Blob,
+            SasResource::Queue => SasService::Queue,
+            SasResource::Table => SasService::Table,
+            SasResource::File => SasService::File,
+        }
+    }
+}
+
 /// Specifies the protocol permitted for a request made with the SAS ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#specifying-the-http-protocol)).
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SasProtocol {
     Https,
     HttpHttps,
@@ -83,7 +105,7 @@ impl fmt::Display for SasResource {
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SasResourceType {
     Service,
     Container,
@@ -101,16 +123,19 @@ impl fmt::Display for SasResourceType {
 }
 
 /// Indicate which operations a key_client may perform on the resource ([Azure documentation](https://docs.microsoft.com/en-us/rest/api/storageservices/create-service-sas#specifying-permissions)).
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SasPermissions {
     Read,
     Write,
     Delete,
+    DeleteVersion,
     List,
     Add,
     Create,
     Update,
     Process,
+    Tags,
+    Filter,
 }
 
 impl fmt::Display for SasPermissions {
@@ -119,25 +144,40 @@ impl fmt::Display for SasPermissions {
             SasPermissions::Read => write!(f, "r"),
             SasPermissions::Write => write!(f, "w"),
             SasPermissions::Delete => write!(f, "d"),
+            SasPermissions::DeleteVersion => write!(f, "x"),
             SasPermissions::List => write!(f, "l"),
             SasPermissions::Add => write!(f, "a"),
             SasPermissions::Create => write!(f, "c"),
             SasPermissions::Update => write!(f, "u"),
             SasPermissions::Process => write!(f, "p"),
+            SasPermissions::Tags => write!(f, "t"),
+            SasPermissions::Filter => write!(f, "f"),
         }
     }
 }
 
+/// Joins the values in the canonical order the service expects,
+/// for example the permissions as `rwdxlacuptf`.
+fn join<T>(values: &[T]) -> String
+where
+    T: Copy + Ord + fmt::Display,
+{
+    let mut values = values.to_vec();
+    values.sort();
+    values.dedup();
+    values.iter().map(ToString::to_string).collect()
+}
+
 pub struct SharedAccessSignature {
     account: String,
     key: String,
 
     signed_version: SasVersion,
-    signed_resource: SasResource,
-    signed_resource_type: SasResourceType,
+    signed_services: Vec<SasService>,
+    signed_resource_types: Vec<SasResourceType>,
     signed_start: Option<DateTime<Utc>>,
     signed_expiry: DateTime<Utc>,
-    signed_permissions: SasPermissions,
+    signed_permissions: Vec<SasPermissions>,
     signed_ip: Option<String>,
     signed_protocol: Option<SasProtocol>,
 }
@@ -159,9 +199,9 @@ impl SharedAccessSignature {
                 let string_to_sign = format!(
                     "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n",
                     self.account,
-                    self.signed_permissions,
-                    self.signed_resource,
-                    self.signed_resource_type,
+                    join(&self.signed_permissions),
+                    join(&self.signed_services),
+                    join(&self.signed_resource_types),
                     self.signed_start
                         .map_or("".to_string(), SharedAccessSignature::format_date),
                     SharedAccessSignature::format_date(self.signed_expiry),
@@ -189,8 +229,8 @@ impl SharedAccessSignature {
     pub fn token(&self) -> String {
         let mut elements: Vec<String> = vec![
             format!("sv={}", self.signed_version),
-            format!("ss={}", self.signed_resource),
-            format!("srt={}", self.signed_resource_type),
+            format!("ss={}", join(&self.signed_services)),
+            format!("srt={}", join(&self.signed_resource_types)),
             format!(
                 "se={}",
                 form_urlencoded::byte_serialize(
@@ -198,7 +238,7 @@ impl SharedAccessSignature {
                 )
                 .collect::<String>()
             ),
-            format!("sp={}", self.signed_permissions),
+            format!("sp={}", join(&self.signed_permissions)),
         ];
 
         if let Some(start) = &self.signed_start {
@@ -253,14 +293,14 @@ pub struct SharedAccessSignatureBuilder<
     key_client: &'a KeyClient,
     signed_version: SasVersion,
     p_signed_resource: PhantomData<SasResourceSet>,
-    signed_resource: Option<SasResource>,
+    signed_services: Vec<SasService>,
     p_signed_resource_type: PhantomData<SasResourceTypeSet>,
-    signed_resource_type: Option<SasResourceType>,
+    signed_resource_types: Vec<SasResourceType>,
     signed_start: Option<DateTime<Utc>>,
     p_signed_expiry: PhantomData<SasExpirySet>,
     signed_expiry: Option<DateTime<Utc>>,
     p_signed_permissions: PhantomData<SasPermissionsSet>,
-    signed_permissions: Option<SasPermissions>,
+    signed_permissions: Vec<SasPermissions>,
     signed_ip: Option<String>,
     signed_protocol: Option<SasProtocol>,
 }
@@ -271,14 +311,14 @@ impl<'a> SharedAccessSignatureBuilder<'a, No, No, No, No> {
             key_client,
             signed_version: SasVersion::V20181109,
             p_signed_resource: PhantomData {},
-            signed_resource: None,
+            signed_services: Vec::new(),
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: None,
+            signed_resource_types: Vec::new(),
             signed_start: None,
             p_signed_expiry: PhantomData {},
             signed_expiry: None,
             p_signed_permissions: PhantomData {},
-            signed_permissions: None,
+            signed_permissions: Vec::new(),
             signed_ip: None,
             signed_protocol: None,
         }
@@ -290,11 +330,11 @@ impl<'a> SharedAccessSignatureBuilder<'a, No, No, No, No> {
             key: self.key_client.key().to_string(),
 
             signed_version: self.signed_version,
-            signed_resource: self.signed_resource.unwrap(),
-            signed_resource_type: self.signed_resource_type.unwrap(),
+            signed_services: self.signed_services.clone(),
+            signed_resource_types: self.signed_resource_types.clone(),
             signed_start: self.signed_start,
             signed_expiry: self.signed_expiry.unwrap(),
-            signed_permissions: self.signed_permissions.unwrap(),
+            signed_permissions: self.signed_permissions.clone(),
             signed_ip: self.signed_ip.clone(),
             signed_protocol: self.signed_protocol,
         }
@@ -332,10 +372,36 @@ impl ClientSharedAccessSignature for KeyClient {
     }
 }
 
+pub trait SasServicesRequired {
+    fn services(&self) -> &[SasService];
+}
+
+impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet> SasServicesRequired
+    for SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >
+where
+    SasResourceSet: ToAssign,
+    SasResourceTypeSet: ToAssign,
+    SasExpirySet: ToAssign,
+    SasPermissionsSet: ToAssign,
+{
+    #[inline]
+    fn services(&self) -> &[SasService] {
+        &self.signed_services
+    }
+}
+
+#[deprecated(note = "an account SAS can cover several services, use SasServicesRequired")]
 pub trait SasResourceRequired {
     fn resource(&self) -> SasResource;
 }
 
+#[allow(deprecated)]
 impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet> SasResourceRequired
     for SharedAccessSignatureBuilder<
         'a,
@@ -350,9 +416,10 @@ where
     SasExpirySet: ToAssign,
     SasPermissionsSet: ToAssign,
 {
+    /// The first of the signed services.
     #[inline]
     fn resource(&self) -> SasResource {
-        self.signed_resource.unwrap()
+        self.signed_services[0].into()
     }
 }
 
@@ -389,9 +456,60 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: Some(resource),
+            signed_services: vec![resource.into()],
+            p_signed_resource_type: PhantomData {},
+            signed_resource_types: self.signed_resource_types,
+            signed_start: self.signed_start,
+            p_signed_expiry: PhantomData {},
+            signed_expiry: self.signed_expiry,
+            p_signed_permissions: PhantomData {},
+            signed_permissions: self.signed_permissions,
+            signed_ip: self.signed_ip,
+            signed_protocol: self.signed_protocol,
+        }
+    }
+}
+
+pub trait SasServicesSupport<'a> {
+    type O;
+    /// # Panics
+    ///
+    /// Panics if `services` is empty: the service rejects a SAS without services.
+    fn with_services(self, services: &[SasService]) -> Self::O;
+}
+
+impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet> SasServicesSupport<'a>
+    for SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >
+where
+    SasResourceSet: ToAssign,
+    SasResourceTypeSet: ToAssign,
+    SasExpirySet: ToAssign,
+    SasPermissionsSet: ToAssign,
+{
+    type O = SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >;
+
+    #[inline]
+    fn with_services(self, services: &[SasService]) -> Self::O {
+        assert!(!services.is_empty(), "a SAS must sign at least one service");
+        SharedAccessSignatureBuilder {
+            key_client: self.key_client,
+            signed_version: self.signed_version,
+            p_signed_resource: PhantomData {},
+            signed_services: services.to_vec(),
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
@@ -403,10 +521,39 @@ where
     }
 }
 
+pub trait SasResourceTypesRequired {
+    fn resource_types(&self) -> &[SasResourceType];
+}
+
+impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet>
+    SasResourceTypesRequired
+    for SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >
+where
+    SasResourceSet: ToAssign,
+    SasResourceTypeSet: ToAssign,
+    SasExpirySet: ToAssign,
+    SasPermissionsSet: ToAssign,
+{
+    #[inline]
+    fn resource_types(&self) -> &[SasResourceType] {
+        &self.signed_resource_types
+    }
+}
+
+#[deprecated(
+    note = "an account SAS can cover several resource types, use SasResourceTypesRequired"
+)]
 pub trait SasResourceTypeRequired {
     fn resource_type(&self) -> SasResourceType;
 }
 
+#[allow(deprecated)]
 impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet>
     SasResourceTypeRequired
     for SharedAccessSignatureBuilder<
@@ -422,9 +569,10 @@ where
     SasExpirySet: ToAssign,
     SasPermissionsSet: ToAssign,
 {
+    /// The first of the signed resource types.
     #[inline]
     fn resource_type(&self) -> SasResourceType {
-        self.signed_resource_type.unwrap()
+        self.signed_resource_types[0]
     }
 }
 
@@ -462,9 +610,65 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
+            p_signed_resource_type: PhantomData {},
+            signed_resource_types: vec![resource_type],
+            signed_start: self.signed_start,
+            p_signed_expiry: PhantomData {},
+            signed_expiry: self.signed_expiry,
+            p_signed_permissions: PhantomData {},
+            signed_permissions: self.signed_permissions,
+            signed_ip: self.signed_ip,
+            signed_protocol: self.signed_protocol,
+        }
+    }
+}
+
+pub trait SasResourceTypesSupport<'a> {
+    type O;
+    /// # Panics
+    ///
+    /// Panics if `resource_types` is empty: the service rejects a SAS without
+    /// resource types.
+    fn with_resource_types(self, resource_types: &[SasResourceType]) -> Self::O;
+}
+
+impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet>
+    SasResourceTypesSupport<'a>
+    for SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >
+where
+    SasResourceSet: ToAssign,
+    SasResourceTypeSet: ToAssign,
+    SasExpirySet: ToAssign,
+    SasPermissionsSet: ToAssign,
+{
+    type O = SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >;
+
+    #[inline]
+    fn with_resource_types(self, resource_types: &[SasResourceType]) -> Self::O {
+        assert!(
+            !resource_types.is_empty(),
+            "a SAS must sign at least one resource type"
+        );
+        SharedAccessSignatureBuilder {
+            key_client: self.key_client,
+            signed_version: self.signed_version,
+            p_signed_resource: PhantomData {},
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: Some(resource_type),
+            signed_resource_types: resource_types.to_vec(),
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
@@ -533,9 +737,9 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: Some(expiry),
@@ -548,7 +752,7 @@ where
 }
 
 pub trait SasPermissionsRequired {
-    fn signed_permissions(&self) -> SasPermissions;
+    fn signed_permissions(&self) -> &[SasPermissions];
 }
 
 impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet> SasPermissionsRequired
@@ -566,8 +770,8 @@ where
     SasPermissionsSet: ToAssign,
 {
     #[inline]
-    fn signed_permissions(&self) -> SasPermissions {
-        self.signed_permissions.unwrap()
+    fn signed_permissions(&self) -> &[SasPermissions] {
+        &self.signed_permissions
     }
 }
 
@@ -605,14 +809,70 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
+            p_signed_resource_type: PhantomData {},
+            signed_resource_types: self.signed_resource_types,
+            signed_start: self.signed_start,
+            p_signed_expiry: PhantomData {},
+            signed_expiry: self.signed_expiry,
+            p_signed_permissions: PhantomData {},
+            signed_permissions: vec![permissions],
+            signed_ip: self.signed_ip,
+            signed_protocol: self.signed_protocol,
+        }
+    }
+}
+
+pub trait SasPermissionListSupport<'a> {
+    type O;
+    /// # Panics
+    ///
+    /// Panics if `permissions` is empty: the service rejects a SAS without
+    /// permissions.
+    fn with_permission_list(self, permissions: &[SasPermissions]) -> Self::O;
+}
+
+impl<'a, SasResourceSet, SasResourceTypeSet, SasExpirySet, SasPermissionsSet>
+    SasPermissionListSupport<'a>
+    for SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >
+where
+    SasResourceSet: ToAssign,
+    SasResourceTypeSet: ToAssign,
+    SasExpirySet: ToAssign,
+    SasPermissionsSet: ToAssign,
+{
+    type O = SharedAccessSignatureBuilder<
+        'a,
+        SasResourceSet,
+        SasResourceTypeSet,
+        SasExpirySet,
+        SasPermissionsSet,
+    >;
+
+    #[inline]
+    fn with_permission_list(self, permissions: &[SasPermissions]) -> Self::O {
+        assert!(
+            !permissions.is_empty(),
+            "a SAS must grant at least one permission"
+        );
+        SharedAccessSignatureBuilder {
+            key_client: self.key_client,
+            signed_version: self.signed_version,
+            p_signed_resource: PhantomData {},
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
             p_signed_permissions: PhantomData {},
-            signed_permissions: Some(permissions),
+            signed_permissions: permissions.to_vec(),
             signed_ip: self.signed_ip,
             signed_protocol: self.signed_protocol,
         }
@@ -652,9 +912,9 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: Some(start),
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
@@ -699,9 +959,9 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
@@ -746,9 +1006,9 @@ where
             key_client: self.key_client,
             signed_version: self.signed_version,
             p_signed_resource: PhantomData {},
-            signed_resource: self.signed_resource,
+            signed_services: self.signed_services,
             p_signed_resource_type: PhantomData {},
-            signed_resource_type: self.signed_resource_type,
+            signed_resource_types: self.signed_resource_types,
             signed_start: self.signed_start,
             p_signed_expiry: PhantomData {},
             signed_expiry: self.signed_expiry,
@@ -759,3 +1019,92 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::client;
+
+    #[test]
+    fn account_sas_lists() {
+        let key_client = client::with_access_key(
+            "myaccount",
+            "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==",
+        );
+        let expiry = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let sas = key_client
+            .shared_access_signature()
+            .with_services(&[SasService::Table, SasService::Blob])
+            .with_resource_types(&[SasResourceType::Object, SasResourceType::Container])
+            .with_permission_list(&[
+                SasPermissions::List,
+                SasPermissions::Read,
+                SasPermissions::Write,
+                SasPermissions::Read,
+            ])
+            .with_expiry(expiry)
+            .finalize();
+        assert!(sas
+            .token()
+            .starts_with("sv=2018-11-09&ss=bt&srt=co&se=2020-01-01T00%3A00%3A00Z&sp=rwl&sig="));
+
+        let sas = key_client
+            .shared_access_signature()
+            .with_resource(SasResource::Blob)
+            .with_resource_type(SasResourceType::Object)
+            .with_permissions(SasPermissions::Read)
+            .with_expiry(expiry)
+            .finalize();
+        assert!(sas
+            .token()
+            .starts_with("sv=2018-11-09&ss=b&srt=o&se=2020-01-01T00%3A00%3A00Z&sp=r&sig="));
+    }
+
+    fn key_client() -> KeyClient {
+        client::with_access_key("myaccount", "dXNlbGVzcw==")
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one service")]
+    fn empty_service_list() {
+        key_client().shared_access_signature().with_services(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one resource type")]
+    fn empty_resource_type_list() {
+        key_client()
+            .shared_access_signature()
+            .with_resource_types(&[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one permission")]
+    fn empty_permission_list() {
+        key_client()
+            .shared_access_signature()
+            .with_permission_list(&[]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_single_value_getters() {
+        let key_client = key_client();
+        let builder = key_client
+            .shared_access_signature()
+            .with_services(&[SasService::Table, SasService::Blob])
+            .with_resource_type(SasResourceType::Container);
+
+        assert_eq!(
+            SasService::from(SasResourceRequired::resource(&builder)),
+            SasService::Table
+        );
+        assert_eq!(
+            SasResourceTypeRequired::resource_type(&builder),
+            SasResourceType::Container
+        );
+    }
+}
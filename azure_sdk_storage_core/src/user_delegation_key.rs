@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+
+/// A key obtained with Get User Delegation Key. It signs user delegation
+/// SAS tokens in place of the account key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDelegationKey {
+    pub signed_oid: String,
+    pub signed_tid: String,
+    pub signed_start: DateTime<Utc>,
+    pub signed_expiry: DateTime<Utc>,
+    pub signed_service: String,
+    pub signed_version: String,
+    pub value: String,
+}
@@ -0,0 +1,121 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_datalake::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    // The account must have the hierarchical namespace enabled.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let file_system_name = std::env::args()
+        .nth(1)
+        .expect("please specify the file system name as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    let response = client
+        .create_file_system()
+        .with_file_system_name(&file_system_name)
+        .finalize()
+        .await?;
+    println!("namespace enabled == {}", response.namespace_enabled);
+
+    client
+        .create_path()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents")
+        .with_resource_type(ResourceType::Directory)
+        .finalize()
+        .await?;
+
+    client
+        .create_path()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/hello.txt")
+        .with_resource_type(ResourceType::File)
+        .finalize()
+        .await?;
+
+    // the data is appended first and committed by the flush
+    let data = b"Hello from Rust!";
+    client
+        .append_data()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/hello.txt")
+        .with_position(0)
+        .with_body(data)
+        .finalize()
+        .await?;
+
+    client
+        .flush_data()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/hello.txt")
+        .with_position(data.len() as u64)
+        .with_close()
+        .finalize()
+        .await?;
+
+    client
+        .set_access_control()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/hello.txt")
+        .with_acl("user::rw-,group::r--,other::---")
+        .finalize()
+        .await?;
+
+    let response = client
+        .get_access_control()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/hello.txt")
+        .finalize()
+        .await?;
+    println!(
+        "owner == {}, group == {}, acl == {}",
+        response.owner, response.group, response.acl
+    );
+
+    client
+        .rename_path()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents/renamed.txt")
+        .with_rename_source("documents/hello.txt")
+        .finalize()
+        .await?;
+
+    let response = client
+        .list_paths()
+        .with_file_system_name(&file_system_name)
+        .with_recursive()
+        .finalize()
+        .await?;
+    for path in response.incomplete_vector.iter() {
+        println!(
+            "{} ({}, {} bytes)",
+            path.name,
+            if path.is_directory { "dir" } else { "file" },
+            path.content_length
+        );
+    }
+
+    client
+        .delete_path()
+        .with_file_system_name(&file_system_name)
+        .with_path("documents")
+        .with_recursive()
+        .finalize()
+        .await?;
+
+    client
+        .delete_file_system()
+        .with_file_system_name(&file_system_name)
+        .finalize()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::NAMESPACE_ENABLED;
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_storage_core::prelude::*;
+use http::HeaderMap;
+use url::form_urlencoded;
+
+/// Lists only the paths under this directory.
+pub trait DirectorySupport<'a> {
+    type O;
+    fn with_directory(self, directory: &'a str) -> Self::O;
+}
+
+pub trait DirectoryOption<'a> {
+    fn directory(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.directory().map(|directory| {
+            form_urlencoded::Serializer::new(String::new())
+                .append_pair("directory", directory)
+                .finish()
+        })
+    }
+}
+
+pub(crate) fn namespace_enabled_from_headers(headers: &HeaderMap) -> Result<bool, AzureError> {
+    let namespace_enabled = headers
+        .get_as_str(NAMESPACE_ENABLED)
+        .ok_or_else(|| AzureError::HeaderNotFound(NAMESPACE_ENABLED.to_owned()))?;
+    Ok(namespace_enabled.parse()?)
+}
+
+pub(crate) fn encode_segment(segment: &str) -> String {
+    // form encoding turns spaces into '+', which the service would
+    // take literally in a path
+    form_urlencoded::byte_serialize(segment.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+/// Encodes every segment of `path` on its own so the slashes are
+/// preserved.
+pub(crate) fn encode_path(path: &str) -> String {
+    path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(encode_segment)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Builds the uri of a file system or, if `path` is specified, of a
+/// file or directory in the file system.
+#[inline]
+pub(crate) fn generate_file_system_uri<C>(
+    c: &C,
+    file_system_name: &str,
+    path: Option<&str>,
+    params: Option<&str>,
+) -> String
+where
+    C: Client,
+{
+    let mut uri = format!("{}/{}", c.dfs_uri(), encode_segment(file_system_name));
+
+    if let Some(path) = path {
+        uri = format!("{}/{}", uri, encode_path(path));
+    }
+
+    if let Some(params) = params {
+        uri = format!("{}?{}", uri, params);
+    }
+
+    uri
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn file_system_uri_keeps_path_separators() {
+        let client = client::with_access_key("myaccount", "");
+        assert_eq!(
+            generate_file_system_uri(
+                &client,
+                "fs",
+                Some("/dir one/file+1.txt"),
+                Some("action=append&position=0")
+            ),
+            "https://myaccount.dfs.core.windows.net/fs/dir%20one/file%2B1.txt?action=append&position=0"
+        );
+        assert_eq!(
+            generate_file_system_uri(&client, "fs", None, Some("resource=filesystem")),
+            "https://myaccount.dfs.core.windows.net/fs?resource=filesystem"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use crate::file_system::generate_file_system_uri;
+use crate::file_system::responses::CreateFileSystemResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    file_system_name: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreateFileSystemBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreateFileSystemBuilder<'a, C, No> {
+        CreateFileSystemBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequired<'a, C>
+    for CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> FileSystemNameRequired<'a> for CreateFileSystemBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdOption<'a>
+    for CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutOption for CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> FileSystemNameSupport<'a> for CreateFileSystemBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = CreateFileSystemBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        CreateFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: Some(file_system_name),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdSupport<'a>
+    for CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileSystemBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutSupport for CreateFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileSystemBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreateFileSystemBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreateFileSystemResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            None,
+            Some("resource=filesystem"),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateFileSystemResponse::from_headers(&headers)
+    }
+}
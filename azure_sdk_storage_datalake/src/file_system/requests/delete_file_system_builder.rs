@@ -0,0 +1,216 @@
+use crate::file_system::generate_file_system_uri;
+use crate::file_system::responses::DeleteFileSystemResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    file_system_name: Option<&'a str>,
+    if_since_condition: Option<IfSinceCondition>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> DeleteFileSystemBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DeleteFileSystemBuilder<'a, C, No> {
+        DeleteFileSystemBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            if_since_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequired<'a, C>
+    for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> FileSystemNameRequired<'a> for DeleteFileSystemBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> IfSinceConditionOption
+    for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdOption<'a>
+    for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutOption for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> FileSystemNameSupport<'a> for DeleteFileSystemBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = DeleteFileSystemBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        DeleteFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: Some(file_system_name),
+            if_since_condition: self.if_since_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> IfSinceConditionSupport
+    for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteFileSystemBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        DeleteFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            if_since_condition: Some(if_since_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdSupport<'a>
+    for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteFileSystemBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            if_since_condition: self.if_since_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutSupport for DeleteFileSystemBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteFileSystemBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteFileSystemBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            if_since_condition: self.if_since_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DeleteFileSystemBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<DeleteFileSystemResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            None,
+            Some("resource=filesystem"),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            &|mut request| {
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        DeleteFileSystemResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,429 @@
+use crate::file_system::generate_file_system_uri;
+use crate::file_system::responses::ListPathsResponse;
+use crate::file_system::{DirectoryOption, DirectorySupport};
+use crate::path::{
+    ContinuationOption, ContinuationSupport, RecursiveOption, RecursiveSupport, UpnOption,
+    UpnSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use futures::stream::{unfold, Stream};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    file_system_name: Option<&'a str>,
+    directory: Option<&'a str>,
+    recursive: bool,
+    continuation: Option<&'a str>,
+    max_results: Option<u32>,
+    upn: bool,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> ListPathsBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> ListPathsBuilder<'a, C, No> {
+        ListPathsBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            directory: None,
+            recursive: false,
+            continuation: None,
+            max_results: None,
+            upn: false,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequired<'a, C> for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> FileSystemNameRequired<'a> for ListPathsBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> DirectoryOption<'a> for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn directory(&self) -> Option<&'a str> {
+        self.directory
+    }
+}
+
+impl<'a, C, FileSystemNameSet> RecursiveOption for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn recursive(&self) -> bool {
+        self.recursive
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ContinuationOption<'a> for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn continuation(&self) -> Option<&'a str> {
+        self.continuation
+    }
+}
+
+impl<'a, C, FileSystemNameSet> MaxResultsOption for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, C, FileSystemNameSet> UpnOption for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn upn(&self) -> bool {
+        self.upn
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdOption<'a>
+    for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutOption for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> FileSystemNameSupport<'a> for ListPathsBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: Some(file_system_name),
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> DirectorySupport<'a> for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_directory(self, directory: &'a str) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: Some(directory),
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> RecursiveSupport for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_recursive(self) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: true,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ContinuationSupport<'a>
+    for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_continuation(self, continuation: &'a str) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: Some(continuation),
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> MaxResultsSupport for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: Some(max_results),
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> UpnSupport for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_upn(self) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: true,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> ClientRequestIdSupport<'a>
+    for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> TimeoutSupport for ListPathsBuilder<'a, C, FileSystemNameSet>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListPathsBuilder<'a, C, FileSystemNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ListPathsBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            file_system_name: self.file_system_name,
+            directory: self.directory,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            max_results: self.max_results,
+            upn: self.upn,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> ListPathsBuilder<'a, C, Yes>
+where
+    C: Client + Clone,
+{
+    pub async fn finalize(self) -> Result<ListPathsResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            None,
+            Some("resource=filesystem"),
+        );
+
+        // the service requires the parameter even when not recursive
+        uri = format!("{}&recursive={}", uri, self.recursive());
+
+        if let Some(directory) = DirectoryOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, directory);
+        }
+        if let Some(continuation) = ContinuationOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, continuation);
+        }
+        if let Some(max_results) = self.max_results() {
+            uri = format!("{}&maxResults={}", uri, max_results);
+        }
+        if self.upn() {
+            uri = format!("{}&upn=true", uri);
+        }
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        ListPathsResponse::from_response(&headers, &body)
+    }
+
+    pub fn stream(self) -> impl Stream<Item = Result<ListPathsResponse, AzureError>> + 'a {
+        #[derive(Debug, Clone, PartialEq)]
+        enum States {
+            Init,
+            Continuation(String),
+        }
+
+        unfold(Some(States::Init), move |continuation: Option<States>| {
+            let req = self.clone();
+            async move {
+                debug!("continuation == {:?}", &continuation);
+                let response = match continuation {
+                    Some(States::Init) => req.finalize().await,
+                    Some(States::Continuation(continuation)) => {
+                        req.with_continuation(&continuation).finalize().await
+                    }
+                    None => return None,
+                };
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(err) => return Some((Err(err), None)),
+                };
+
+                let continuation = response
+                    .incomplete_vector
+                    .token()
+                    .map(|ct| States::Continuation(ct.to_owned()));
+
+                Some((Ok(response), continuation))
+            }
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod create_file_system_builder;
+pub use self::create_file_system_builder::CreateFileSystemBuilder;
+mod delete_file_system_builder;
+pub use self::delete_file_system_builder::DeleteFileSystemBuilder;
+mod list_paths_builder;
+pub use self::list_paths_builder::ListPathsBuilder;
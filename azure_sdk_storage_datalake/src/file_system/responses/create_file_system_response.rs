@@ -0,0 +1,38 @@
+use crate::file_system::namespace_enabled_from_headers;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateFileSystemResponse {
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    /// Whether the account has the hierarchical namespace enabled.
+    pub namespace_enabled: bool,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl CreateFileSystemResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<CreateFileSystemResponse, AzureError> {
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let namespace_enabled = namespace_enabled_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(CreateFileSystemResponse {
+            etag,
+            last_modified,
+            namespace_enabled,
+            request_id,
+            date,
+        })
+    }
+}
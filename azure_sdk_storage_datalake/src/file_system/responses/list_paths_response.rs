@@ -0,0 +1,37 @@
+use crate::path::{paths_from_response, Path};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::{
+    continuation_token_from_headers_optional, date_from_headers, request_id_from_headers, RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListPathsResponse {
+    pub incomplete_vector: IncompleteVector<Path>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl ListPathsResponse {
+    pub fn is_complete(&self) -> bool {
+        self.incomplete_vector.is_complete()
+    }
+
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Result<ListPathsResponse, AzureError> {
+        let paths = paths_from_response(body)?;
+        let continuation = continuation_token_from_headers_optional(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(ListPathsResponse {
+            incomplete_vector: IncompleteVector::new(continuation, paths),
+            request_id,
+            date,
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod create_file_system_response;
+pub use self::create_file_system_response::CreateFileSystemResponse;
+mod delete_file_system_response;
+pub use self::delete_file_system_response::DeleteFileSystemResponse;
+mod list_paths_response;
+pub use self::list_paths_response::ListPathsResponse;
@@ -0,0 +1,94 @@
+#![recursion_limit = "128"]
+#![allow(clippy::needless_lifetimes)]
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde_derive;
+#[macro_use]
+extern crate azure_sdk_core;
+pub mod file_system;
+pub mod path;
+pub mod prelude;
+use azure_sdk_core::No;
+use azure_sdk_storage_core::Client;
+
+pub trait FileSystem<C>
+where
+    C: Client,
+{
+    fn create_file_system<'a>(
+        &'a self,
+    ) -> file_system::requests::CreateFileSystemBuilder<'a, C, No>;
+    fn delete_file_system<'a>(
+        &'a self,
+    ) -> file_system::requests::DeleteFileSystemBuilder<'a, C, No>;
+    fn list_paths<'a>(&'a self) -> file_system::requests::ListPathsBuilder<'a, C, No>;
+}
+
+pub trait Path<C>
+where
+    C: Client,
+{
+    fn create_path<'a>(&'a self) -> path::requests::CreatePathBuilder<'a, C, No, No, No>;
+    fn rename_path<'a>(&'a self) -> path::requests::RenamePathBuilder<'a, C, No, No, No>;
+    fn delete_path<'a>(&'a self) -> path::requests::DeletePathBuilder<'a, C, No, No>;
+    fn append_data<'a>(&'a self) -> path::requests::AppendDataBuilder<'a, C, No, No, No, No>;
+    fn flush_data<'a>(&'a self) -> path::requests::FlushDataBuilder<'a, C, No, No, No>;
+    fn get_access_control<'a>(&'a self) -> path::requests::GetAccessControlBuilder<'a, C, No, No>;
+    fn set_access_control<'a>(&'a self) -> path::requests::SetAccessControlBuilder<'a, C, No, No>;
+}
+
+impl<C> FileSystem<C> for C
+where
+    C: Client,
+{
+    fn create_file_system<'a>(
+        &'a self,
+    ) -> file_system::requests::CreateFileSystemBuilder<'a, C, No> {
+        file_system::requests::CreateFileSystemBuilder::new(self)
+    }
+
+    fn delete_file_system<'a>(
+        &'a self,
+    ) -> file_system::requests::DeleteFileSystemBuilder<'a, C, No> {
+        file_system::requests::DeleteFileSystemBuilder::new(self)
+    }
+
+    fn list_paths<'a>(&'a self) -> file_system::requests::ListPathsBuilder<'a, C, No> {
+        file_system::requests::ListPathsBuilder::new(self)
+    }
+}
+
+impl<C> Path<C> for C
+where
+    C: Client,
+{
+    fn create_path<'a>(&'a self) -> path::requests::CreatePathBuilder<'a, C, No, No, No> {
+        path::requests::CreatePathBuilder::new(self)
+    }
+
+    fn rename_path<'a>(&'a self) -> path::requests::RenamePathBuilder<'a, C, No, No, No> {
+        path::requests::RenamePathBuilder::new(self)
+    }
+
+    fn delete_path<'a>(&'a self) -> path::requests::DeletePathBuilder<'a, C, No, No> {
+        path::requests::DeletePathBuilder::new(self)
+    }
+
+    fn append_data<'a>(&'a self) -> path::requests::AppendDataBuilder<'a, C, No, No, No, No> {
+        path::requests::AppendDataBuilder::new(self)
+    }
+
+    fn flush_data<'a>(&'a self) -> path::requests::FlushDataBuilder<'a, C, No, No, No> {
+        path::requests::FlushDataBuilder::new(self)
+    }
+
+    fn get_access_control<'a>(&'a self) -> path::requests::GetAccessControlBuilder<'a, C, No, No> {
+        path::requests::GetAccessControlBuilder::new(self)
+    }
+
+    fn set_access_control<'a>(&'a self) -> path::requests::SetAccessControlBuilder<'a, C, No, No> {
+        path::requests::SetAccessControlBuilder::new(self)
+    }
+}
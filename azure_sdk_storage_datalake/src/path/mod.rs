@@ -0,0 +1,333 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{ACL, GROUP, OWNER, PERMISSIONS, UMASK};
+use azure_sdk_core::util::HeaderMapExt;
+use chrono::{DateTime, Utc};
+use http::request::Builder;
+use http::HeaderMap;
+use serde_json::Value;
+use url::form_urlencoded;
+
+create_enum!(ResourceType, (File, "file"), (Directory, "directory"));
+
+pub trait PathSupport<'a> {
+    type O;
+    fn with_path(self, path: &'a str) -> Self::O;
+}
+
+pub trait PathRequired<'a> {
+    fn path(&self) -> &'a str;
+}
+
+pub trait ResourceTypeSupport {
+    type O;
+    fn with_resource_type(self, resource_type: ResourceType) -> Self::O;
+}
+
+pub trait ResourceTypeRequired {
+    fn resource_type(&self) -> ResourceType;
+}
+
+/// The path to rename, in the same file system.
+pub trait RenameSourceSupport<'a> {
+    type O;
+    fn with_rename_source(self, rename_source: &'a str) -> Self::O;
+}
+
+pub trait RenameSourceRequired<'a> {
+    fn rename_source(&self) -> &'a str;
+}
+
+/// Offset of the data in the file: for an append it must be the
+/// length of the data uploaded so far, for a flush the length of the
+/// file once flushed.
+pub trait PositionSupport {
+    type O;
+    fn with_position(self, position: u64) -> Self::O;
+}
+
+pub trait PositionRequired {
+    fn position(&self) -> u64;
+}
+
+pub trait RecursiveSupport {
+    type O;
+    fn with_recursive(self) -> Self::O;
+}
+
+pub trait RecursiveOption {
+    fn recursive(&self) -> bool;
+}
+
+/// Token returned by a previous call that could not complete the
+/// operation in one go.
+pub trait ContinuationSupport<'a> {
+    type O;
+    fn with_continuation(self, continuation: &'a str) -> Self::O;
+}
+
+pub trait ContinuationOption<'a> {
+    fn continuation(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.continuation().map(|continuation| {
+            form_urlencoded::Serializer::new(String::new())
+                .append_pair("continuation", continuation)
+                .finish()
+        })
+    }
+}
+
+/// Returns the user principal names of the owner and the group instead
+/// of their object ids.
+pub trait UpnSupport {
+    type O;
+    fn with_upn(self) -> Self::O;
+}
+
+pub trait UpnOption {
+    fn upn(&self) -> bool;
+}
+
+pub trait RetainUncommittedDataSupport {
+    type O;
+    fn with_retain_uncommitted_data(self) -> Self::O;
+}
+
+pub trait RetainUncommittedDataOption {
+    fn retain_uncommitted_data(&self) -> bool;
+}
+
+/// Signals that this is the final flush of the file.
+pub trait CloseSupport {
+    type O;
+    fn with_close(self) -> Self::O;
+}
+
+pub trait CloseOption {
+    fn close(&self) -> bool;
+}
+
+pub trait OwnerSupport<'a> {
+    type O;
+    fn with_owner(self, owner: &'a str) -> Self::O;
+}
+
+pub trait OwnerOption<'a> {
+    fn owner(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(owner) = self.owner() {
+            builder = builder.header(OWNER, owner);
+        }
+        builder
+    }
+}
+
+pub trait GroupSupport<'a> {
+    type O;
+    fn with_group(self, group: &'a str) -> Self::O;
+}
+
+pub trait GroupOption<'a> {
+    fn group(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(group) = self.group() {
+            builder = builder.header(GROUP, group);
+        }
+        builder
+    }
+}
+
+/// POSIX permissions, either symbolic (`rwxr-x---`) or octal (`0750`).
+pub trait PermissionsSupport<'a> {
+    type O;
+    fn with_permissions(self, permissions: &'a str) -> Self::O;
+}
+
+pub trait PermissionsOption<'a> {
+    fn permissions(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(permissions) = self.permissions() {
+            builder = builder.header(PERMISSIONS, permissions);
+        }
+        builder
+    }
+}
+
+/// POSIX access control list, e.g. `user::rwx,group::r-x,other::---`.
+pub trait AclSupport<'a> {
+    type O;
+    fn with_acl(self, acl: &'a str) -> Self::O;
+}
+
+pub trait AclOption<'a> {
+    fn acl(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(acl) = self.acl() {
+            builder = builder.header(ACL, acl);
+        }
+        builder
+    }
+}
+
+/// Octal mask (e.g. `0027`) applied to the permissions of the created
+/// path.
+pub trait UmaskSupport<'a> {
+    type O;
+    fn with_umask(self, umask: &'a str) -> Self::O;
+}
+
+pub trait UmaskOption<'a> {
+    fn umask(&self) -> Option<&'a str>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(umask) = self.umask() {
+            builder = builder.header(UMASK, umask);
+        }
+        builder
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    pub name: String,
+    pub is_directory: bool,
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    pub content_length: u64,
+    /// Only returned by accounts with the hierarchical namespace enabled.
+    pub owner: Option<String>,
+    pub group: Option<String>,
+    pub permissions: Option<String>,
+}
+
+impl AsRef<str> for Path {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+// The service returns most of the values as strings, booleans and
+// numbers included.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PathEntry {
+    name: String,
+    is_directory: Option<Value>,
+    last_modified: String,
+    etag: String,
+    content_length: Option<Value>,
+    owner: Option<String>,
+    group: Option<String>,
+    permissions: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PathList {
+    paths: Vec<PathEntry>,
+}
+
+fn value_to_string(value: Value) -> String {
+    match value {
+        Value::String(s) => s,
+        value => value.to_string(),
+    }
+}
+
+impl Path {
+    fn from_entry(entry: PathEntry) -> Result<Path, AzureError> {
+        let is_directory = match entry.is_directory {
+            Some(value) => value_to_string(value).parse()?,
+            None => false,
+        };
+        let content_length = match entry.content_length {
+            Some(value) => value_to_string(value).parse()?,
+            None => 0,
+        };
+        let last_modified = DateTime::parse_from_rfc2822(&entry.last_modified)?.with_timezone(&Utc);
+
+        Ok(Path {
+            name: entry.name,
+            is_directory,
+            last_modified,
+            etag: entry.etag,
+            content_length,
+            owner: entry.owner,
+            group: entry.group,
+            permissions: entry.permissions,
+        })
+    }
+}
+
+pub(crate) fn paths_from_response(body: &[u8]) -> Result<Vec<Path>, AzureError> {
+    let list: PathList = serde_json::from_slice(body)?;
+    list.paths.into_iter().map(Path::from_entry).collect()
+}
+
+fn header_as_string(headers: &HeaderMap, name: &str) -> Result<String, AzureError> {
+    headers
+        .get_as_str(name)
+        .map(|value| value.to_owned())
+        .ok_or_else(|| AzureError::HeaderNotFound(name.to_owned()))
+}
+
+pub(crate) fn owner_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    header_as_string(headers, OWNER)
+}
+
+pub(crate) fn group_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    header_as_string(headers, GROUP)
+}
+
+pub(crate) fn permissions_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    header_as_string(headers, PERMISSIONS)
+}
+
+pub(crate) fn acl_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    header_as_string(headers, ACL)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_list_paths_response() {
+        let body = br#"{"paths":[
+            {"contentLength":"0","etag":"0x8D7E1F6A3C1A8F2","group":"$superuser","isDirectory":"true","lastModified":"Thu, 16 Apr 2020 09:31:12 GMT","name":"dir","owner":"$superuser","permissions":"rwxr-x---"},
+            {"contentLength":"16","etag":"0x8D7E1F6A3D2B6C1","group":"$superuser","lastModified":"Thu, 16 Apr 2020 09:31:13 GMT","name":"dir/hello.txt","owner":"$superuser","permissions":"rw-r-----"},
+            {"contentLength":1024,"etag":"0x8D7E1F6A3E9C0D4","isDirectory":false,"lastModified":"Thu, 16 Apr 2020 09:31:14 GMT","name":"flat.bin"}
+        ]}"#;
+
+        let paths = paths_from_response(body).unwrap();
+        assert_eq!(paths.len(), 3);
+
+        assert_eq!(paths[0].name, "dir");
+        assert!(paths[0].is_directory);
+        assert_eq!(paths[0].owner.as_deref(), Some("$superuser"));
+        assert_eq!(paths[0].permissions.as_deref(), Some("rwxr-x---"));
+        assert_eq!(
+            paths[0].last_modified.to_rfc3339(),
+            "2020-04-16T09:31:12+00:00"
+        );
+
+        assert_eq!(paths[1].name, "dir/hello.txt");
+        assert!(!paths[1].is_directory);
+        assert_eq!(paths[1].content_length, 16);
+
+        assert_eq!(paths[2].content_length, 1024);
+        assert!(!paths[2].is_directory);
+        assert_eq!(paths[2].owner, None);
+    }
+}
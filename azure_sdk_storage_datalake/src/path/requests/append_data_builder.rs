@@ -0,0 +1,473 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::AppendDataResponse;
+use crate::path::{PathRequired, PathSupport, PositionRequired, PositionSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    p_position: PhantomData<PositionSet>,
+    p_body: PhantomData<BodySet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    position: Option<u64>,
+    body: Option<&'a [u8]>,
+    content_md5: Option<&'a [u8]>,
+    lease_id: Option<&'a LeaseId>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> AppendDataBuilder<'a, C, No, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> AppendDataBuilder<'a, C, No, No, No, No> {
+        AppendDataBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            p_position: PhantomData {},
+            position: None,
+            p_body: PhantomData {},
+            body: None,
+            content_md5: None,
+            lease_id: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> ClientRequired<'a, C>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet, PositionSet, BodySet> FileSystemNameRequired<'a>
+    for AppendDataBuilder<'a, C, Yes, PathSet, PositionSet, BodySet>
+where
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PositionSet, BodySet> PathRequired<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, Yes, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, BodySet> PositionRequired
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, Yes, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn position(&self) -> u64 {
+        self.position.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> BodyRequired<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn body(&self) -> &'a [u8] {
+        self.body.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> ContentMD5Option<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_md5(&self) -> Option<&'a [u8]> {
+        self.content_md5
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> LeaseIdOption<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> ClientRequestIdOption<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> TimeoutOption
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet, PositionSet, BodySet> FileSystemNameSupport<'a>
+    for AppendDataBuilder<'a, C, No, PathSet, PositionSet, BodySet>
+where
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, Yes, PathSet, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            position: self.position,
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PositionSet, BodySet> PathSupport<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, No, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, Yes, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            position: self.position,
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, BodySet> PositionSupport
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, No, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, Yes, BodySet>;
+
+    #[inline]
+    fn with_position(self, position: u64) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: Some(position),
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> BodySupport<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, Yes>;
+
+    #[inline]
+    fn with_body(self, body: &'a [u8]) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            body: Some(body),
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> ContentMD5Support<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_content_md5(self, content_md5: &'a [u8]) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            body: self.body,
+            content_md5: Some(content_md5),
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> LeaseIdSupport<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: Some(lease_id),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> ClientRequestIdSupport<'a>
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet> TimeoutSupport
+    for AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = AppendDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet, BodySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AppendDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            p_body: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            body: self.body,
+            content_md5: self.content_md5,
+            lease_id: self.lease_id,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> AppendDataBuilder<'a, C, Yes, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<AppendDataResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some(&format!("action=append&position={}", self.position())),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PATCH,
+            &|mut request| {
+                request = ContentMD5Option::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(self.body()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        AppendDataResponse::from_headers(&headers)
+    }
+}
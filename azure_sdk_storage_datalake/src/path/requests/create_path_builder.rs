@@ -0,0 +1,563 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::CreatePathResponse;
+use crate::path::{
+    PathRequired, PathSupport, PermissionsOption, PermissionsSupport, ResourceType,
+    ResourceTypeRequired, ResourceTypeSupport, UmaskOption, UmaskSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    p_resource_type: PhantomData<ResourceTypeSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    resource_type: Option<ResourceType>,
+    permissions: Option<&'a str>,
+    umask: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreatePathBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreatePathBuilder<'a, C, No, No, No> {
+        CreatePathBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            p_resource_type: PhantomData {},
+            resource_type: None,
+            permissions: None,
+            umask: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> ClientRequired<'a, C>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet, ResourceTypeSet> FileSystemNameRequired<'a>
+    for CreatePathBuilder<'a, C, Yes, PathSet, ResourceTypeSet>
+where
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, ResourceTypeSet> PathRequired<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, Yes, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ResourceTypeRequired
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn resource_type(&self) -> ResourceType {
+        self.resource_type.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> PermissionsOption<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn permissions(&self) -> Option<&'a str> {
+        self.permissions
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> UmaskOption<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn umask(&self) -> Option<&'a str> {
+        self.umask
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> LeaseIdOption<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> IfSinceConditionOption
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> IfMatchConditionOption<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> ClientRequestIdOption<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> TimeoutOption
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet, ResourceTypeSet> FileSystemNameSupport<'a>
+    for CreatePathBuilder<'a, C, No, PathSet, ResourceTypeSet>
+where
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, Yes, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, ResourceTypeSet> PathSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, No, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, Yes, ResourceTypeSet>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ResourceTypeSupport
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, Yes>;
+
+    #[inline]
+    fn with_resource_type(self, resource_type: ResourceType) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: Some(resource_type),
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> PermissionsSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_permissions(self, permissions: &'a str) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: Some(permissions),
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> UmaskSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_umask(self, umask: &'a str) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: Some(umask),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> LeaseIdSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> IfSinceConditionSupport
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> IfMatchConditionSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> ClientRequestIdSupport<'a>
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet> TimeoutSupport
+    for CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    ResourceTypeSet: ToAssign,
+    C: Client,
+{
+    type O = CreatePathBuilder<'a, C, FileSystemNameSet, PathSet, ResourceTypeSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreatePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_resource_type: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            resource_type: self.resource_type,
+            permissions: self.permissions,
+            umask: self.umask,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreatePathBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreatePathResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some(&format!("resource={}", self.resource_type())),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = PermissionsOption::add_header(&self, request);
+                request = UmaskOption::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreatePathResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,475 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::DeletePathResponse;
+use crate::path::{
+    ContinuationOption, ContinuationSupport, PathRequired, PathSupport, RecursiveOption,
+    RecursiveSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    recursive: bool,
+    continuation: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> DeletePathBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DeletePathBuilder<'a, C, No, No> {
+        DeletePathBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            recursive: false,
+            continuation: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequired<'a, C>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet> FileSystemNameRequired<'a> for DeletePathBuilder<'a, C, Yes, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathRequired<'a> for DeletePathBuilder<'a, C, FileSystemNameSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> RecursiveOption
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn recursive(&self) -> bool {
+        self.recursive
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ContinuationOption<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn continuation(&self) -> Option<&'a str> {
+        self.continuation
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdOption<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionOption
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionOption<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdOption<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutOption
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet> FileSystemNameSupport<'a> for DeletePathBuilder<'a, C, No, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, Yes, PathSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathSupport<'a> for DeletePathBuilder<'a, C, FileSystemNameSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, Yes>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> RecursiveSupport
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_recursive(self) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: true,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ContinuationSupport<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_continuation(self, continuation: &'a str) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: Some(continuation),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdSupport<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionSupport
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionSupport<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdSupport<'a>
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutSupport
+    for DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = DeletePathBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeletePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            recursive: self.recursive,
+            continuation: self.continuation,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DeletePathBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<DeletePathResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some(&format!("recursive={}", self.recursive())),
+        );
+
+        if let Some(continuation) = ContinuationOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, continuation);
+        }
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            &|mut request| {
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        DeletePathResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,567 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::FlushDataResponse;
+use crate::path::{
+    CloseOption, CloseSupport, PathRequired, PathSupport, PositionRequired, PositionSupport,
+    RetainUncommittedDataOption, RetainUncommittedDataSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    p_position: PhantomData<PositionSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    position: Option<u64>,
+    retain_uncommitted_data: bool,
+    close: bool,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> FlushDataBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> FlushDataBuilder<'a, C, No, No, No> {
+        FlushDataBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            p_position: PhantomData {},
+            position: None,
+            retain_uncommitted_data: false,
+            close: false,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> ClientRequired<'a, C>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet, PositionSet> FileSystemNameRequired<'a>
+    for FlushDataBuilder<'a, C, Yes, PathSet, PositionSet>
+where
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PositionSet> PathRequired<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, Yes, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> PositionRequired
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn position(&self) -> u64 {
+        self.position.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> RetainUncommittedDataOption
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn retain_uncommitted_data(&self) -> bool {
+        self.retain_uncommitted_data
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> CloseOption
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn close(&self) -> bool {
+        self.close
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> LeaseIdOption<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> IfSinceConditionOption
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> IfMatchConditionOption<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> ClientRequestIdOption<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> TimeoutOption
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet, PositionSet> FileSystemNameSupport<'a>
+    for FlushDataBuilder<'a, C, No, PathSet, PositionSet>
+where
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, Yes, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PositionSet> PathSupport<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, No, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, Yes, PositionSet>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> PositionSupport
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, Yes>;
+
+    #[inline]
+    fn with_position(self, position: u64) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: Some(position),
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> RetainUncommittedDataSupport
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_retain_uncommitted_data(self) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: true,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> CloseSupport
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_close(self) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: true,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> LeaseIdSupport<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> IfSinceConditionSupport
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> IfMatchConditionSupport<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> ClientRequestIdSupport<'a>
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, PositionSet> TimeoutSupport
+    for FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    PositionSet: ToAssign,
+    C: Client,
+{
+    type O = FlushDataBuilder<'a, C, FileSystemNameSet, PathSet, PositionSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        FlushDataBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_position: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            position: self.position,
+            retain_uncommitted_data: self.retain_uncommitted_data,
+            close: self.close,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> FlushDataBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<FlushDataResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some(&format!("action=flush&position={}", self.position())),
+        );
+
+        if self.retain_uncommitted_data() {
+            uri = format!("{}&retainUncommittedData=true", uri);
+        }
+        if self.close() {
+            uri = format!("{}&close=true", uri);
+        }
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PATCH,
+            &|mut request| {
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        FlushDataResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,423 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::GetAccessControlResponse;
+use crate::path::{PathRequired, PathSupport, UpnOption, UpnSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    upn: bool,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> GetAccessControlBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> GetAccessControlBuilder<'a, C, No, No> {
+        GetAccessControlBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            upn: false,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequired<'a, C>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet> FileSystemNameRequired<'a> for GetAccessControlBuilder<'a, C, Yes, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathRequired<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> UpnOption
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn upn(&self) -> bool {
+        self.upn
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdOption<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionOption
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionOption<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdOption<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutOption
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet> FileSystemNameSupport<'a> for GetAccessControlBuilder<'a, C, No, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, Yes, PathSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathSupport<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, Yes>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> UpnSupport
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_upn(self) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: true,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdSupport<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: self.upn,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionSupport
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionSupport<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdSupport<'a>
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutSupport
+    for GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = GetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            upn: self.upn,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> GetAccessControlBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<GetAccessControlResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some("action=getAccessControl"),
+        );
+
+        if self.upn() {
+            uri = format!("{}&upn=true", uri);
+        }
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::HEAD,
+            &|mut request| {
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        GetAccessControlResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,14 @@
+mod append_data_builder;
+pub use self::append_data_builder::AppendDataBuilder;
+mod create_path_builder;
+pub use self::create_path_builder::CreatePathBuilder;
+mod delete_path_builder;
+pub use self::delete_path_builder::DeletePathBuilder;
+mod flush_data_builder;
+pub use self::flush_data_builder::FlushDataBuilder;
+mod get_access_control_builder;
+pub use self::get_access_control_builder::GetAccessControlBuilder;
+mod rename_path_builder;
+pub use self::rename_path_builder::RenamePathBuilder;
+mod set_access_control_builder;
+pub use self::set_access_control_builder::SetAccessControlBuilder;
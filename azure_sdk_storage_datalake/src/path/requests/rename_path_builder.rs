@@ -0,0 +1,459 @@
+use crate::file_system::encode_path;
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::RenamePathResponse;
+use crate::path::{PathRequired, PathSupport, RenameSourceRequired, RenameSourceSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::RENAME_SOURCE;
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::retry_policy::NonRetriable;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    p_rename_source: PhantomData<RenameSourceSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    rename_source: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> RenamePathBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> RenamePathBuilder<'a, C, No, No, No> {
+        RenamePathBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            p_rename_source: PhantomData {},
+            rename_source: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> ClientRequired<'a, C>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet, RenameSourceSet> FileSystemNameRequired<'a>
+    for RenamePathBuilder<'a, C, Yes, PathSet, RenameSourceSet>
+where
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, RenameSourceSet> PathRequired<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, Yes, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> RenameSourceRequired<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn rename_source(&self) -> &'a str {
+        self.rename_source.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> LeaseIdOption<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> IfSinceConditionOption
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> IfMatchConditionOption<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> ClientRequestIdOption<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> TimeoutOption
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet, RenameSourceSet> FileSystemNameSupport<'a>
+    for RenamePathBuilder<'a, C, No, PathSet, RenameSourceSet>
+where
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, Yes, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, RenameSourceSet> PathSupport<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, No, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, Yes, RenameSourceSet>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> RenameSourceSupport<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, Yes>;
+
+    #[inline]
+    fn with_rename_source(self, rename_source: &'a str) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: Some(rename_source),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> LeaseIdSupport<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> IfSinceConditionSupport
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> IfMatchConditionSupport<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> ClientRequestIdSupport<'a>
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet, RenameSourceSet> TimeoutSupport
+    for RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    RenameSourceSet: ToAssign,
+    C: Client,
+{
+    type O = RenamePathBuilder<'a, C, FileSystemNameSet, PathSet, RenameSourceSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        RenamePathBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            p_rename_source: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            rename_source: self.rename_source,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> RenamePathBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<RenamePathResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            None,
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let rename_source = format!(
+            "/{}/{}",
+            self.file_system_name(),
+            encode_path(self.rename_source())
+        );
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = request.header(RENAME_SOURCE, &rename_source as &str);
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                // once renamed the source is gone: a replay would fail with 404
+                request.extension(NonRetriable)
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        RenamePathResponse::from_headers(&headers)
+    }
+}
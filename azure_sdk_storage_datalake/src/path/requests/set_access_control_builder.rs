@@ -0,0 +1,586 @@
+use crate::file_system::generate_file_system_uri;
+use crate::path::responses::SetAccessControlResponse;
+use crate::path::{
+    AclOption, AclSupport, GroupOption, GroupSupport, OwnerOption, OwnerSupport, PathRequired,
+    PathSupport, PermissionsOption, PermissionsSupport,
+};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::lease::LeaseId;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_file_system_name: PhantomData<FileSystemNameSet>,
+    p_path: PhantomData<PathSet>,
+    file_system_name: Option<&'a str>,
+    path: Option<&'a str>,
+    owner: Option<&'a str>,
+    group: Option<&'a str>,
+    permissions: Option<&'a str>,
+    acl: Option<&'a str>,
+    lease_id: Option<&'a LeaseId>,
+    if_since_condition: Option<IfSinceCondition>,
+    if_match_condition: Option<IfMatchCondition<'a>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> SetAccessControlBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> SetAccessControlBuilder<'a, C, No, No> {
+        SetAccessControlBuilder {
+            client,
+            p_file_system_name: PhantomData {},
+            file_system_name: None,
+            p_path: PhantomData {},
+            path: None,
+            owner: None,
+            group: None,
+            permissions: None,
+            acl: None,
+            lease_id: None,
+            if_since_condition: None,
+            if_match_condition: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequired<'a, C>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, PathSet> FileSystemNameRequired<'a> for SetAccessControlBuilder<'a, C, Yes, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_system_name(&self) -> &'a str {
+        self.file_system_name.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathRequired<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, Yes>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn path(&self) -> &'a str {
+        self.path.unwrap()
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> OwnerOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn owner(&self) -> Option<&'a str> {
+        self.owner
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> GroupOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn group(&self) -> Option<&'a str> {
+        self.group
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> PermissionsOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn permissions(&self) -> Option<&'a str> {
+        self.permissions
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> AclOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn acl(&self) -> Option<&'a str> {
+        self.acl
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn lease_id(&self) -> Option<&'a LeaseId> {
+        self.lease_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionOption
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_since_condition(&self) -> Option<IfSinceCondition> {
+        self.if_since_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn if_match_condition(&self) -> Option<IfMatchCondition<'a>> {
+        self.if_match_condition
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdOption<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutOption
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, PathSet> FileSystemNameSupport<'a> for SetAccessControlBuilder<'a, C, No, PathSet>
+where
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, Yes, PathSet>;
+
+    #[inline]
+    fn with_file_system_name(self, file_system_name: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: Some(file_system_name),
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet> PathSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, No>
+where
+    FileSystemNameSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, Yes>;
+
+    #[inline]
+    fn with_path(self, path: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: Some(path),
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> OwnerSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_owner(self, owner: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: Some(owner),
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> GroupSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_group(self, group: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: Some(group),
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> PermissionsSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_permissions(self, permissions: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: Some(permissions),
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> AclSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_acl(self, acl: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: Some(acl),
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> LeaseIdSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: Some(lease_id),
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfSinceConditionSupport
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_since_condition(self, if_since_condition: IfSinceCondition) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: Some(if_since_condition),
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> IfMatchConditionSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_if_match_condition(self, if_match_condition: IfMatchCondition<'a>) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: Some(if_match_condition),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> ClientRequestIdSupport<'a>
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, FileSystemNameSet, PathSet> TimeoutSupport
+    for SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>
+where
+    FileSystemNameSet: ToAssign,
+    PathSet: ToAssign,
+    C: Client,
+{
+    type O = SetAccessControlBuilder<'a, C, FileSystemNameSet, PathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        SetAccessControlBuilder {
+            client: self.client,
+            p_file_system_name: PhantomData {},
+            p_path: PhantomData {},
+            file_system_name: self.file_system_name,
+            path: self.path,
+            owner: self.owner,
+            group: self.group,
+            permissions: self.permissions,
+            acl: self.acl,
+            lease_id: self.lease_id,
+            if_since_condition: self.if_since_condition,
+            if_match_condition: self.if_match_condition,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> SetAccessControlBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<SetAccessControlResponse, AzureError> {
+        let mut uri = generate_file_system_uri(
+            self.client(),
+            self.file_system_name(),
+            Some(self.path()),
+            Some("action=setAccessControl"),
+        );
+
+        if let Some(timeout) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, timeout);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PATCH,
+            &|mut request| {
+                request = OwnerOption::add_header(&self, request);
+                request = GroupOption::add_header(&self, request);
+                request = PermissionsOption::add_header(&self, request);
+                request = AclOption::add_header(&self, request);
+                request = LeaseIdOption::add_header(&self, request);
+                request = IfSinceConditionOption::add_header(&self, request);
+                request = IfMatchConditionOption::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        SetAccessControlResponse::from_headers(&headers)
+    }
+}
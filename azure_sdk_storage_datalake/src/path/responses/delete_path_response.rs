@@ -0,0 +1,8 @@
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+response_from_headers!(DeletePathResponse,
+               continuation_token_from_headers_optional -> continuation: Option<String>,
+               request_id_from_headers -> request_id: RequestId,
+               date_from_headers -> date: DateTime<Utc>
+);
@@ -0,0 +1,50 @@
+use crate::path::{
+    acl_from_headers, group_from_headers, owner_from_headers, permissions_from_headers,
+};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetAccessControlResponse {
+    pub owner: String,
+    pub group: String,
+    /// Symbolic POSIX permissions, e.g. `rwxr-x---`. A trailing `+`
+    /// means the path has an extended ACL.
+    pub permissions: String,
+    pub acl: String,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetAccessControlResponse {
+    pub(crate) fn from_headers(
+        headers: &HeaderMap,
+    ) -> Result<GetAccessControlResponse, AzureError> {
+        let owner = owner_from_headers(headers)?;
+        let group = group_from_headers(headers)?;
+        let permissions = permissions_from_headers(headers)?;
+        let acl = acl_from_headers(headers)?;
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(GetAccessControlResponse {
+            owner,
+            group,
+            permissions,
+            acl,
+            etag,
+            last_modified,
+            request_id,
+            date,
+        })
+    }
+}
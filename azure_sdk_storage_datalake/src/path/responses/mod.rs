@@ -0,0 +1,14 @@
+mod append_data_response;
+pub use self::append_data_response::AppendDataResponse;
+mod create_path_response;
+pub use self::create_path_response::CreatePathResponse;
+mod delete_path_response;
+pub use self::delete_path_response::DeletePathResponse;
+mod flush_data_response;
+pub use self::flush_data_response::FlushDataResponse;
+mod get_access_control_response;
+pub use self::get_access_control_response::GetAccessControlResponse;
+mod rename_path_response;
+pub use self::rename_path_response::RenamePathResponse;
+mod set_access_control_response;
+pub use self::set_access_control_response::SetAccessControlResponse;
@@ -0,0 +1,11 @@
+pub use crate::file_system::{DirectoryOption, DirectorySupport};
+pub use crate::path::{
+    AclOption, AclSupport, CloseOption, CloseSupport, ContinuationOption, ContinuationSupport,
+    GroupOption, GroupSupport, OwnerOption, OwnerSupport, PathRequired, PathSupport,
+    PermissionsOption, PermissionsSupport, PositionRequired, PositionSupport, RecursiveOption,
+    RecursiveSupport, RenameSourceRequired, RenameSourceSupport, ResourceType,
+    ResourceTypeRequired, ResourceTypeSupport, RetainUncommittedDataOption,
+    RetainUncommittedDataSupport, UmaskOption, UmaskSupport, UpnOption, UpnSupport,
+};
+pub use crate::FileSystem as FileSystemTrait;
+pub use crate::Path as PathTrait;
@@ -0,0 +1,199 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::key_client::KeyClient;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_datalake::prelude::*;
+use futures::stream::StreamExt;
+
+#[tokio::test]
+async fn create_write_and_list_paths() {
+    let file_system_name: &'static str = "azuresdkrustetoetsfs1";
+
+    let client = initialize();
+
+    let response = client
+        .create_file_system()
+        .with_file_system_name(file_system_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(response.namespace_enabled);
+
+    client
+        .create_path()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/nested")
+        .with_resource_type(ResourceType::Directory)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_path()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/file.bin")
+        .with_resource_type(ResourceType::File)
+        .with_permissions("0640")
+        .finalize()
+        .await
+        .unwrap();
+
+    let first = vec![1u8; 512];
+    let second = vec![2u8; 256];
+    client
+        .append_data()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/file.bin")
+        .with_position(0)
+        .with_body(&first)
+        .finalize()
+        .await
+        .unwrap();
+    client
+        .append_data()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/file.bin")
+        .with_position(first.len() as u64)
+        .with_body(&second)
+        .finalize()
+        .await
+        .unwrap();
+    client
+        .flush_data()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/file.bin")
+        .with_position((first.len() + second.len()) as u64)
+        .with_close()
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .rename_path()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one/nested/renamed.bin")
+        .with_rename_source("dir one/file.bin")
+        .finalize()
+        .await
+        .unwrap();
+
+    // one path per page to exercise the continuation
+    let mut stream = Box::pin(
+        client
+            .list_paths()
+            .with_file_system_name(file_system_name)
+            .with_recursive()
+            .with_max_results(1)
+            .stream(),
+    );
+
+    let mut names = Vec::new();
+    while let Some(response) = stream.next().await {
+        for path in response.unwrap().incomplete_vector.iter() {
+            if path.name == "dir one/nested/renamed.bin" {
+                assert!(!path.is_directory);
+                assert_eq!(path.content_length, 768);
+                assert_eq!(path.permissions.as_deref(), Some("rw-r-----"));
+            }
+            names.push(path.name.clone());
+        }
+    }
+    names.sort();
+    assert_eq!(
+        names,
+        vec!["dir one", "dir one/nested", "dir one/nested/renamed.bin"]
+    );
+
+    let response = client
+        .delete_path()
+        .with_file_system_name(file_system_name)
+        .with_path("dir one")
+        .with_recursive()
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.continuation, None);
+
+    client
+        .delete_file_system()
+        .with_file_system_name(file_system_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn set_and_get_access_control() {
+    let file_system_name: &'static str = "azuresdkrustetoetsfs2";
+
+    let client = initialize();
+
+    client
+        .create_file_system()
+        .with_file_system_name(file_system_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_path()
+        .with_file_system_name(file_system_name)
+        .with_path("file.txt")
+        .with_resource_type(ResourceType::File)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .set_access_control()
+        .with_file_system_name(file_system_name)
+        .with_path("file.txt")
+        .with_permissions("rwxr-x---")
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_access_control()
+        .with_file_system_name(file_system_name)
+        .with_path("file.txt")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.permissions, "rwxr-x---");
+
+    client
+        .set_access_control()
+        .with_file_system_name(file_system_name)
+        .with_path("file.txt")
+        .with_acl("user::rw-,group::r--,other::---")
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_access_control()
+        .with_file_system_name(file_system_name)
+        .with_path("file.txt")
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.acl, "user::rw-,group::r--,other::---");
+    assert_eq!(response.permissions, "rw-r-----");
+
+    client
+        .delete_file_system()
+        .with_file_system_name(file_system_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> KeyClient {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    client::with_access_key(&account, &master_key)
+}
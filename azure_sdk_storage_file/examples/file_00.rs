@@ -0,0 +1,95 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_file::prelude::*;
+use std::error::Error;
+use url::Url;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let share_name = std::env::args()
+        .nth(1)
+        .expect("please specify the share name as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    client
+        .create_share()
+        .with_share_name(&share_name)
+        .with_quota(1)
+        .finalize()
+        .await?;
+
+    client
+        .create_directory()
+        .with_share_name(&share_name)
+        .with_directory_path("documents")
+        .finalize()
+        .await?;
+
+    let data = b"Hello from Rust!";
+
+    // a file is created with its final size and then filled range by range
+    client
+        .create_file()
+        .with_share_name(&share_name)
+        .with_file_path("documents/hello.txt")
+        .with_content_length(data.len() as u64)
+        .finalize()
+        .await?;
+
+    let range = Range::new(0, data.len() as u64);
+    client
+        .put_range()
+        .with_share_name(&share_name)
+        .with_file_path("documents/hello.txt")
+        .with_range(&range)
+        .with_body(data)
+        .finalize()
+        .await?;
+
+    let response = client
+        .get_range()
+        .with_share_name(&share_name)
+        .with_file_path("documents/hello.txt")
+        .finalize()
+        .await?;
+    println!("{}", String::from_utf8(response.data)?);
+
+    let response = client
+        .list_directories_and_files()
+        .with_share_name(&share_name)
+        .with_directory_path("documents")
+        .finalize()
+        .await?;
+    for file in response.files.iter() {
+        println!("{} ({} bytes)", file.name, file.content_length);
+    }
+
+    // a read only SAS, valid for one hour
+    let path = Url::parse(&format!(
+        "{}/{}/documents/hello.txt",
+        client.file_uri(),
+        share_name
+    ))?;
+    let end = chrono::Utc::now() + chrono::Duration::hours(1);
+    let sas = FileSASBuilder::new(&path)
+        .with_key(&master_key)
+        .with_validity_end(&end)
+        .allow_read()
+        .finalize();
+    println!("SAS url == {}", sas);
+
+    client
+        .delete_share()
+        .with_share_name(&share_name)
+        .finalize()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use xml::Element;
+
+pub trait DirectoryPathSupport<'a> {
+    type O;
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O;
+}
+
+pub trait DirectoryPathRequired<'a> {
+    fn directory_path(&self) -> &'a str;
+}
+
+pub trait DirectoryPathOption<'a> {
+    fn directory_path(&self) -> Option<&'a str>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DirectoryEntry {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileEntry {
+    pub name: String,
+    pub content_length: u64,
+}
+
+/// The content of a directory, as returned by a single List Directories
+/// and Files call.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct DirectoryListing {
+    pub directories: Vec<DirectoryEntry>,
+    pub files: Vec<FileEntry>,
+    pub next_marker: Option<String>,
+}
+
+impl DirectoryEntry {
+    fn parse(elem: &Element) -> Result<DirectoryEntry, AzureError> {
+        Ok(DirectoryEntry {
+            name: cast_must::<String>(elem, &["Name"])?,
+        })
+    }
+}
+
+impl FileEntry {
+    fn parse(elem: &Element) -> Result<FileEntry, AzureError> {
+        Ok(FileEntry {
+            name: cast_must::<String>(elem, &["Name"])?,
+            content_length: cast_must::<u64>(elem, &["Properties", "Content-Length"])?,
+        })
+    }
+}
+
+pub(crate) fn directory_listing_from_response(body: &str) -> Result<DirectoryListing, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut directories = Vec::new();
+    for directory in traverse(&elem, &["Entries", "Directory"], true)? {
+        directories.push(DirectoryEntry::parse(directory)?);
+    }
+
+    let mut files = Vec::new();
+    for file in traverse(&elem, &["Entries", "File"], true)? {
+        files.push(FileEntry::parse(file)?);
+    }
+
+    let next_marker = cast_optional::<String>(&elem, &["NextMarker"])?.filter(|nm| !nm.is_empty());
+
+    Ok(DirectoryListing {
+        directories,
+        files,
+        next_marker,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_list_directories_and_files_response() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ServiceEndpoint="https://myaccount.file.core.windows.net/" ShareName="myshare" DirectoryPath="mydir">
+  <MaxResults>3</MaxResults>
+  <Entries>
+    <File>
+      <Name>Rocky1.mp4</Name>
+      <Properties>
+        <Content-Length>2048</Content-Length>
+      </Properties>
+    </File>
+    <Directory>
+      <Name>Rocky2</Name>
+      <Properties />
+    </Directory>
+    <File>
+      <Name>Rocky3.mp4</Name>
+      <Properties>
+        <Content-Length>0</Content-Length>
+      </Properties>
+    </File>
+  </Entries>
+  <NextMarker />
+</EnumerationResults>"#;
+
+        let listing = directory_listing_from_response(body).unwrap();
+        assert_eq!(
+            listing.directories,
+            vec![DirectoryEntry {
+                name: "Rocky2".to_owned()
+            }]
+        );
+        assert_eq!(listing.files.len(), 2);
+        assert_eq!(listing.files[0].name, "Rocky1.mp4");
+        assert_eq!(listing.files[0].content_length, 2048);
+        assert_eq!(listing.files[1].content_length, 0);
+        assert_eq!(listing.next_marker, None);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::directory::responses::CreateDirectoryResponse;
+use crate::directory::{DirectoryPathRequired, DirectoryPathSupport};
+use crate::file::add_default_smb_headers;
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_directory_path: PhantomData<DirectoryPathSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreateBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreateBuilder<'a, C, No, No> {
+        CreateBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_directory_path: PhantomData {},
+            directory_path: None,
+            metadata: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequired<'a, C>
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, DirectoryPathSet> ShareNameRequired<'a> for CreateBuilder<'a, C, Yes, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathRequired<'a> for CreateBuilder<'a, C, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn directory_path(&self) -> &'a str {
+        self.directory_path.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> MetadataOption<'a>
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequestIdOption<'a>
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> TimeoutOption
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, DirectoryPathSet> ShareNameSupport<'a> for CreateBuilder<'a, C, No, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, Yes, DirectoryPathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathSupport<'a> for CreateBuilder<'a, C, ShareNameSet, No>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> MetadataSupport<'a>
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: Some(metadata),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequestIdSupport<'a>
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> TimeoutSupport
+    for CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreateBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreateDirectoryResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            Some(self.directory_path()),
+            Some("restype=directory"),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = ClientRequestIdOption::add_header(&self, request);
+                request = MetadataOption::add_header(&self, request);
+                request = add_default_smb_headers(request, "Directory");
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateDirectoryResponse::from_headers(&headers)
+    }
+}
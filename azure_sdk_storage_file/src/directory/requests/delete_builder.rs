@@ -0,0 +1,227 @@
+use crate::directory::responses::DeleteDirectoryResponse;
+use crate::directory::{DirectoryPathRequired, DirectoryPathSupport};
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_directory_path: PhantomData<DirectoryPathSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> DeleteBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DeleteBuilder<'a, C, No, No> {
+        DeleteBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_directory_path: PhantomData {},
+            directory_path: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequired<'a, C>
+    for DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, DirectoryPathSet> ShareNameRequired<'a> for DeleteBuilder<'a, C, Yes, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathRequired<'a> for DeleteBuilder<'a, C, ShareNameSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn directory_path(&self) -> &'a str {
+        self.directory_path.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequestIdOption<'a>
+    for DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> TimeoutOption
+    for DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, DirectoryPathSet> ShareNameSupport<'a> for DeleteBuilder<'a, C, No, DirectoryPathSet>
+where
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBuilder<'a, C, Yes, DirectoryPathSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        DeleteBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathSupport<'a> for DeleteBuilder<'a, C, ShareNameSet, No>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBuilder<'a, C, ShareNameSet, Yes>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        DeleteBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> ClientRequestIdSupport<'a>
+    for DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, DirectoryPathSet> TimeoutSupport
+    for DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>
+where
+    ShareNameSet: ToAssign,
+    DirectoryPathSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteBuilder<'a, C, ShareNameSet, DirectoryPathSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_directory_path: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DeleteBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<DeleteDirectoryResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            Some(self.directory_path()),
+            Some("restype=directory"),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+        DeleteDirectoryResponse::from_headers(&headers)
+    }
+}
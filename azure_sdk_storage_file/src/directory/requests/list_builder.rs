@@ -0,0 +1,376 @@
+use crate::directory::responses::ListDirectoriesAndFilesResponse;
+use crate::directory::{DirectoryPathOption, DirectoryPathSupport};
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use futures::stream::{unfold, Stream};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    share_name: Option<&'a str>,
+    directory_path: Option<&'a str>,
+    prefix: Option<&'a str>,
+    next_marker: Option<&'a str>,
+    max_results: Option<u32>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> ListBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> ListBuilder<'a, C, No> {
+        ListBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            directory_path: None,
+            prefix: None,
+            next_marker: None,
+            max_results: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequired<'a, C> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> ShareNameRequired<'a> for ListBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathOption<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn directory_path(&self) -> Option<&'a str> {
+        self.directory_path
+    }
+}
+
+impl<'a, C, ShareNameSet> PrefixOption<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn prefix(&self) -> Option<&'a str> {
+        self.prefix
+    }
+}
+
+impl<'a, C, ShareNameSet> NextMarkerOption<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn next_marker(&self) -> Option<&'a str> {
+        self.next_marker
+    }
+}
+
+impl<'a, C, ShareNameSet> MaxResultsOption for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn max_results(&self) -> Option<u32> {
+        self.max_results
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequestIdOption<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet> TimeoutOption for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> ShareNameSupport<'a> for ListBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = ListBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: Some(share_name),
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> DirectoryPathSupport<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_directory_path(self, directory_path: &'a str) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: Some(directory_path),
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> PrefixSupport<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_prefix(self, prefix: &'a str) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: Some(prefix),
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> NextMarkerSupport<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: Some(next_marker),
+            max_results: self.max_results,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> MaxResultsSupport for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_max_results(self, max_results: u32) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: Some(max_results),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequestIdSupport<'a> for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> TimeoutSupport for ListBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = ListBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ListBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            directory_path: self.directory_path,
+            prefix: self.prefix,
+            next_marker: self.next_marker,
+            max_results: self.max_results,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> ListBuilder<'a, C, Yes>
+where
+    C: Client + Clone,
+{
+    pub async fn finalize(self) -> Result<ListDirectoriesAndFilesResponse, AzureError> {
+        // without a directory path the root directory of the share is listed
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            self.directory_path(),
+            Some("restype=directory&comp=list"),
+        );
+
+        if let Some(mr) = MaxResultsOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, mr);
+        }
+        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        debug!("generated uri = {}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        ListDirectoriesAndFilesResponse::from_response(&headers, &body)
+    }
+
+    pub fn stream(
+        self,
+    ) -> impl Stream<Item = Result<ListDirectoriesAndFilesResponse, AzureError>> + 'a {
+        #[derive(Debug, Clone, PartialEq)]
+        enum States {
+            Init,
+            NextMarker(String),
+        }
+
+        unfold(Some(States::Init), move |next_marker: Option<States>| {
+            let req = self.clone();
+            async move {
+                debug!("next_marker == {:?}", &next_marker);
+                let response = match next_marker {
+                    Some(States::Init) => req.finalize().await,
+                    Some(States::NextMarker(next_marker)) => {
+                        req.with_next_marker(&next_marker).finalize().await
+                    }
+                    None => return None,
+                };
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(err) => return Some((Err(err), None)),
+                };
+
+                let next_marker = response
+                    .next_marker
+                    .as_ref()
+                    .map(|nm| States::NextMarker(nm.to_owned()));
+
+                Some((Ok(response), next_marker))
+            }
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod create_builder;
+pub use self::create_builder::CreateBuilder;
+mod delete_builder;
+pub use self::delete_builder::DeleteBuilder;
+mod list_builder;
+pub use self::list_builder::ListBuilder;
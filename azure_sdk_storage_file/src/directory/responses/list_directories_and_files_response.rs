@@ -0,0 +1,37 @@
+use crate::directory::{directory_listing_from_response, DirectoryEntry, FileEntry};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListDirectoriesAndFilesResponse {
+    pub directories: Vec<DirectoryEntry>,
+    pub files: Vec<FileEntry>,
+    pub next_marker: Option<String>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl ListDirectoriesAndFilesResponse {
+    pub fn is_complete(&self) -> bool {
+        self.next_marker.is_none()
+    }
+
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<ListDirectoriesAndFilesResponse, AzureError> {
+        let listing = directory_listing_from_response(body)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(ListDirectoriesAndFilesResponse {
+            directories: listing.directories,
+            files: listing.files,
+            next_marker: listing.next_marker,
+            request_id,
+            date,
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod create_directory_response;
+pub use self::create_directory_response::CreateDirectoryResponse;
+mod delete_directory_response;
+pub use self::delete_directory_response::DeleteDirectoryResponse;
+mod list_directories_and_files_response;
+pub use self::list_directories_and_files_response::ListDirectoriesAndFilesResponse;
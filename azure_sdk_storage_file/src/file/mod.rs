@@ -0,0 +1,105 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{
+    FILE_ATTRIBUTES, FILE_CONTENT_LENGTH, FILE_CREATION_TIME, FILE_LAST_WRITE_TIME,
+    FILE_PERMISSION, META_PREFIX,
+};
+use azure_sdk_core::parsing::{cast_must, traverse};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::util::HeaderMapExt;
+use http::request::Builder;
+use http::HeaderMap;
+use std::collections::HashMap;
+use xml::Element;
+
+pub trait FilePathSupport<'a> {
+    type O;
+    fn with_file_path(self, file_path: &'a str) -> Self::O;
+}
+
+pub trait FilePathRequired<'a> {
+    fn file_path(&self) -> &'a str;
+}
+
+/// Adds the SMB properties the service requires when creating a file or
+/// a directory: the permissions are inherited from the parent directory
+/// and the timestamps are set to the request time.
+#[must_use]
+pub(crate) fn add_default_smb_headers(builder: Builder, attributes: &'static str) -> Builder {
+    builder
+        .header(FILE_PERMISSION, "inherit")
+        .header(FILE_ATTRIBUTES, attributes)
+        .header(FILE_CREATION_TIME, "now")
+        .header(FILE_LAST_WRITE_TIME, "now")
+}
+
+/// Parses the Range elements of a List Ranges response. The service
+/// returns inclusive ends while `Range` ends are exclusive.
+pub(crate) fn ranges_from_response(body: &str) -> Result<Vec<Range>, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+    for range in traverse(&elem, &["Range"], true)? {
+        let start = cast_must::<u64>(range, &["Start"])?;
+        let end = cast_must::<u64>(range, &["End"])?;
+        v.push(Range::new(start, end + 1));
+    }
+    Ok(v)
+}
+
+pub(crate) fn metadata_from_headers(
+    headers: &HeaderMap,
+) -> Result<HashMap<String, String>, AzureError> {
+    let mut metadata = HashMap::new();
+    for (key, value) in headers {
+        if key.as_str().starts_with(META_PREFIX) {
+            metadata.insert(
+                key.as_str()[META_PREFIX.len()..].to_owned(),
+                value.to_str()?.to_owned(),
+            );
+        }
+    }
+    Ok(metadata)
+}
+
+pub(crate) fn file_content_length_from_headers(headers: &HeaderMap) -> Result<u64, AzureError> {
+    let content_length = headers
+        .get_as_str(FILE_CONTENT_LENGTH)
+        .ok_or_else(|| AzureError::HeaderNotFound(FILE_CONTENT_LENGTH.to_owned()))?;
+    Ok(content_length.parse::<u64>()?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::header::HeaderValue;
+
+    #[test]
+    fn parse_list_ranges_response() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <Ranges>\
+            <Range><Start>0</Start><End>511</End></Range>\
+            <Range><Start>1024</Start><End>2047</End></Range>\
+            </Ranges>";
+
+        let ranges = ranges_from_response(body).unwrap();
+        assert_eq!(ranges, vec![Range::new(0, 512), Range::new(1024, 2048)]);
+        assert_eq!(ranges[1].len(), 1024);
+
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?><Ranges />";
+        assert!(ranges_from_response(body).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_metadata_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ms-meta-color", HeaderValue::from_static("red"));
+        headers.insert("x-ms-request-id", HeaderValue::from_static("ignored"));
+
+        let metadata = metadata_from_headers(&headers).unwrap();
+        assert_eq!(metadata.len(), 1);
+        assert_eq!(metadata.get("color"), Some(&"red".to_owned()));
+    }
+}
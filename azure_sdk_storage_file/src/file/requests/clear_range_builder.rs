@@ -0,0 +1,297 @@
+use crate::file::responses::ClearRangeResponse;
+use crate::file::{FilePathRequired, FilePathSupport};
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::FILE_WRITE;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_range: PhantomData<RangeSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    range: Option<&'a Range>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> ClearRangeBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> ClearRangeBuilder<'a, C, No, No, No> {
+        ClearRangeBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_range: PhantomData {},
+            range: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> ClientRequired<'a, C>
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, FilePathSet, RangeSet> ShareNameRequired<'a>
+    for ClearRangeBuilder<'a, C, Yes, FilePathSet, RangeSet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, RangeSet> FilePathRequired<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, Yes, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet> RangeRequired<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn range(&self) -> &'a Range {
+        self.range.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> ClientRequestIdOption<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> TimeoutOption
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, FilePathSet, RangeSet> ShareNameSupport<'a>
+    for ClearRangeBuilder<'a, C, No, FilePathSet, RangeSet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    type O = ClearRangeBuilder<'a, C, Yes, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            range: self.range,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, RangeSet> FilePathSupport<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, No, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    type O = ClearRangeBuilder<'a, C, ShareNameSet, Yes, RangeSet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            range: self.range,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet> RangeSupport<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, No>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    C: Client,
+{
+    type O = ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, Yes>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: Some(range),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> ClientRequestIdSupport<'a>
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    type O = ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> TimeoutSupport
+    for ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    type O = ClearRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ClearRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> ClearRangeBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<ClearRangeResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            Some(self.file_path()),
+            Some("comp=range"),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = RangeRequired::add_header(&self, request);
+                request = request.header(FILE_WRITE, "clear");
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        ClearRangeResponse::from_headers(&headers)
+    }
+}
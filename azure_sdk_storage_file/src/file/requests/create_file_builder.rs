@@ -0,0 +1,352 @@
+use crate::file::add_default_smb_headers;
+use crate::file::responses::CreateFileResponse;
+use crate::file::{FilePathRequired, FilePathSupport};
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::{FILE_CONTENT_LENGTH, FILE_TYPE};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_content_length: PhantomData<ContentLengthSet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    content_length: Option<u64>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreateFileBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreateFileBuilder<'a, C, No, No, No> {
+        CreateFileBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_content_length: PhantomData {},
+            content_length: None,
+            metadata: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> ClientRequired<'a, C>
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, FilePathSet, ContentLengthSet> ShareNameRequired<'a>
+    for CreateFileBuilder<'a, C, Yes, FilePathSet, ContentLengthSet>
+where
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, ContentLengthSet> FilePathRequired<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, Yes, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet> ContentLengthRequired
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_length(&self) -> u64 {
+        self.content_length.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> MetadataOption<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> ClientRequestIdOption<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> TimeoutOption
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, FilePathSet, ContentLengthSet> ShareNameSupport<'a>
+    for CreateFileBuilder<'a, C, No, FilePathSet, ContentLengthSet>
+where
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, Yes, FilePathSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            content_length: self.content_length,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, ContentLengthSet> FilePathSupport<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, No, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, ShareNameSet, Yes, ContentLengthSet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            content_length: self.content_length,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet> ContentLengthSupport
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, No>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, Yes>;
+
+    #[inline]
+    fn with_content_length(self, content_length: u64) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            content_length: Some(content_length),
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> MetadataSupport<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            content_length: self.content_length,
+            metadata: Some(metadata),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> ClientRequestIdSupport<'a>
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            content_length: self.content_length,
+            metadata: self.metadata,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, ContentLengthSet> TimeoutSupport
+    for CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    ContentLengthSet: ToAssign,
+    C: Client,
+{
+    type O = CreateFileBuilder<'a, C, ShareNameSet, FilePathSet, ContentLengthSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateFileBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_content_length: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            content_length: self.content_length,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreateFileBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreateFileResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            Some(self.file_path()),
+            None,
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = request.header(FILE_TYPE, "file");
+                request = request.header(
+                    FILE_CONTENT_LENGTH,
+                    &self.content_length().to_string() as &str,
+                );
+                request = ClientRequestIdOption::add_header(&self, request);
+                request = MetadataOption::add_header(&self, request);
+                request = add_default_smb_headers(request, "None");
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateFileResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,16 @@
+mod clear_range_builder;
+pub use self::clear_range_builder::ClearRangeBuilder;
+mod create_file_builder;
+pub use self::create_file_builder::CreateFileBuilder;
+mod delete_file_builder;
+pub use self::delete_file_builder::DeleteFileBuilder;
+mod get_file_metadata_builder;
+pub use self::get_file_metadata_builder::GetFileMetadataBuilder;
+mod get_range_builder;
+pub use self::get_range_builder::GetRangeBuilder;
+mod list_ranges_builder;
+pub use self::list_ranges_builder::ListRangesBuilder;
+mod put_range_builder;
+pub use self::put_range_builder::PutRangeBuilder;
+mod set_file_metadata_builder;
+pub use self::set_file_metadata_builder::SetFileMetadataBuilder;
@@ -0,0 +1,419 @@
+use crate::file::responses::PutRangeResponse;
+use crate::file::{FilePathRequired, FilePathSupport};
+use crate::share::generate_share_uri;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::headers::FILE_WRITE;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    p_file_path: PhantomData<FilePathSet>,
+    p_range: PhantomData<RangeSet>,
+    p_body: PhantomData<BodySet>,
+    share_name: Option<&'a str>,
+    file_path: Option<&'a str>,
+    range: Option<&'a Range>,
+    body: Option<&'a [u8]>,
+    content_md5: Option<&'a [u8]>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> PutRangeBuilder<'a, C, No, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> PutRangeBuilder<'a, C, No, No, No, No> {
+        PutRangeBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            p_file_path: PhantomData {},
+            file_path: None,
+            p_range: PhantomData {},
+            range: None,
+            p_body: PhantomData {},
+            body: None,
+            content_md5: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequired<'a, C>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, FilePathSet, RangeSet, BodySet> ShareNameRequired<'a>
+    for PutRangeBuilder<'a, C, Yes, FilePathSet, RangeSet, BodySet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, RangeSet, BodySet> FilePathRequired<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, Yes, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn file_path(&self) -> &'a str {
+        self.file_path.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, BodySet> RangeRequired<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, Yes, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn range(&self) -> &'a Range {
+        self.range.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> BodyRequired<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, Yes>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn body(&self) -> &'a [u8] {
+        self.body.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> ContentMD5Option<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn content_md5(&self) -> Option<&'a [u8]> {
+        self.content_md5
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequestIdOption<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> TimeoutOption
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, FilePathSet, RangeSet, BodySet> ShareNameSupport<'a>
+    for PutRangeBuilder<'a, C, No, FilePathSet, RangeSet, BodySet>
+where
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, Yes, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: Some(share_name),
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, RangeSet, BodySet> FilePathSupport<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, No, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, Yes, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_file_path(self, file_path: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: Some(file_path),
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, BodySet> RangeSupport<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, No, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, Yes, BodySet>;
+
+    #[inline]
+    fn with_range(self, range: &'a Range) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: Some(range),
+            body: self.body,
+            content_md5: self.content_md5,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet> BodySupport<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, No>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, Yes>;
+
+    #[inline]
+    fn with_body(self, body: &'a [u8]) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: Some(body),
+            content_md5: self.content_md5,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> ContentMD5Support<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_content_md5(self, content_md5: &'a [u8]) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: Some(content_md5),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> ClientRequestIdSupport<'a>
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet> TimeoutSupport
+    for PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>
+where
+    ShareNameSet: ToAssign,
+    FilePathSet: ToAssign,
+    RangeSet: ToAssign,
+    BodySet: ToAssign,
+    C: Client,
+{
+    type O = PutRangeBuilder<'a, C, ShareNameSet, FilePathSet, RangeSet, BodySet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutRangeBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            p_file_path: PhantomData {},
+            p_range: PhantomData {},
+            p_body: PhantomData {},
+            share_name: self.share_name,
+            file_path: self.file_path,
+            range: self.range,
+            body: self.body,
+            content_md5: self.content_md5,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> PutRangeBuilder<'a, C, Yes, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<PutRangeResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            Some(self.file_path()),
+            Some("comp=range"),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = RangeRequired::add_header(&self, request);
+                request = request.header(FILE_WRITE, "update");
+                request = ContentMD5Option::add_header(&self, request);
+                request = ClientRequestIdOption::add_header(&self, request);
+                request
+            },
+            Some(self.body()),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        PutRangeResponse::from_headers(&headers)
+    }
+}
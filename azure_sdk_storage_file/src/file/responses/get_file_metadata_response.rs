@@ -0,0 +1,36 @@
+use crate::file::metadata_from_headers;
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetFileMetadataResponse {
+    pub metadata: HashMap<String, String>,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl GetFileMetadataResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<GetFileMetadataResponse, AzureError> {
+        let metadata = metadata_from_headers(headers)?;
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(GetFileMetadataResponse {
+            metadata,
+            etag,
+            last_modified,
+            request_id,
+            date,
+        })
+    }
+}
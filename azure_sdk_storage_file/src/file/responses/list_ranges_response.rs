@@ -0,0 +1,44 @@
+use crate::file::{file_content_length_from_headers, ranges_from_response};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{
+    date_from_headers, etag_from_headers, last_modified_from_headers, request_id_from_headers,
+    RequestId,
+};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListRangesResponse {
+    /// The ranges holding data. The ranges not listed read as zeros.
+    pub ranges: Vec<Range>,
+    /// The size of the whole file, in bytes.
+    pub content_length: u64,
+    pub etag: String,
+    pub last_modified: DateTime<Utc>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl ListRangesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<ListRangesResponse, AzureError> {
+        let ranges = ranges_from_response(body)?;
+        let content_length = file_content_length_from_headers(headers)?;
+        let etag = etag_from_headers(headers)?;
+        let last_modified = last_modified_from_headers(headers)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(ListRangesResponse {
+            ranges,
+            content_length,
+            etag,
+            last_modified,
+            request_id,
+            date,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+mod clear_range_response;
+pub use self::clear_range_response::ClearRangeResponse;
+mod create_file_response;
+pub use self::create_file_response::CreateFileResponse;
+mod delete_file_response;
+pub use self::delete_file_response::DeleteFileResponse;
+mod get_file_metadata_response;
+pub use self::get_file_metadata_response::GetFileMetadataResponse;
+mod get_range_response;
+pub use self::get_range_response::GetRangeResponse;
+mod list_ranges_response;
+pub use self::list_ranges_response::ListRangesResponse;
+mod put_range_response;
+pub use self::put_range_response::PutRangeResponse;
+mod set_file_metadata_response;
+pub use self::set_file_metadata_response::SetFileMetadataResponse;
@@ -0,0 +1,9 @@
+use azure_sdk_core::RequestId;
+use chrono::{DateTime, Utc};
+
+response_from_headers!(SetFileMetadataResponse,
+               etag_from_headers -> etag: String,
+               request_id_from_headers -> request_id: RequestId,
+               date_from_headers -> date: DateTime<Utc>,
+               request_server_encrypted_from_headers -> request_server_encrypted: bool
+);
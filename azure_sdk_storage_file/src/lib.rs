@@ -0,0 +1,116 @@
+#![recursion_limit = "128"]
+#![allow(clippy::needless_lifetimes)]
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate azure_sdk_core;
+pub mod directory;
+pub mod file;
+pub mod prelude;
+pub mod share;
+use azure_sdk_core::No;
+use azure_sdk_storage_core::Client;
+
+pub trait Share<C>
+where
+    C: Client,
+{
+    fn create_share<'a>(&'a self) -> share::requests::CreateBuilder<'a, C, No>;
+    fn delete_share<'a>(&'a self) -> share::requests::DeleteBuilder<'a, C, No>;
+    fn list_shares<'a>(&'a self) -> share::requests::ListBuilder<'a, C>;
+}
+
+pub trait Directory<C>
+where
+    C: Client,
+{
+    fn create_directory<'a>(&'a self) -> directory::requests::CreateBuilder<'a, C, No, No>;
+    fn delete_directory<'a>(&'a self) -> directory::requests::DeleteBuilder<'a, C, No, No>;
+    fn list_directories_and_files<'a>(&'a self) -> directory::requests::ListBuilder<'a, C, No>;
+}
+
+pub trait File<C>
+where
+    C: Client,
+{
+    fn create_file<'a>(&'a self) -> file::requests::CreateFileBuilder<'a, C, No, No, No>;
+    fn delete_file<'a>(&'a self) -> file::requests::DeleteFileBuilder<'a, C, No, No>;
+    fn put_range<'a>(&'a self) -> file::requests::PutRangeBuilder<'a, C, No, No, No, No>;
+    fn clear_range<'a>(&'a self) -> file::requests::ClearRangeBuilder<'a, C, No, No, No>;
+    fn get_range<'a>(&'a self) -> file::requests::GetRangeBuilder<'a, C, No, No>;
+    fn list_ranges<'a>(&'a self) -> file::requests::ListRangesBuilder<'a, C, No, No>;
+    fn get_file_metadata<'a>(&'a self) -> file::requests::GetFileMetadataBuilder<'a, C, No, No>;
+    fn set_file_metadata<'a>(&'a self) -> file::requests::SetFileMetadataBuilder<'a, C, No, No>;
+}
+
+impl<C> Share<C> for C
+where
+    C: Client,
+{
+    fn create_share<'a>(&'a self) -> share::requests::CreateBuilder<'a, C, No> {
+        share::requests::CreateBuilder::new(self)
+    }
+
+    fn delete_share<'a>(&'a self) -> share::requests::DeleteBuilder<'a, C, No> {
+        share::requests::DeleteBuilder::new(self)
+    }
+
+    fn list_shares<'a>(&'a self) -> share::requests::ListBuilder<'a, C> {
+        share::requests::ListBuilder::new(self)
+    }
+}
+
+impl<C> Directory<C> for C
+where
+    C: Client,
+{
+    fn create_directory<'a>(&'a self) -> directory::requests::CreateBuilder<'a, C, No, No> {
+        directory::requests::CreateBuilder::new(self)
+    }
+
+    fn delete_directory<'a>(&'a self) -> directory::requests::DeleteBuilder<'a, C, No, No> {
+        directory::requests::DeleteBuilder::new(self)
+    }
+
+    fn list_directories_and_files<'a>(&'a self) -> directory::requests::ListBuilder<'a, C, No> {
+        directory::requests::ListBuilder::new(self)
+    }
+}
+
+impl<C> File<C> for C
+where
+    C: Client,
+{
+    fn create_file<'a>(&'a self) -> file::requests::CreateFileBuilder<'a, C, No, No, No> {
+        file::requests::CreateFileBuilder::new(self)
+    }
+
+    fn delete_file<'a>(&'a self) -> file::requests::DeleteFileBuilder<'a, C, No, No> {
+        file::requests::DeleteFileBuilder::new(self)
+    }
+
+    fn put_range<'a>(&'a self) -> file::requests::PutRangeBuilder<'a, C, No, No, No, No> {
+        file::requests::PutRangeBuilder::new(self)
+    }
+
+    fn clear_range<'a>(&'a self) -> file::requests::ClearRangeBuilder<'a, C, No, No, No> {
+        file::requests::ClearRangeBuilder::new(self)
+    }
+
+    fn get_range<'a>(&'a self) -> file::requests::GetRangeBuilder<'a, C, No, No> {
+        file::requests::GetRangeBuilder::new(self)
+    }
+
+    fn list_ranges<'a>(&'a self) -> file::requests::ListRangesBuilder<'a, C, No, No> {
+        file::requests::ListRangesBuilder::new(self)
+    }
+
+    fn get_file_metadata<'a>(&'a self) -> file::requests::GetFileMetadataBuilder<'a, C, No, No> {
+        file::requests::GetFileMetadataBuilder::new(self)
+    }
+
+    fn set_file_metadata<'a>(&'a self) -> file::requests::SetFileMetadataBuilder<'a, C, No, No> {
+        file::requests::SetFileMetadataBuilder::new(self)
+    }
+}
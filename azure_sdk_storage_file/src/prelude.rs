@@ -0,0 +1,6 @@
+pub use crate::directory::{DirectoryPathOption, DirectoryPathRequired, DirectoryPathSupport};
+pub use crate::file::{FilePathRequired, FilePathSupport};
+pub use crate::share::{ShareQuotaOption, ShareQuotaSupport};
+pub use crate::Directory as DirectoryTrait;
+pub use crate::File as FileTrait;
+pub use crate::Share as ShareTrait;
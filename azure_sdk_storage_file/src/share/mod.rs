@@ -0,0 +1,203 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::SHARE_QUOTA;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use azure_sdk_storage_core::prelude::*;
+use chrono::{DateTime, Utc};
+use http::request::Builder;
+use std::collections::HashMap;
+use url::form_urlencoded;
+use xml::{Element, Xml};
+
+pub trait ShareQuotaSupport {
+    type O;
+    fn with_quota(self, quota: u64) -> Self::O;
+}
+
+pub trait ShareQuotaOption {
+    fn quota(&self) -> Option<u64>;
+
+    #[must_use]
+    fn add_header(&self, mut builder: Builder) -> Builder {
+        if let Some(quota) = self.quota() {
+            builder = builder.header(SHARE_QUOTA, &quota.to_string() as &str);
+        }
+        builder
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Share {
+    pub name: String,
+    pub last_modified: DateTime<Utc>,
+    pub etag: String,
+    /// Maximum size of the share, in GiB.
+    pub quota: u64,
+    pub metadata: HashMap<String, String>,
+}
+
+impl AsRef<str> for Share {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Share {
+    fn parse(elem: &Element) -> Result<Share, AzureError> {
+        let name = cast_must::<String>(elem, &["Name"])?;
+        let last_modified = cast_must::<DateTime<Utc>>(elem, &["Properties", "Last-Modified"])?;
+        let etag = cast_must::<String>(elem, &["Properties", "Etag"])?;
+        let quota = cast_must::<u64>(elem, &["Properties", "Quota"])?;
+
+        let mut metadata = HashMap::new();
+        for m in traverse(elem, &["Metadata"], true)? {
+            for key in &m.children {
+                // skip the whitespace between the nodes
+                let elem = match key {
+                    Xml::ElementNode(elem) => elem,
+                    _ => continue,
+                };
+
+                let content = match elem.children.first() {
+                    Some(Xml::CharacterNode(content)) => content.to_owned(),
+                    None => String::new(),
+                    _ => {
+                        return Err(AzureError::UnexpectedXMLError(String::from(
+                            "Metadata node should contain a CharacterNode with metadata value",
+                        )));
+                    }
+                };
+
+                metadata.insert(elem.name.to_owned(), content);
+            }
+        }
+
+        Ok(Share {
+            name,
+            last_modified,
+            etag,
+            quota,
+            metadata,
+        })
+    }
+}
+
+pub(crate) fn incomplete_vector_from_share_response(
+    body: &str,
+) -> Result<IncompleteVector<Share>, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+
+    for share in traverse(&elem, &["Shares", "Share"], true)? {
+        v.push(Share::parse(share)?);
+    }
+
+    let next_marker = cast_optional::<String>(&elem, &["NextMarker"])?.filter(|nm| !nm.is_empty());
+
+    Ok(IncompleteVector::new(next_marker, v))
+}
+
+fn encode_segment(segment: &str) -> String {
+    // form encoding turns spaces into '+', which the service would
+    // take literally in a path
+    form_urlencoded::byte_serialize(segment.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+/// Builds the uri of a share or, if `path` is specified, of a directory
+/// or file in the share. Every segment of `path` is encoded on its own
+/// so the slashes are preserved.
+#[inline]
+pub(crate) fn generate_share_uri<C>(
+    c: &C,
+    share_name: &str,
+    path: Option<&str>,
+    params: Option<&str>,
+) -> String
+where
+    C: Client,
+{
+    let mut uri = format!("{}/{}", c.file_uri(), encode_segment(share_name));
+
+    if let Some(path) = path {
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            uri = format!("{}/{}", uri, encode_segment(segment));
+        }
+    }
+
+    if let Some(params) = params {
+        uri = format!("{}?{}", uri, params);
+    }
+
+    uri
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_list_shares_response() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ServiceEndpoint="https://myaccount.file.core.windows.net/">
+  <Prefix>s</Prefix>
+  <MaxResults>2</MaxResults>
+  <Shares>
+    <Share>
+      <Name>share1</Name>
+      <Properties>
+        <Last-Modified>Wed, 23 Oct 2013 20:39:39 GMT</Last-Modified>
+        <Etag>0x8CACB9BD7C6B1B2</Etag>
+        <Quota>55</Quota>
+      </Properties>
+      <Metadata>
+        <Color>red</Color>
+      </Metadata>
+    </Share>
+    <Share>
+      <Name>share2</Name>
+      <Properties>
+        <Last-Modified>Wed, 23 Oct 2013 20:39:39 GMT</Last-Modified>
+        <Etag>0x8CACB9BD7C1EEEC</Etag>
+        <Quota>5120</Quota>
+      </Properties>
+    </Share>
+  </Shares>
+  <NextMarker>/myaccount/share3</NextMarker>
+</EnumerationResults>"#;
+
+        let iv = incomplete_vector_from_share_response(body).unwrap();
+        assert_eq!(iv.token(), Some("/myaccount/share3"));
+        assert_eq!(iv.len(), 2);
+        assert_eq!(iv[0].name, "share1");
+        assert_eq!(iv[0].quota, 55);
+        assert_eq!(iv[0].etag, "0x8CACB9BD7C6B1B2");
+        assert_eq!(iv[0].metadata.get("Color"), Some(&"red".to_owned()));
+        assert_eq!(iv[1].name, "share2");
+        assert_eq!(iv[1].quota, 5120);
+        assert!(iv[1].metadata.is_empty());
+    }
+
+    #[test]
+    fn share_uri_keeps_path_separators() {
+        let client = client::with_access_key("myaccount", "");
+        assert_eq!(
+            generate_share_uri(
+                &client,
+                "share",
+                Some("dir one/file+1.txt"),
+                Some("comp=range")
+            ),
+            "https://myaccount.file.core.windows.net/share/dir%20one/file%2B1.txt?comp=range"
+        );
+        assert_eq!(
+            generate_share_uri(&client, "share", None, Some("restype=share")),
+            "https://myaccount.file.core.windows.net/share?restype=share"
+        );
+    }
+}
@@ -0,0 +1,252 @@
+use crate::share::generate_share_uri;
+use crate::share::responses::CreateShareResponse;
+use crate::share::{ShareQuotaOption, ShareQuotaSupport};
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_share_name: PhantomData<ShareNameSet>,
+    share_name: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    quota: Option<u64>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreateBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreateBuilder<'a, C, No> {
+        CreateBuilder {
+            client,
+            p_share_name: PhantomData {},
+            share_name: None,
+            metadata: None,
+            quota: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequired<'a, C> for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> ShareNameRequired<'a> for CreateBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn share_name(&self) -> &'a str {
+        self.share_name.unwrap()
+    }
+}
+
+impl<'a, C, ShareNameSet> MetadataOption<'a> for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, ShareNameSet> ShareQuotaOption for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn quota(&self) -> Option<u64> {
+        self.quota
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequestIdOption<'a> for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, ShareNameSet> TimeoutOption for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> ShareNameSupport<'a> for CreateBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_share_name(self, share_name: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: Some(share_name),
+            metadata: self.metadata,
+            quota: self.quota,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> MetadataSupport<'a> for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: Some(metadata),
+            quota: self.quota,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> ShareQuotaSupport for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_quota(self, quota: u64) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: self.metadata,
+            quota: Some(quota),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> ClientRequestIdSupport<'a> for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: self.metadata,
+            quota: self.quota,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, ShareNameSet> TimeoutSupport for CreateBuilder<'a, C, ShareNameSet>
+where
+    ShareNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, ShareNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_share_name: PhantomData {},
+            share_name: self.share_name,
+            metadata: self.metadata,
+            quota: self.quota,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreateBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreateShareResponse, AzureError> {
+        let mut uri = generate_share_uri(
+            self.client(),
+            self.share_name(),
+            None,
+            Some("restype=share"),
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = ClientRequestIdOption::add_header(&self, request);
+                request = MetadataOption::add_header(&self, request);
+                request = ShareQuotaOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::CREATED).await?;
+        CreateShareResponse::from_headers(&headers)
+    }
+}
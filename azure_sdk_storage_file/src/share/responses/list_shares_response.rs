@@ -0,0 +1,34 @@
+use crate::share::{incomplete_vector_from_share_response, Share};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSharesResponse {
+    pub incomplete_vector: IncompleteVector<Share>,
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+}
+
+impl ListSharesResponse {
+    pub fn is_complete(&self) -> bool {
+        self.incomplete_vector.is_complete()
+    }
+
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<ListSharesResponse, AzureError> {
+        let incomplete_vector = incomplete_vector_from_share_response(body)?;
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+
+        Ok(ListSharesResponse {
+            incomplete_vector,
+            request_id,
+            date,
+        })
+    }
+}
@@ -0,0 +1,6 @@
+mod create_share_response;
+pub use self::create_share_response::CreateShareResponse;
+mod delete_share_response;
+pub use self::delete_share_response::DeleteShareResponse;
+mod list_shares_response;
+pub use self::list_shares_response::ListSharesResponse;
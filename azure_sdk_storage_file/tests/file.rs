@@ -0,0 +1,200 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::key_client::KeyClient;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_file::prelude::*;
+use futures::stream::StreamExt;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn create_list_and_delete_share() {
+    let share_name: &'static str = "azuresdkrustetoetsshare1";
+
+    let client = initialize();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", "rust");
+
+    client
+        .create_share()
+        .with_share_name(share_name)
+        .with_metadata(&metadata)
+        .with_quota(1)
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut stream = Box::pin(
+        client
+            .list_shares()
+            .with_prefix("azuresdkrustetoets")
+            .with_include_metadata()
+            .with_max_results(1)
+            .stream(),
+    );
+
+    let mut found = false;
+    while let Some(response) = stream.next().await {
+        for share in response.unwrap().incomplete_vector.iter() {
+            if share.name == share_name {
+                assert_eq!(share.quota, 1);
+                assert_eq!(share.metadata.get("source"), Some(&"rust".to_owned()));
+                found = true;
+            }
+        }
+    }
+    assert!(found);
+
+    client
+        .delete_share()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn write_read_and_list_file() {
+    let share_name: &'static str = "azuresdkrustetoetsshare2";
+    let file_path = "dir one/file.bin";
+
+    let client = initialize();
+
+    client
+        .create_share()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_directory()
+        .with_share_name(share_name)
+        .with_directory_path("dir one")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .create_file()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .with_content_length(2048)
+        .finalize()
+        .await
+        .unwrap();
+
+    let data = vec![42u8; 512];
+    let range = Range::new(512, 1024);
+    client
+        .put_range()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .with_range(&range)
+        .with_body(&data)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .list_ranges()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.content_length, 2048);
+    assert_eq!(response.ranges, vec![range]);
+
+    let response = client
+        .get_range()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .with_range(&range)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.data, data);
+
+    client
+        .clear_range()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .with_range(&range)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .list_ranges()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(response.ranges.is_empty());
+
+    let mut metadata = HashMap::new();
+    metadata.insert("color", "red");
+    client
+        .set_file_metadata()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let response = client
+        .get_file_metadata()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(response.metadata.get("color"), Some(&"red".to_owned()));
+
+    let response = client
+        .list_directories_and_files()
+        .with_share_name(share_name)
+        .with_directory_path("dir one")
+        .finalize()
+        .await
+        .unwrap();
+    assert!(response.directories.is_empty());
+    assert_eq!(response.files.len(), 1);
+    assert_eq!(response.files[0].name, "file.bin");
+    assert_eq!(response.files[0].content_length, 2048);
+
+    client
+        .delete_file()
+        .with_share_name(share_name)
+        .with_file_path(file_path)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_directory()
+        .with_share_name(share_name)
+        .with_directory_path("dir one")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .delete_share()
+        .with_share_name(share_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> KeyClient {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    client::with_access_key(&account, &master_key)
+}
@@ -0,0 +1,80 @@
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_queue::prelude::*;
+use std::error::Error;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    // First we retrieve the account name and master key from environment variables.
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    let queue_name = std::env::args()
+        .nth(1)
+        .expect("please specify the queue name as command line parameter");
+
+    let client = client::with_access_key(&account, &master_key);
+
+    client
+        .create_queue()
+        .with_queue_name(&queue_name)
+        .finalize()
+        .await?;
+
+    let response = client
+        .put_message()
+        .with_queue_name(&queue_name)
+        .with_message_text("Hello from Rust!")
+        .with_message_ttl(3600)
+        .finalize()
+        .await?;
+    println!("{:?}", response);
+
+    let response = client
+        .peek_messages()
+        .with_queue_name(&queue_name)
+        .with_number_of_messages(32)
+        .finalize()
+        .await?;
+    println!("peeked {} messages", response.messages.len());
+
+    // get the messages, making them invisible for 30 seconds
+    let response = client
+        .get_messages()
+        .with_queue_name(&queue_name)
+        .with_number_of_messages(32)
+        .with_visibility_timeout(30)
+        .finalize()
+        .await?;
+
+    for message in response.messages.iter() {
+        println!("{} ==> {}", message.message_id, message.message_text);
+
+        client
+            .delete_message()
+            .with_queue_name(&queue_name)
+            .with_message_id(&message.message_id)
+            .with_pop_receipt(&message.pop_receipt)
+            .finalize()
+            .await?;
+    }
+
+    let response = client
+        .list_queues()
+        .with_include_metadata()
+        .finalize()
+        .await?;
+    for queue in response.incomplete_vector.iter() {
+        println!("{} ==> {:?}", queue.name, queue.metadata);
+    }
+
+    client
+        .delete_queue()
+        .with_queue_name(&queue_name)
+        .finalize()
+        .await?;
+
+    Ok(())
+}
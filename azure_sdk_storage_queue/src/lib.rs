@@ -0,0 +1,83 @@
+#![recursion_limit = "128"]
+#![allow(clippy::needless_lifetimes)]
+
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate azure_sdk_core;
+pub mod message;
+pub mod prelude;
+pub mod queue;
+use azure_sdk_core::No;
+use azure_sdk_storage_core::Client;
+
+pub trait Queue<C>
+where
+    C: Client,
+{
+    fn create_queue<'a>(&'a self) -> queue::requests::CreateBuilder<'a, C, No>;
+    fn delete_queue<'a>(&'a self) -> queue::requests::DeleteBuilder<'a, C, No>;
+    fn list_queues<'a>(&'a self) -> queue::requests::ListBuilder<'a, C>;
+}
+
+pub trait Message<C>
+where
+    C: Client,
+{
+    fn put_message<'a>(&'a self) -> message::requests::PutMessageBuilder<'a, C, No, No>;
+    fn get_messages<'a>(&'a self) -> message::requests::GetMessagesBuilder<'a, C, No>;
+    fn peek_messages<'a>(&'a self) -> message::requests::PeekMessagesBuilder<'a, C, No>;
+    fn update_message<'a>(
+        &'a self,
+    ) -> message::requests::UpdateMessageBuilder<'a, C, No, No, No, No>;
+    fn delete_message<'a>(&'a self) -> message::requests::DeleteMessageBuilder<'a, C, No, No, No>;
+    fn clear_messages<'a>(&'a self) -> message::requests::ClearMessagesBuilder<'a, C, No>;
+}
+
+impl<C> Queue<C> for C
+where
+    C: Client,
+{
+    fn create_queue<'a>(&'a self) -> queue::requests::CreateBuilder<'a, C, No> {
+        queue::requests::CreateBuilder::new(self)
+    }
+
+    fn delete_queue<'a>(&'a self) -> queue::requests::DeleteBuilder<'a, C, No> {
+        queue::requests::DeleteBuilder::new(self)
+    }
+
+    fn list_queues<'a>(&'a self) -> queue::requests::ListBuilder<'a, C> {
+        queue::requests::ListBuilder::new(self)
+    }
+}
+
+impl<C> Message<C> for C
+where
+    C: Client,
+{
+    fn put_message<'a>(&'a self) -> message::requests::PutMessageBuilder<'a, C, No, No> {
+        message::requests::PutMessageBuilder::new(self)
+    }
+
+    fn get_messages<'a>(&'a self) -> message::requests::GetMessagesBuilder<'a, C, No> {
+        message::requests::GetMessagesBuilder::new(self)
+    }
+
+    fn peek_messages<'a>(&'a self) -> message::requests::PeekMessagesBuilder<'a, C, No> {
+        message::requests::PeekMessagesBuilder::new(self)
+    }
+
+    fn update_message<'a>(
+        &'a self,
+    ) -> message::requests::UpdateMessageBuilder<'a, C, No, No, No, No> {
+        message::requests::UpdateMessageBuilder::new(self)
+    }
+
+    fn delete_message<'a>(&'a self) -> message::requests::DeleteMessageBuilder<'a, C, No, No, No> {
+        message::requests::DeleteMessageBuilder::new(self)
+    }
+
+    fn clear_messages<'a>(&'a self) -> message::requests::ClearMessagesBuilder<'a, C, No> {
+        message::requests::ClearMessagesBuilder::new(self)
+    }
+}
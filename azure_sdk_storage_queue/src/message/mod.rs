@@ -0,0 +1,321 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::headers::{POP_RECEIPT, TIME_NEXT_VISIBLE};
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_storage_core::prelude::*;
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use url::form_urlencoded;
+use xml::Element;
+
+pub trait MessageTextSupport<'a> {
+    type O;
+    fn with_message_text(self, message_text: &'a str) -> Self::O;
+}
+
+pub trait MessageTextRequired<'a> {
+    fn message_text(&self) -> &'a str;
+
+    fn to_body(&self) -> String {
+        generate_message_body(self.message_text())
+    }
+}
+
+pub trait MessageTextOption<'a> {
+    fn message_text(&self) -> Option<&'a str>;
+
+    fn to_body(&self) -> Option<String> {
+        self.message_text().map(generate_message_body)
+    }
+}
+
+pub trait MessageIdSupport<'a> {
+    type O;
+    fn with_message_id(self, message_id: &'a str) -> Self::O;
+}
+
+pub trait MessageIdRequired<'a> {
+    fn message_id(&self) -> &'a str;
+}
+
+pub trait PopReceiptSupport<'a> {
+    type O;
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O;
+}
+
+pub trait PopReceiptRequired<'a> {
+    fn pop_receipt(&self) -> &'a str;
+
+    fn to_uri_parameter(&self) -> String {
+        format!(
+            "popreceipt={}",
+            form_urlencoded::byte_serialize(self.pop_receipt().as_bytes()).collect::<String>()
+        )
+    }
+}
+
+pub trait VisibilityTimeoutSupport {
+    type O;
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O;
+}
+
+pub trait VisibilityTimeoutOption {
+    fn visibility_timeout(&self) -> Option<u64>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.visibility_timeout()
+            .map(|visibility_timeout| format!("visibilitytimeout={}", visibility_timeout))
+    }
+}
+
+pub trait VisibilityTimeoutRequired {
+    fn visibility_timeout(&self) -> u64;
+
+    fn to_uri_parameter(&self) -> String {
+        format!("visibilitytimeout={}", self.visibility_timeout())
+    }
+}
+
+pub trait MessageTTLSupport {
+    type O;
+    fn with_message_ttl(self, message_ttl: i64) -> Self::O;
+}
+
+pub trait MessageTTLOption {
+    fn message_ttl(&self) -> Option<i64>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.message_ttl()
+            .map(|message_ttl| format!("messagettl={}", message_ttl))
+    }
+}
+
+pub trait NumberOfMessagesSupport {
+    type O;
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O;
+}
+
+pub trait NumberOfMessagesOption {
+    fn number_of_messages(&self) -> Option<u32>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.number_of_messages()
+            .map(|number_of_messages| format!("numofmessages={}", number_of_messages))
+    }
+}
+
+/// A message returned by Put Message. The text is not echoed back by the service.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertedMessage {
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub pop_receipt: String,
+    pub time_next_visible: DateTime<Utc>,
+}
+
+/// A message returned by Get Messages. It stays invisible until `time_next_visible`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueueMessage {
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub pop_receipt: String,
+    pub time_next_visible: DateTime<Utc>,
+    pub dequeue_count: u64,
+    pub message_text: String,
+}
+
+/// A message returned by Peek Messages. Peeking does not change the message visibility
+/// so there is no pop receipt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeekedMessage {
+    pub message_id: String,
+    pub insertion_time: DateTime<Utc>,
+    pub expiration_time: DateTime<Utc>,
+    pub dequeue_count: u64,
+    pub message_text: String,
+}
+
+impl InsertedMessage {
+    fn parse(elem: &Element) -> Result<InsertedMessage, AzureError> {
+        Ok(InsertedMessage {
+            message_id: cast_must::<String>(elem, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(elem, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(elem, &["ExpirationTime"])?,
+            pop_receipt: cast_must::<String>(elem, &["PopReceipt"])?,
+            time_next_visible: cast_must::<DateTime<Utc>>(elem, &["TimeNextVisible"])?,
+        })
+    }
+}
+
+impl QueueMessage {
+    fn parse(elem: &Element) -> Result<QueueMessage, AzureError> {
+        Ok(QueueMessage {
+            message_id: cast_must::<String>(elem, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(elem, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(elem, &["ExpirationTime"])?,
+            pop_receipt: cast_must::<String>(elem, &["PopReceipt"])?,
+            time_next_visible: cast_must::<DateTime<Utc>>(elem, &["TimeNextVisible"])?,
+            dequeue_count: cast_must::<u64>(elem, &["DequeueCount"])?,
+            message_text: cast_optional::<String>(elem, &["MessageText"])?.unwrap_or_default(),
+        })
+    }
+}
+
+impl PeekedMessage {
+    fn parse(elem: &Element) -> Result<PeekedMessage, AzureError> {
+        Ok(PeekedMessage {
+            message_id: cast_must::<String>(elem, &["MessageId"])?,
+            insertion_time: cast_must::<DateTime<Utc>>(elem, &["InsertionTime"])?,
+            expiration_time: cast_must::<DateTime<Utc>>(elem, &["ExpirationTime"])?,
+            dequeue_count: cast_must::<u64>(elem, &["DequeueCount"])?,
+            message_text: cast_optional::<String>(elem, &["MessageText"])?.unwrap_or_default(),
+        })
+    }
+}
+
+fn parse_messages<T, F>(body: &str, parse: F) -> Result<Vec<T>, AzureError>
+where
+    F: Fn(&Element) -> Result<T, AzureError>,
+{
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+    for message in traverse(&elem, &["QueueMessage"], true)? {
+        v.push(parse(message)?);
+    }
+    Ok(v)
+}
+
+pub(crate) fn inserted_messages_from_response(
+    body: &str,
+) -> Result<Vec<InsertedMessage>, AzureError> {
+    parse_messages(body, InsertedMessage::parse)
+}
+
+pub(crate) fn queue_messages_from_response(body: &str) -> Result<Vec<QueueMessage>, AzureError> {
+    parse_messages(body, QueueMessage::parse)
+}
+
+pub(crate) fn peeked_messages_from_response(body: &str) -> Result<Vec<PeekedMessage>, AzureError> {
+    parse_messages(body, PeekedMessage::parse)
+}
+
+#[inline]
+pub(crate) fn generate_message_uri<C>(
+    c: &C,
+    queue_name: &str,
+    message_id: Option<&str>,
+    params: &[String],
+) -> String
+where
+    C: Client,
+{
+    let mut uri = format!(
+        "{}/{}/messages",
+        c.queue_uri(),
+        form_urlencoded::byte_serialize(queue_name.as_bytes()).collect::<String>()
+    );
+
+    if let Some(message_id) = message_id {
+        uri = format!(
+            "{}/{}",
+            uri,
+            form_urlencoded::byte_serialize(message_id.as_bytes()).collect::<String>()
+        );
+    }
+
+    if !params.is_empty() {
+        uri = format!("{}?{}", uri, params.join("&"));
+    }
+
+    uri
+}
+
+pub(crate) fn generate_message_body(message_text: &str) -> String {
+    format!(
+        "<QueueMessage><MessageText>{}</MessageText></QueueMessage>",
+        xml::escape(message_text)
+    )
+}
+
+pub(crate) fn pop_receipt_from_headers(headers: &HeaderMap) -> Result<String, AzureError> {
+    let pop_receipt = headers
+        .get_as_str(POP_RECEIPT)
+        .ok_or_else(|| AzureError::HeaderNotFound(POP_RECEIPT.to_owned()))?;
+    Ok(pop_receipt.to_owned())
+}
+
+pub(crate) fn time_next_visible_from_headers(
+    headers: &HeaderMap,
+) -> Result<DateTime<Utc>, AzureError> {
+    let time_next_visible = headers
+        .get_as_str(TIME_NEXT_VISIBLE)
+        .ok_or_else(|| AzureError::HeaderNotFound(TIME_NEXT_VISIBLE.to_owned()))?;
+    Ok(DateTime::parse_from_rfc2822(time_next_visible)?.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_get_messages_response() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <QueueMessagesList><QueueMessage>\
+            <MessageId>5974b586-0df3-4e2d-ad0c-18e3892bfca2</MessageId>\
+            <InsertionTime>Fri, 09 Oct 2009 21:04:30 GMT</InsertionTime>\
+            <ExpirationTime>Fri, 16 Oct 2009 21:04:30 GMT</ExpirationTime>\
+            <PopReceipt>YzQ4Yzg1MDItYTc0Ny00OWNjLTkxYTUtZGM0MDFiZDAwYzEw</PopReceipt>\
+            <TimeNextVisible>Fri, 09 Oct 2009 23:29:20 GMT</TimeNextVisible>\
+            <DequeueCount>1</DequeueCount>\
+            <MessageText>a &lt;b&gt; &amp; c</MessageText>\
+            </QueueMessage></QueueMessagesList>";
+
+        let messages = queue_messages_from_response(body).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].message_id,
+            "5974b586-0df3-4e2d-ad0c-18e3892bfca2"
+        );
+        assert_eq!(
+            messages[0].pop_receipt,
+            "YzQ4Yzg1MDItYTc0Ny00OWNjLTkxYTUtZGM0MDFiZDAwYzEw"
+        );
+        assert_eq!(messages[0].dequeue_count, 1);
+        assert_eq!(messages[0].message_text, "a <b> & c");
+        assert_eq!(
+            messages[0].time_next_visible,
+            DateTime::parse_from_rfc2822("Fri, 09 Oct 2009 23:29:20 GMT").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_peek_messages_response() {
+        let body = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <QueueMessagesList><QueueMessage>\
+            <MessageId>5974b586-0df3-4e2d-ad0c-18e3892bfca2</MessageId>\
+            <InsertionTime>Fri, 09 Oct 2009 21:04:30 GMT</InsertionTime>\
+            <ExpirationTime>Fri, 16 Oct 2009 21:04:30 GMT</ExpirationTime>\
+            <DequeueCount>0</DequeueCount>\
+            <MessageText></MessageText>\
+            </QueueMessage></QueueMessagesList>";
+
+        let messages = peeked_messages_from_response(body).unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].dequeue_count, 0);
+        assert_eq!(messages[0].message_text, "");
+    }
+
+    #[test]
+    fn message_body_is_escaped() {
+        assert_eq!(
+            generate_message_body("<hello & goodbye>"),
+            "<QueueMessage><MessageText>&lt;hello &amp; goodbye&gt;</MessageText></QueueMessage>"
+        );
+    }
+}
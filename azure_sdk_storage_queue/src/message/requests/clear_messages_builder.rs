@@ -0,0 +1,166 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::ClearMessagesResponse;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> ClearMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> ClearMessagesBuilder<'a, C, No> {
+        ClearMessagesBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequired<'a, C> for ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> QueueNameRequired<'a> for ClearMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdOption<'a> for ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutOption for ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> QueueNameSupport<'a> for ClearMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = ClearMessagesBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdSupport<'a> for ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = ClearMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutSupport for ClearMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = ClearMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ClearMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> ClearMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<ClearMessagesResponse, AzureError> {
+        let mut params = Vec::new();
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(self.client(), self.queue_name(), None, &params);
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, _) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        ClearMessagesResponse::from_headers(&headers)
+    }
+}
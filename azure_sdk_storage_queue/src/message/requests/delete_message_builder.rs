@@ -0,0 +1,291 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::DeleteMessageResponse;
+use crate::prelude::*;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_id: PhantomData<MessageIdSet>,
+    p_pop_receipt: PhantomData<PopReceiptSet>,
+    queue_name: Option<&'a str>,
+    message_id: Option<&'a str>,
+    pop_receipt: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> DeleteMessageBuilder<'a, C, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> DeleteMessageBuilder<'a, C, No, No, No> {
+        DeleteMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_id: PhantomData {},
+            message_id: None,
+            p_pop_receipt: PhantomData {},
+            pop_receipt: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequired<'a, C>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, MessageIdSet, PopReceiptSet> QueueNameRequired<'a>
+    for DeleteMessageBuilder<'a, C, Yes, MessageIdSet, PopReceiptSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, PopReceiptSet> MessageIdRequired<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, Yes, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn message_id(&self) -> &'a str {
+        self.message_id.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet> PopReceiptRequired<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, Yes>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn pop_receipt(&self) -> &'a str {
+        self.pop_receipt.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequestIdOption<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> TimeoutOption
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, MessageIdSet, PopReceiptSet> QueueNameSupport<'a>
+    for DeleteMessageBuilder<'a, C, No, MessageIdSet, PopReceiptSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteMessageBuilder<'a, C, Yes, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: Some(queue_name),
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, PopReceiptSet> MessageIdSupport<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, No, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteMessageBuilder<'a, C, QueueNameSet, Yes, PopReceiptSet>;
+
+    #[inline]
+    fn with_message_id(self, message_id: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: Some(message_id),
+            pop_receipt: self.pop_receipt,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet> PopReceiptSupport<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, No>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, Yes>;
+
+    #[inline]
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: Some(pop_receipt),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> ClientRequestIdSupport<'a>
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> TimeoutSupport
+    for DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    type O = DeleteMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        DeleteMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> DeleteMessageBuilder<'a, C, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<DeleteMessageResponse, AzureError> {
+        let mut params = vec![PopReceiptRequired::to_uri_parameter(&self)];
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(
+            self.client(),
+            self.queue_name(),
+            Some(self.message_id()),
+            &params,
+        );
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::DELETE,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, _) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        DeleteMessageResponse::from_headers(&headers)
+    }
+}
@@ -0,0 +1,250 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::GetMessagesResponse;
+use crate::prelude::*;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::retry_policy::NonRetriable;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    number_of_messages: Option<u32>,
+    visibility_timeout: Option<u64>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> GetMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> GetMessagesBuilder<'a, C, No> {
+        GetMessagesBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            number_of_messages: None,
+            visibility_timeout: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequired<'a, C> for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> QueueNameRequired<'a> for GetMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet> NumberOfMessagesOption for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn number_of_messages(&self) -> Option<u32> {
+        self.number_of_messages
+    }
+}
+
+impl<'a, C, QueueNameSet> VisibilityTimeoutOption for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> Option<u64> {
+        self.visibility_timeout
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdOption<'a> for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutOption for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> QueueNameSupport<'a> for GetMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = GetMessagesBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> NumberOfMessagesSupport for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: Some(number_of_messages),
+            visibility_timeout: self.visibility_timeout,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> VisibilityTimeoutSupport for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: Some(visibility_timeout),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdSupport<'a> for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutSupport for GetMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = GetMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        GetMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            visibility_timeout: self.visibility_timeout,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> GetMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<GetMessagesResponse, AzureError> {
+        let mut params = Vec::new();
+        if let Some(nm) = NumberOfMessagesOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(self.client(), self.queue_name(), None, &params);
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            // dequeuing hides the messages: a replayed request would lose them
+            &|request| ClientRequestIdOption::add_header(&self, request).extension(NonRetriable),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        GetMessagesResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,12 @@
+mod clear_messages_builder;
+pub use self::clear_messages_builder::ClearMessagesBuilder;
+mod delete_message_builder;
+pub use self::delete_message_builder::DeleteMessageBuilder;
+mod get_messages_builder;
+pub use self::get_messages_builder::GetMessagesBuilder;
+mod peek_messages_builder;
+pub use self::peek_messages_builder::PeekMessagesBuilder;
+mod put_message_builder;
+pub use self::put_message_builder::PutMessageBuilder;
+mod update_message_builder;
+pub use self::update_message_builder::UpdateMessageBuilder;
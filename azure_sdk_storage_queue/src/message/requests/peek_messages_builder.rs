@@ -0,0 +1,207 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::PeekMessagesResponse;
+use crate::prelude::*;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    number_of_messages: Option<u32>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> PeekMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> PeekMessagesBuilder<'a, C, No> {
+        PeekMessagesBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            number_of_messages: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequired<'a, C> for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> QueueNameRequired<'a> for PeekMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet> NumberOfMessagesOption for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn number_of_messages(&self) -> Option<u32> {
+        self.number_of_messages
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdOption<'a> for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutOption for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> QueueNameSupport<'a> for PeekMessagesBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = PeekMessagesBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        PeekMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            number_of_messages: self.number_of_messages,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> NumberOfMessagesSupport for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = PeekMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_number_of_messages(self, number_of_messages: u32) -> Self::O {
+        PeekMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: Some(number_of_messages),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdSupport<'a> for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = PeekMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PeekMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutSupport for PeekMessagesBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = PeekMessagesBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PeekMessagesBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            number_of_messages: self.number_of_messages,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> PeekMessagesBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<PeekMessagesResponse, AzureError> {
+        let mut params = vec!["peekonly=true".to_owned()];
+        if let Some(nm) = NumberOfMessagesOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(self.client(), self.queue_name(), None, &params);
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::GET,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            None,
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::OK)
+                .await?;
+        PeekMessagesResponse::from_response(&headers, &body)
+    }
+}
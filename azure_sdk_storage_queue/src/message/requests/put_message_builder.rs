@@ -0,0 +1,319 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::PutMessageResponse;
+use crate::prelude::*;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body_as_string, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_text: PhantomData<MessageTextSet>,
+    queue_name: Option<&'a str>,
+    message_text: Option<&'a str>,
+    visibility_timeout: Option<u64>,
+    message_ttl: Option<i64>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> PutMessageBuilder<'a, C, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> PutMessageBuilder<'a, C, No, No> {
+        PutMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_text: PhantomData {},
+            message_text: None,
+            visibility_timeout: None,
+            message_ttl: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> ClientRequired<'a, C>
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, MessageTextSet> QueueNameRequired<'a> for PutMessageBuilder<'a, C, Yes, MessageTextSet>
+where
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet> MessageTextRequired<'a> for PutMessageBuilder<'a, C, QueueNameSet, Yes>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn message_text(&self) -> &'a str {
+        self.message_text.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> VisibilityTimeoutOption
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> Option<u64> {
+        self.visibility_timeout
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> MessageTTLOption
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn message_ttl(&self) -> Option<i64> {
+        self.message_ttl
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> ClientRequestIdOption<'a>
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> TimeoutOption
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, MessageTextSet> QueueNameSupport<'a> for PutMessageBuilder<'a, C, No, MessageTextSet>
+where
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, Yes, MessageTextSet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: Some(queue_name),
+            message_text: self.message_text,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> MessageTextSupport<'a> for PutMessageBuilder<'a, C, QueueNameSet, No>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, QueueNameSet, Yes>;
+
+    #[inline]
+    fn with_message_text(self, message_text: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: self.queue_name,
+            message_text: Some(message_text),
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> VisibilityTimeoutSupport
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: self.queue_name,
+            message_text: self.message_text,
+            visibility_timeout: Some(visibility_timeout),
+            message_ttl: self.message_ttl,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> MessageTTLSupport
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>;
+
+    #[inline]
+    fn with_message_ttl(self, message_ttl: i64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: self.queue_name,
+            message_text: self.message_text,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: Some(message_ttl),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> ClientRequestIdSupport<'a>
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: self.queue_name,
+            message_text: self.message_text,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageTextSet> TimeoutSupport
+    for PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>
+where
+    QueueNameSet: ToAssign,
+    MessageTextSet: ToAssign,
+    C: Client,
+{
+    type O = PutMessageBuilder<'a, C, QueueNameSet, MessageTextSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        PutMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_text: PhantomData {},
+            queue_name: self.queue_name,
+            message_text: self.message_text,
+            visibility_timeout: self.visibility_timeout,
+            message_ttl: self.message_ttl,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> PutMessageBuilder<'a, C, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<PutMessageResponse, AzureError> {
+        let mut params = Vec::new();
+        if let Some(nm) = VisibilityTimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = MessageTTLOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(self.client(), self.queue_name(), None, &params);
+        trace!("uri == {:?}", uri);
+
+        let body = self.to_body();
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::POST,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            Some(body.as_bytes()),
+        )?;
+
+        let (headers, body) =
+            check_status_extract_headers_and_body_as_string(future_response, StatusCode::CREATED)
+                .await?;
+        PutMessageResponse::from_response(&headers, &body)
+    }
+}
@@ -0,0 +1,448 @@
+use crate::message::generate_message_uri;
+use crate::message::responses::UpdateMessageResponse;
+use crate::prelude::*;
+use azure_sdk_core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct UpdateMessageBuilder<
+    'a,
+    C,
+    QueueNameSet,
+    MessageIdSet,
+    PopReceiptSet,
+    VisibilityTimeoutSet,
+> where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    p_message_id: PhantomData<MessageIdSet>,
+    p_pop_receipt: PhantomData<PopReceiptSet>,
+    p_visibility_timeout: PhantomData<VisibilityTimeoutSet>,
+    queue_name: Option<&'a str>,
+    message_id: Option<&'a str>,
+    pop_receipt: Option<&'a str>,
+    visibility_timeout: Option<u64>,
+    message_text: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> UpdateMessageBuilder<'a, C, No, No, No, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> UpdateMessageBuilder<'a, C, No, No, No, No> {
+        UpdateMessageBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            p_message_id: PhantomData {},
+            message_id: None,
+            p_pop_receipt: PhantomData {},
+            pop_receipt: None,
+            p_visibility_timeout: PhantomData {},
+            visibility_timeout: None,
+            message_text: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> ClientRequired<'a, C>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> QueueNameRequired<'a>
+    for UpdateMessageBuilder<'a, C, Yes, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, PopReceiptSet, VisibilityTimeoutSet> MessageIdRequired<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, Yes, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn message_id(&self) -> &'a str {
+        self.message_id.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, VisibilityTimeoutSet> PopReceiptRequired<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, Yes, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn pop_receipt(&self) -> &'a str {
+        self.pop_receipt.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> VisibilityTimeoutRequired
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, Yes>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn visibility_timeout(&self) -> u64 {
+        self.visibility_timeout.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> MessageTextOption<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn message_text(&self) -> Option<&'a str> {
+        self.message_text
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+    ClientRequestIdOption<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> TimeoutOption
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> QueueNameSupport<'a>
+    for UpdateMessageBuilder<'a, C, No, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<'a, C, Yes, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: Some(queue_name),
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_text: self.message_text,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, PopReceiptSet, VisibilityTimeoutSet> MessageIdSupport<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, No, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<'a, C, QueueNameSet, Yes, PopReceiptSet, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_message_id(self, message_id: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: Some(message_id),
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_text: self.message_text,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, VisibilityTimeoutSet> PopReceiptSupport<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, No, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, Yes, VisibilityTimeoutSet>;
+
+    #[inline]
+    fn with_pop_receipt(self, pop_receipt: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: Some(pop_receipt),
+            visibility_timeout: self.visibility_timeout,
+            message_text: self.message_text,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet> VisibilityTimeoutSupport
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, No>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, Yes>;
+
+    #[inline]
+    fn with_visibility_timeout(self, visibility_timeout: u64) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: Some(visibility_timeout),
+            message_text: self.message_text,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> MessageTextSupport<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<
+        'a,
+        C,
+        QueueNameSet,
+        MessageIdSet,
+        PopReceiptSet,
+        VisibilityTimeoutSet,
+    >;
+
+    #[inline]
+    fn with_message_text(self, message_text: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_text: Some(message_text),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+    ClientRequestIdSupport<'a>
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<
+        'a,
+        C,
+        QueueNameSet,
+        MessageIdSet,
+        PopReceiptSet,
+        VisibilityTimeoutSet,
+    >;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_text: self.message_text,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet> TimeoutSupport
+    for UpdateMessageBuilder<'a, C, QueueNameSet, MessageIdSet, PopReceiptSet, VisibilityTimeoutSet>
+where
+    QueueNameSet: ToAssign,
+    MessageIdSet: ToAssign,
+    PopReceiptSet: ToAssign,
+    VisibilityTimeoutSet: ToAssign,
+    C: Client,
+{
+    type O = UpdateMessageBuilder<
+        'a,
+        C,
+        QueueNameSet,
+        MessageIdSet,
+        PopReceiptSet,
+        VisibilityTimeoutSet,
+    >;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        UpdateMessageBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            p_message_id: PhantomData {},
+            p_pop_receipt: PhantomData {},
+            p_visibility_timeout: PhantomData {},
+            queue_name: self.queue_name,
+            message_id: self.message_id,
+            pop_receipt: self.pop_receipt,
+            visibility_timeout: self.visibility_timeout,
+            message_text: self.message_text,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> UpdateMessageBuilder<'a, C, Yes, Yes, Yes, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<UpdateMessageResponse, AzureError> {
+        let mut params = vec![
+            PopReceiptRequired::to_uri_parameter(&self),
+            VisibilityTimeoutRequired::to_uri_parameter(&self),
+        ];
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            params.push(nm);
+        }
+
+        let uri = generate_message_uri(
+            self.client(),
+            self.queue_name(),
+            Some(self.message_id()),
+            &params,
+        );
+        trace!("uri == {:?}", uri);
+
+        // the message text is optional: without it only the
+        // visibility timeout is updated
+        let body = MessageTextOption::to_body(&self);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|request| ClientRequestIdOption::add_header(&self, request),
+            Some(body.as_ref().map(|b| b.as_bytes()).unwrap_or(&[])),
+        )?;
+
+        let (headers, _) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+        UpdateMessageResponse::from_headers(&headers)
+    }
+}
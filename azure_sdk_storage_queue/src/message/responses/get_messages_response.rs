@@ -0,0 +1,29 @@
+use crate::message::{queue_messages_from_response, QueueMessage};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetMessagesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub messages: Vec<QueueMessage>,
+}
+
+impl GetMessagesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<GetMessagesResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let messages = queue_messages_from_response(body)?;
+
+        Ok(GetMessagesResponse {
+            request_id,
+            date,
+            messages,
+        })
+    }
+}
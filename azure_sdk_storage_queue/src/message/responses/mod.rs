@@ -0,0 +1,12 @@
+mod clear_messages_response;
+pub use self::clear_messages_response::ClearMessagesResponse;
+mod delete_message_response;
+pub use self::delete_message_response::DeleteMessageResponse;
+mod get_messages_response;
+pub use self::get_messages_response::GetMessagesResponse;
+mod peek_messages_response;
+pub use self::peek_messages_response::PeekMessagesResponse;
+mod put_message_response;
+pub use self::put_message_response::PutMessageResponse;
+mod update_message_response;
+pub use self::update_message_response::UpdateMessageResponse;
@@ -0,0 +1,29 @@
+use crate::message::{peeked_messages_from_response, PeekedMessage};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeekMessagesResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub messages: Vec<PeekedMessage>,
+}
+
+impl PeekMessagesResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<PeekMessagesResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let messages = peeked_messages_from_response(body)?;
+
+        Ok(PeekMessagesResponse {
+            request_id,
+            date,
+            messages,
+        })
+    }
+}
@@ -0,0 +1,36 @@
+use crate::message::{inserted_messages_from_response, InsertedMessage};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PutMessageResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub message: InsertedMessage,
+}
+
+impl PutMessageResponse {
+    pub(crate) fn from_response(
+        headers: &HeaderMap,
+        body: &str,
+    ) -> Result<PutMessageResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let message = inserted_messages_from_response(body)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                AzureError::UnexpectedXMLError(String::from(
+                    "Put Message response should contain a QueueMessage",
+                ))
+            })?;
+
+        Ok(PutMessageResponse {
+            request_id,
+            date,
+            message,
+        })
+    }
+}
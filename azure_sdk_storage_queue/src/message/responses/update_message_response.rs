@@ -0,0 +1,29 @@
+use crate::message::{pop_receipt_from_headers, time_next_visible_from_headers};
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::{date_from_headers, request_id_from_headers, RequestId};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateMessageResponse {
+    pub request_id: RequestId,
+    pub date: DateTime<Utc>,
+    pub pop_receipt: String,
+    pub time_next_visible: DateTime<Utc>,
+}
+
+impl UpdateMessageResponse {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<UpdateMessageResponse, AzureError> {
+        let request_id = request_id_from_headers(headers)?;
+        let date = date_from_headers(headers)?;
+        let pop_receipt = pop_receipt_from_headers(headers)?;
+        let time_next_visible = time_next_visible_from_headers(headers)?;
+
+        Ok(UpdateMessageResponse {
+            request_id,
+            date,
+            pop_receipt,
+            time_next_visible,
+        })
+    }
+}
@@ -0,0 +1,8 @@
+pub use crate::message::{
+    MessageIdRequired, MessageIdSupport, MessageTTLOption, MessageTTLSupport, MessageTextOption,
+    MessageTextRequired, MessageTextSupport, NumberOfMessagesOption, NumberOfMessagesSupport,
+    PopReceiptRequired, PopReceiptSupport, VisibilityTimeoutOption, VisibilityTimeoutRequired,
+    VisibilityTimeoutSupport,
+};
+pub use crate::Message as MessageTrait;
+pub use crate::Queue as QueueTrait;
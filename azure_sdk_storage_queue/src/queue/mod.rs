@@ -0,0 +1,124 @@
+pub mod requests;
+pub mod responses;
+
+use azure_sdk_core::errors::AzureError;
+use azure_sdk_core::incompletevector::IncompleteVector;
+use azure_sdk_core::parsing::{cast_must, cast_optional, traverse};
+use azure_sdk_storage_core::prelude::*;
+use std::collections::HashMap;
+use url::form_urlencoded;
+use xml::{Element, Xml};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Queue {
+    pub name: String,
+    pub metadata: HashMap<String, String>,
+}
+
+impl AsRef<str> for Queue {
+    fn as_ref(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Queue {
+    fn parse(elem: &Element) -> Result<Queue, AzureError> {
+        let name = cast_must::<String>(elem, &["Name"])?;
+
+        let mut metadata = HashMap::new();
+        for m in traverse(elem, &["Metadata"], true)? {
+            for key in &m.children {
+                // skip the whitespace between the nodes
+                let elem = match key {
+                    Xml::ElementNode(elem) => elem,
+                    _ => continue,
+                };
+
+                let content = match elem.children.first() {
+                    Some(Xml::CharacterNode(content)) => content.to_owned(),
+                    None => String::new(),
+                    _ => {
+                        return Err(AzureError::UnexpectedXMLError(String::from(
+                            "Metadata node should contain a CharacterNode with metadata value",
+                        )));
+                    }
+                };
+
+                metadata.insert(elem.name.to_owned(), content);
+            }
+        }
+
+        Ok(Queue { name, metadata })
+    }
+}
+
+pub(crate) fn incomplete_vector_from_queue_response(
+    body: &str,
+) -> Result<IncompleteVector<Queue>, AzureError> {
+    let elem: Element = body.parse()?;
+
+    let mut v = Vec::new();
+
+    for queue in traverse(&elem, &["Queues", "Queue"], true)? {
+        v.push(Queue::parse(queue)?);
+    }
+
+    let next_marker = cast_optional::<String>(&elem, &["NextMarker"])?.filter(|nm| !nm.is_empty());
+
+    Ok(IncompleteVector::new(next_marker, v))
+}
+
+#[inline]
+pub(crate) fn generate_queue_uri<C>(c: &C, queue_name: &str, params: Option<&str>) -> String
+where
+    C: Client,
+{
+    match params {
+        Some(ref params) => format!(
+            "{}/{}?{}",
+            c.queue_uri(),
+            form_urlencoded::byte_serialize(queue_name.as_bytes()).collect::<String>(),
+            params
+        ),
+        None => format!(
+            "{}/{}",
+            c.queue_uri(),
+            form_urlencoded::byte_serialize(queue_name.as_bytes()).collect::<String>(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_list_queues_response() {
+        let body = r#"<?xml version="1.0" encoding="utf-8"?>
+<EnumerationResults ServiceEndpoint="https://myaccount.queue.core.windows.net/">
+  <Prefix>q</Prefix>
+  <MaxResults>2</MaxResults>
+  <Queues>
+    <Queue>
+      <Name>q1</Name>
+      <Metadata>
+        <Color>red</Color>
+        <SomeMetadataName>SomeMetadataValue</SomeMetadataName>
+      </Metadata>
+    </Queue>
+    <Queue>
+      <Name>q2</Name>
+    </Queue>
+  </Queues>
+  <NextMarker>/myaccount/q3</NextMarker>
+</EnumerationResults>"#;
+
+        let iv = incomplete_vector_from_queue_response(body).unwrap();
+        assert_eq!(iv.token(), Some("/myaccount/q3"));
+        assert_eq!(iv.len(), 2);
+        assert_eq!(iv[0].name, "q1");
+        assert_eq!(iv[0].metadata.get("Color"), Some(&"red".to_owned()));
+        assert_eq!(iv[1].name, "q2");
+        assert!(iv[1].metadata.is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+use crate::queue::generate_queue_uri;
+use crate::queue::responses::CreateQueueResponse;
+use azure_sdk_core::errors::{extract_status_headers_and_body, AzureError, UnexpectedHTTPResult};
+use azure_sdk_core::prelude::*;
+use azure_sdk_core::{No, ToAssign, Yes};
+use azure_sdk_storage_core::prelude::*;
+use hyper::{Method, StatusCode};
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    client: &'a C,
+    p_queue_name: PhantomData<QueueNameSet>,
+    queue_name: Option<&'a str>,
+    metadata: Option<&'a HashMap<&'a str, &'a str>>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+}
+
+impl<'a, C> CreateBuilder<'a, C, No>
+where
+    C: Client,
+{
+    #[inline]
+    pub(crate) fn new(client: &'a C) -> CreateBuilder<'a, C, No> {
+        CreateBuilder {
+            client,
+            p_queue_name: PhantomData {},
+            queue_name: None,
+            metadata: None,
+            client_request_id: None,
+            timeout: None,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequired<'a, C> for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client(&self) -> &'a C {
+        self.client
+    }
+}
+
+//get mandatory no traits methods
+
+//set mandatory no traits methods
+impl<'a, C> QueueNameRequired<'a> for CreateBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    #[inline]
+    fn queue_name(&self) -> &'a str {
+        self.queue_name.unwrap()
+    }
+}
+
+impl<'a, C, QueueNameSet> MetadataOption<'a> for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn metadata(&self) -> Option<&'a HashMap<&'a str, &'a str>> {
+        self.metadata
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdOption<'a> for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutOption for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    #[inline]
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, C> QueueNameSupport<'a> for CreateBuilder<'a, C, No>
+where
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, Yes>;
+
+    #[inline]
+    fn with_queue_name(self, queue_name: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: Some(queue_name),
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> MetadataSupport<'a> for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_metadata(self, metadata: &'a HashMap<&'a str, &'a str>) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: Some(metadata),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> ClientRequestIdSupport<'a> for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: self.metadata,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+        }
+    }
+}
+
+impl<'a, C, QueueNameSet> TimeoutSupport for CreateBuilder<'a, C, QueueNameSet>
+where
+    QueueNameSet: ToAssign,
+    C: Client,
+{
+    type O = CreateBuilder<'a, C, QueueNameSet>;
+
+    #[inline]
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        CreateBuilder {
+            client: self.client,
+            p_queue_name: PhantomData {},
+            queue_name: self.queue_name,
+            metadata: self.metadata,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+        }
+    }
+}
+
+// methods callable only when every mandatory field has been filled
+impl<'a, C> CreateBuilder<'a, C, Yes>
+where
+    C: Client,
+{
+    pub async fn finalize(self) -> Result<CreateQueueResponse, AzureError> {
+        let mut uri = generate_queue_uri(self.client(), self.queue_name(), None);
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}?{}", uri, nm);
+        }
+
+        trace!("uri == {:?}", uri);
+
+        let future_response = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            &|mut request| {
+                request = ClientRequestIdOption::add_header(&self, request);
+                request = MetadataOption::add_header(&self, request);
+                request
+            },
+            Some(&[]),
+        )?;
+
+        let (status_code, headers, body) = extract_status_headers_and_body(future_response).await?;
+
+        // the service answers with 204 if the queue already exists
+        // with the same metadata
+        match status_code {
+            StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                CreateQueueResponse::from_headers(&headers)
+            }
+            _ => Err(UnexpectedHTTPResult::new_multiple(
+                vec![StatusCode::CREATED, StatusCode::NO_CONTENT],
+                status_code,
+                std::str::from_utf8(&body)?,
+            )
+            .with_headers(&headers)
+            .into()),
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod create_queue_response;
+pub use self::create_queue_response::CreateQueueResponse;
+mod delete_queue_response;
+pub use self::delete_queue_response::DeleteQueueResponse;
+mod list_queues_response;
+pub use self::list_queues_response::ListQueuesResponse;
@@ -0,0 +1,171 @@
+#![cfg(all(test, feature = "test_e2e"))]
+use azure_sdk_core::prelude::*;
+use azure_sdk_storage_core::key_client::KeyClient;
+use azure_sdk_storage_core::prelude::*;
+use azure_sdk_storage_queue::prelude::*;
+use futures::stream::StreamExt;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn create_list_and_delete_queue() {
+    let queue_name: &'static str = "azuresdkrustetoetsqueue1";
+
+    let client = initialize();
+
+    let mut metadata = HashMap::new();
+    metadata.insert("source", "rust");
+
+    client
+        .create_queue()
+        .with_queue_name(queue_name)
+        .with_metadata(&metadata)
+        .finalize()
+        .await
+        .unwrap();
+
+    let mut stream = Box::pin(
+        client
+            .list_queues()
+            .with_prefix("azuresdkrustetoets")
+            .with_include_metadata()
+            .with_max_results(1)
+            .stream(),
+    );
+
+    let mut found = false;
+    while let Some(response) = stream.next().await {
+        for queue in response.unwrap().incomplete_vector.iter() {
+            if queue.name == queue_name {
+                assert_eq!(queue.metadata.get("source"), Some(&"rust".to_owned()));
+                found = true;
+            }
+        }
+    }
+    assert!(found);
+
+    client
+        .delete_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn put_get_update_and_delete_message() {
+    let queue_name: &'static str = "azuresdkrustetoetsqueue2";
+
+    let client = initialize();
+
+    client
+        .create_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_message()
+        .with_queue_name(queue_name)
+        .with_message_text("<first & message>")
+        .with_message_ttl(600)
+        .finalize()
+        .await
+        .unwrap();
+
+    let peeked = client
+        .peek_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(peeked.messages.len(), 1);
+    assert_eq!(peeked.messages[0].message_text, "<first & message>");
+
+    let got = client
+        .get_messages()
+        .with_queue_name(queue_name)
+        .with_visibility_timeout(60)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(got.messages.len(), 1);
+    let message = &got.messages[0];
+    assert_eq!(message.dequeue_count, 1);
+
+    // the message is now invisible
+    let got_again = client
+        .get_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(got_again.messages.is_empty());
+
+    let updated = client
+        .update_message()
+        .with_queue_name(queue_name)
+        .with_message_id(&message.message_id)
+        .with_pop_receipt(&message.pop_receipt)
+        .with_visibility_timeout(0)
+        .with_message_text("updated")
+        .finalize()
+        .await
+        .unwrap();
+
+    let peeked = client
+        .peek_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert_eq!(peeked.messages[0].message_text, "updated");
+
+    client
+        .delete_message()
+        .with_queue_name(queue_name)
+        .with_message_id(&message.message_id)
+        .with_pop_receipt(&updated.pop_receipt)
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .put_message()
+        .with_queue_name(queue_name)
+        .with_message_text("second")
+        .finalize()
+        .await
+        .unwrap();
+
+    client
+        .clear_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+
+    let peeked = client
+        .peek_messages()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+    assert!(peeked.messages.is_empty());
+
+    client
+        .delete_queue()
+        .with_queue_name(queue_name)
+        .finalize()
+        .await
+        .unwrap();
+}
+
+fn initialize() -> KeyClient {
+    let account =
+        std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
+    let master_key =
+        std::env::var("STORAGE_MASTER_KEY").expect("Set env variable STORAGE_MASTER_KEY first!");
+
+    client::with_access_key(&account, &master_key)
+}
@@ -1,7 +1,7 @@
 #[macro_use]
 extern crate serde_derive;
 
-use azure_sdk_storage_table::{Batch, CloudTable, Continuation, TableClient};
+use azure_sdk_storage_table::{Batch, CloudTable, Continuation, Filter, Query, TableClient};
 use std::error::Error;
 use std::mem;
 
@@ -67,6 +67,18 @@ async fn main() -> Result<(), Box<dyn Error>> {
         println!("segment(value): {:?}", entities.first());
     }
 
+    let query = Query::new()
+        .with_filter(Filter::eq("PartitionKey", "big2").and(Filter::ge("RowKey", "rk-10")))
+        .with_select(&["RowKey", "data"])
+        .with_top(5);
+    let mut cont = Continuation::start();
+    while let Some(entities) = cloud_table
+        .execute_typed_query::<MyEntity>(&query, &mut cont)
+        .await?
+    {
+        println!("segment(typed query): {:?}", entities);
+    }
+
     let mut batch = Batch::new("big2".to_owned());
     for r in 0usize..cnt {
         if r % 2 == 0 {
@@ -56,12 +56,17 @@ async fn main() -> Result<(), Box<dyn Error>> {
     println!("update_entity completed without errors: {:?}", my_entity);
 
     my_entity.payload.my_value = "Going round and round".to_owned();
-    let my_entity = table.insert_or_update_entity(my_entity).await?;
+    let mut my_entity = table.insert_or_update_entity(my_entity).await?;
     println!(
         "insert_or_update_entity completed without errors: {:?}",
         my_entity
     );
 
+    // merge only touches the properties in the payload
+    my_entity.payload.my_value = "All through the town".to_owned();
+    let my_entity = table.merge_entity(my_entity).await?;
+    println!("merge_entity completed without errors: {:?}", my_entity);
+
     // get the entity again (new payload and etag)
     let ret: TableEntity<MyEntity> = table
         .get(&my_entity.partition_key, &my_entity.row_key, None)
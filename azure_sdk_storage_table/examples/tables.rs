@@ -1,4 +1,5 @@
-use azure_sdk_storage_table::TableClient;
+use azure_sdk_storage_table::{Filter, Query, TableClient};
+use futures::stream::StreamExt;
 use std::error::Error;
 
 #[tokio::main]
@@ -16,5 +17,14 @@ async fn main() -> Result<(), Box<dyn Error>> {
     for ref table in tables {
         println!("{}", table);
     }
+
+    // tables whose name starts with "a", one page at a time
+    let query = Query::new()
+        .with_filter(Filter::ge("TableName", "a").and(Filter::lt("TableName", "b")))
+        .with_top(10);
+    let mut stream = Box::pin(client.stream_query_tables(Some(&query)));
+    while let Some(page) = stream.next().await {
+        println!("page == {:?}", page?);
+    }
     Ok(())
 }
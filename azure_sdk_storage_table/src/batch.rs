@@ -1,4 +1,5 @@
-use crate::{entity_path, TableEntity};
+use crate::{edm, entity_path, BatchFailure, TableEntity};
+use azure_sdk_core::errors::AzureError;
 use serde::Serialize;
 use serde_json;
 
@@ -18,6 +19,17 @@ quick_error! {
             display("json error: {}", err)
             cause(err)
         }
+        OperationFailed(failure: BatchFailure) {
+            display("Batch rolled back, {}", failure)
+        }
+        MalformedResponse(err: String) {
+            display("Malformed batch response: {}", err)
+        }
+        AzureError(err: AzureError) {
+            from()
+            display("Azure error: {}", err)
+            cause(err)
+        }
     }
 }
 
@@ -37,6 +49,17 @@ pub enum BatchOperation {
         payload: String,
         etag: Option<String>,
     },
+
+    Merge {
+        row_key: String,
+        payload: String,
+        etag: Option<String>,
+    },
+
+    InsertOrMerge {
+        row_key: String,
+        payload: String,
+    },
 }
 
 impl BatchOperation {
@@ -66,14 +89,52 @@ impl BatchOperation {
                 body.push_str("Accept: application/json;odata=nometadata\n");
                 body.push_str("Content-Type: application/json\n");
                 if let Some(etag) = etag {
-                    body.push_str("If-Match: \"");
+                    // the etag returned by the service is already quoted
+                    body.push_str("If-Match: ");
                     body.push_str(etag);
-                    body.push_str("\"\n\n");
+                    body.push_str("\n\n");
                 } else {
                     body.push_str("If-Match: *\n\n");
                 }
-                body.push_str(&payload);
-                body.push_str("\n");
+                body.push_str(payload);
+                body.push('\n');
+            }
+
+            BatchOperation::Merge {
+                ref row_key,
+                ref etag,
+                ref payload,
+            } => {
+                body.push_str("MERGE ");
+                body.push_str(uri_prefix);
+                body.push_str(&entity_path(table, partition_key, row_key));
+                body.push_str(" HTTP/1.1\n");
+                body.push_str("Accept: application/json;odata=nometadata\n");
+                body.push_str("Content-Type: application/json\n");
+                if let Some(etag) = etag {
+                    body.push_str("If-Match: ");
+                    body.push_str(etag);
+                    body.push_str("\n\n");
+                } else {
+                    body.push_str("If-Match: *\n\n");
+                }
+                body.push_str(payload);
+                body.push('\n');
+            }
+
+            // without If-Match the merge inserts the entity if missing
+            BatchOperation::InsertOrMerge {
+                ref row_key,
+                ref payload,
+            } => {
+                body.push_str("MERGE ");
+                body.push_str(uri_prefix);
+                body.push_str(&entity_path(table, partition_key, row_key));
+                body.push_str(" HTTP/1.1\n");
+                body.push_str("Accept: application/json;odata=nometadata\n");
+                body.push_str("Content-Type: application/json\n\n");
+                body.push_str(payload);
+                body.push('\n');
             }
 
             BatchOperation::Delete {
@@ -87,9 +148,9 @@ impl BatchOperation {
                 body.push_str("Accept: application/json;odata=nometadata\n");
                 body.push_str("Content-Type: application/json\n");
                 if let Some(etag) = etag {
-                    body.push_str("If-Match: \"");
+                    body.push_str("If-Match: ");
                     body.push_str(etag);
-                    body.push_str("\"\n");
+                    body.push('\n');
                 } else {
                     body.push_str("If-Match: *\n");
                 }
@@ -155,7 +216,7 @@ impl Batch {
     where
         T: Serialize,
     {
-        let payload = serde_json::to_string(&InsertPayload {
+        let payload = edm::to_json(&InsertPayload {
             partition_key: &self.partition_key,
             row_key: &row_key,
             payload: data,
@@ -187,7 +248,7 @@ impl Batch {
     {
         self.add_operation(BatchOperation::Update {
             row_key: row_key.to_owned(),
-            payload: serde_json::to_string(data)?,
+            payload: edm::to_json(data)?,
             etag: etag,
         })
     }
@@ -204,6 +265,65 @@ impl Batch {
         }
     }
 
+    /// Add a merge operation: only the properties in `data` are updated
+    pub fn add_merge<T>(
+        &mut self,
+        row_key: String,
+        data: &T,
+        etag: Option<String>,
+    ) -> Result<&mut Self, BatchError>
+    where
+        T: Serialize,
+    {
+        self.add_operation(BatchOperation::Merge {
+            row_key,
+            payload: edm::to_json(data)?,
+            etag,
+        })
+    }
+
+    /// Add a merge operation using a TableEntitiy
+    pub fn add_merge_entity<T>(&mut self, entity: TableEntity<T>) -> Result<&mut Self, BatchError>
+    where
+        T: Serialize,
+    {
+        if entity.partition_key != self.partition_key {
+            Err(BatchError::UnexpectedPartitionKey)
+        } else {
+            self.add_merge(entity.row_key, &entity.payload, entity.etag)
+        }
+    }
+
+    /// Add an insert or merge operation
+    pub fn add_insert_or_merge<T>(
+        &mut self,
+        row_key: String,
+        data: &T,
+    ) -> Result<&mut Self, BatchError>
+    where
+        T: Serialize,
+    {
+        self.add_operation(BatchOperation::InsertOrMerge {
+            row_key,
+            payload: edm::to_json(data)?,
+        })
+    }
+
+    /// Add an insert or merge operation using a TableEntitiy
+    pub fn add_insert_or_merge_entity<T>(
+        &mut self,
+        entity: TableEntity<T>,
+    ) -> Result<&mut Self, BatchError>
+    where
+        T: Serialize,
+    {
+        if entity.partition_key != self.partition_key {
+            Err(BatchError::UnexpectedPartitionKey)
+        } else {
+            self.add_insert_or_merge(entity.row_key, &entity.payload)
+        }
+    }
+
     /// Add a delete operation
     pub fn add_delete(
         &mut self,
@@ -249,3 +369,80 @@ impl Batch {
         payload
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ETAG: &str = "W/\"datetime'2020-05-10T10%3A11%3A12.1234567Z'\"";
+
+    #[derive(Serialize)]
+    struct Data {
+        data: &'static str,
+    }
+
+    // the HTTP request of every operation of the changeset
+    fn operations(batch: Batch) -> Vec<String> {
+        batch
+            .into_payload("https://myaccount.table.core.windows.net/", "mytable")
+            .split("--changeset_8a28b620-b4bb-458c-a177-0959fb14c977\n")
+            .skip(1)
+            .map(|part| part.split_once("\n\n").unwrap().1.to_owned())
+            .collect()
+    }
+
+    fn if_match(operation: &str) -> Option<&str> {
+        operation
+            .lines()
+            .find(|line| line.starts_with("If-Match:"))
+            .map(|line| line["If-Match:".len()..].trim())
+    }
+
+    #[test]
+    fn merge_payloads() {
+        let mut batch = Batch::new("pk".to_owned());
+        batch
+            .add_merge("rk1".to_owned(), &Data { data: "merged" }, None)
+            .unwrap()
+            .add_insert_or_merge("rk2".to_owned(), &Data { data: "upserted" })
+            .unwrap();
+
+        let operations = operations(batch);
+        assert_eq!(operations.len(), 2);
+
+        assert!(operations[0].starts_with(
+            "MERGE https://myaccount.table.core.windows.net/mytable\
+             (PartitionKey='pk',RowKey='rk1') HTTP/1.1\n"
+        ));
+        assert_eq!(if_match(&operations[0]), Some("*"));
+        assert!(operations[0].contains("{\"data\":\"merged\"}"));
+
+        assert!(operations[1].starts_with(
+            "MERGE https://myaccount.table.core.windows.net/mytable\
+             (PartitionKey='pk',RowKey='rk2') HTTP/1.1\n"
+        ));
+        assert_eq!(if_match(&operations[1]), None);
+        assert!(operations[1].contains("{\"data\":\"upserted\"}"));
+    }
+
+    #[test]
+    fn service_etag_is_sent_verbatim() {
+        let mut batch = Batch::new("pk".to_owned());
+        batch
+            .add_update("rk1".to_owned(), &Data { data: "a" }, Some(ETAG.to_owned()))
+            .unwrap()
+            .add_merge("rk2".to_owned(), &Data { data: "b" }, Some(ETAG.to_owned()))
+            .unwrap()
+            .add_delete("rk3".to_owned(), Some(ETAG.to_owned()))
+            .unwrap();
+
+        let operations = operations(batch);
+        assert_eq!(operations.len(), 3);
+        assert!(operations[0].starts_with("PUT "));
+        assert!(operations[1].starts_with("MERGE "));
+        assert!(operations[2].starts_with("DELETE "));
+        for operation in &operations {
+            assert_eq!(if_match(operation), Some(ETAG));
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use crate::BatchError;
+use hyper::StatusCode;
+use serde_json::Value;
+use std::fmt;
+
+/// The outcome of one operation of a committed batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchOperationResponse {
+    pub status_code: StatusCode,
+    pub etag: Option<String>,
+}
+
+/// Why the service rolled back a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchFailure {
+    pub status_code: StatusCode,
+    /// Index, in the batch, of the operation that failed.
+    pub operation_index: Option<usize>,
+    /// OData error code, e.g. `EntityAlreadyExists`.
+    pub error_code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl fmt::Display for BatchFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation_index {
+            Some(index) => write!(f, "operation {}", index)?,
+            None => write!(f, "operation unknown")?,
+        }
+        write!(f, " failed with {}", self.status_code)?;
+        if let Some(error_code) = &self.error_code {
+            write!(f, " ({})", error_code)?;
+        }
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+struct HttpPart<'a> {
+    headers: Vec<(&'a str, &'a str)>,
+    body: &'a str,
+}
+
+impl<'a> HttpPart<'a> {
+    fn parse(part: &'a str) -> HttpPart<'a> {
+        let part = part.trim_start_matches('\n');
+        let (head, body) = match part.find("\n\n") {
+            Some(pos) => (&part[..pos], &part[pos + 2..]),
+            None => (part, ""),
+        };
+
+        HttpPart {
+            headers: head
+                .lines()
+                .map(|line| {
+                    let mut split = line.splitn(2, ':');
+                    (
+                        split.next().unwrap_or("").trim(),
+                        split.next().unwrap_or("").trim(),
+                    )
+                })
+                .collect(),
+            body,
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&'a str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    }
+}
+
+fn boundary(content_type: &str) -> Result<&str, BatchError> {
+    content_type
+        .split(';')
+        .map(|param| param.trim())
+        .find(|param| param.starts_with("boundary="))
+        .map(|param| param["boundary=".len()..].trim_matches('"'))
+        .ok_or_else(|| BatchError::MalformedResponse(content_type.to_owned()))
+}
+
+fn split_multipart<'a>(body: &'a str, content_type: &str) -> Result<Vec<&'a str>, BatchError> {
+    let delimiter = format!("--{}", boundary(content_type)?);
+
+    // the first chunk is the (empty) preamble, the last one starts with
+    // the closing "--"
+    Ok(body
+        .split(&delimiter as &str)
+        .skip(1)
+        .take_while(|part| !part.starts_with("--"))
+        .collect())
+}
+
+fn status_code(status_line: &str) -> Result<StatusCode, BatchError> {
+    status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|status_code| StatusCode::from_bytes(status_code.as_bytes()).ok())
+        .ok_or_else(|| BatchError::MalformedResponse(status_line.to_owned()))
+}
+
+/// The message of a failed changeset starts with the index of the
+/// failing operation, e.g. `1:The specified entity already exists.`.
+fn failure_from_body(status_code: StatusCode, body: &str) -> BatchFailure {
+    let error = serde_json::from_str::<Value>(body.trim()).ok();
+    let error = error.as_ref().and_then(|value| value.get("odata.error"));
+
+    let error_code = error
+        .and_then(|error| error.get("code"))
+        .and_then(Value::as_str)
+        .map(|code| code.to_owned());
+    let message = error
+        .and_then(|error| error.get("message"))
+        .and_then(|message| message.get("value"))
+        .and_then(Value::as_str);
+
+    let (operation_index, message) = match message {
+        Some(message) => {
+            let mut split = message.splitn(2, ':');
+            match (split.next().map(str::parse::<usize>), split.next()) {
+                (Some(Ok(index)), Some(rest)) => (Some(index), Some(rest.to_owned())),
+                _ => (None, Some(message.to_owned())),
+            }
+        }
+        None => (None, None),
+    };
+
+    BatchFailure {
+        status_code,
+        operation_index,
+        error_code,
+        message,
+    }
+}
+
+/// Parses the HTTP response of one operation, failing with
+/// `BatchError::OperationFailed` if it was not successful.
+fn operation_response(http_response: &str) -> Result<BatchOperationResponse, BatchError> {
+    let parsed = HttpPart::parse(http_response);
+    let (status_line, headers) = parsed
+        .headers
+        .split_first()
+        .ok_or_else(|| BatchError::MalformedResponse(http_response.to_owned()))?;
+    let status_code = status_code(status_line.0)?;
+
+    if !status_code.is_success() {
+        return Err(BatchError::OperationFailed(failure_from_body(
+            status_code,
+            parsed.body,
+        )));
+    }
+
+    Ok(BatchOperationResponse {
+        status_code,
+        etag: headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("ETag"))
+            .map(|(_, value)| (*value).to_owned()),
+    })
+}
+
+/// Parses the `multipart/mixed` response of a batch. If the changeset was
+/// rolled back the service answers with the failing operation only, and if
+/// the whole batch was rejected with a bare `application/http` response:
+/// both are returned as `BatchError::OperationFailed`.
+pub(crate) fn batch_responses_from_response(
+    content_type: &str,
+    body: &str,
+) -> Result<Vec<BatchOperationResponse>, BatchError> {
+    let body = body.replace("\r\n", "\n");
+
+    let mut responses = Vec::new();
+
+    for batch_part in split_multipart(&body, content_type)? {
+        let batch_part = HttpPart::parse(batch_part);
+        let part_content_type = batch_part
+            .header("Content-Type")
+            .ok_or_else(|| BatchError::MalformedResponse(batch_part.body.to_owned()))?;
+
+        if part_content_type.starts_with("application/http") {
+            responses.push(operation_response(batch_part.body)?);
+            continue;
+        }
+
+        for changeset_part in split_multipart(batch_part.body, part_content_type)? {
+            responses.push(operation_response(HttpPart::parse(changeset_part).body)?);
+        }
+    }
+
+    Ok(responses)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CONTENT_TYPE: &str = "multipart/mixed; boundary=batchresponse_4c637ba4";
+
+    #[test]
+    fn parse_committed_batch() {
+        let body = "--batchresponse_4c637ba4\r\n\
+            Content-Type: multipart/mixed; boundary=changesetresponse_ddc5a5d5\r\n\
+            \r\n\
+            --changesetresponse_ddc5a5d5\r\n\
+            Content-Type: application/http\r\n\
+            Content-Transfer-Encoding: binary\r\n\
+            \r\n\
+            HTTP/1.1 201 Created\r\n\
+            DataServiceVersion: 3.0;\r\n\
+            Content-Type: application/json;odata=nometadata;streaming=true;charset=utf-8\r\n\
+            ETag: W/\"datetime'2020-05-10T10%3A11%3A12.1234567Z'\"\r\n\
+            \r\n\
+            {\"PartitionKey\":\"pk\",\"RowKey\":\"rk1\",\"data\":\"hello\"}\r\n\
+            --changesetresponse_ddc5a5d5\r\n\
+            Content-Type: application/http\r\n\
+            Content-Transfer-Encoding: binary\r\n\
+            \r\n\
+            HTTP/1.1 204 No Content\r\n\
+            DataServiceVersion: 1.0;\r\n\
+            \r\n\
+            \r\n\
+            --changesetresponse_ddc5a5d5--\r\n\
+            --batchresponse_4c637ba4--\r\n";
+
+        let responses = batch_responses_from_response(CONTENT_TYPE, body).unwrap();
+        assert_eq!(
+            responses,
+            vec![
+                BatchOperationResponse {
+                    status_code: StatusCode::CREATED,
+                    etag: Some("W/\"datetime'2020-05-10T10%3A11%3A12.1234567Z'\"".to_owned()),
+                },
+                BatchOperationResponse {
+                    status_code: StatusCode::NO_CONTENT,
+                    etag: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_rolled_back_batch() {
+        let body = "--batchresponse_4c637ba4\r\n\
+            Content-Type: multipart/mixed; boundary=changesetresponse_ddc5a5d5\r\n\
+            \r\n\
+            --changesetresponse_ddc5a5d5\r\n\
+            Content-Type: application/http\r\n\
+            Content-Transfer-Encoding: binary\r\n\
+            \r\n\
+            HTTP/1.1 409 Conflict\r\n\
+            DataServiceVersion: 3.0;\r\n\
+            Content-Type: application/json;odata=nometadata;streaming=true;charset=utf-8\r\n\
+            \r\n\
+            {\"odata.error\":{\"code\":\"EntityAlreadyExists\",\"message\":{\"lang\":\"en-US\",\"value\":\"1:The specified entity already exists.\\nRequestId:7a4bda47\"}}}\r\n\
+            --changesetresponse_ddc5a5d5--\r\n\
+            --batchresponse_4c637ba4--\r\n";
+
+        match batch_responses_from_response(CONTENT_TYPE, body) {
+            Err(BatchError::OperationFailed(failure)) => {
+                assert_eq!(failure.status_code, StatusCode::CONFLICT);
+                assert_eq!(failure.operation_index, Some(1));
+                assert_eq!(failure.error_code.as_deref(), Some("EntityAlreadyExists"));
+                assert_eq!(
+                    failure.message.as_deref(),
+                    Some("The specified entity already exists.\nRequestId:7a4bda47")
+                );
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_rejected_batch() {
+        let body = "--batchresponse_4c637ba4\r\n\
+            Content-Type: application/http\r\n\
+            Content-Transfer-Encoding: binary\r\n\
+            \r\n\
+            HTTP/1.1 400 Bad Request\r\n\
+            DataServiceVersion: 3.0;\r\n\
+            Content-Type: application/json;odata=nometadata;streaming=true;charset=utf-8\r\n\
+            \r\n\
+            {\"odata.error\":{\"code\":\"InvalidInput\",\"message\":{\"lang\":\"en-US\",\"value\":\"0:One of the request inputs is not valid.\"}}}\r\n\
+            --batchresponse_4c637ba4--\r\n";
+
+        match batch_responses_from_response(CONTENT_TYPE, body) {
+            Err(BatchError::OperationFailed(failure)) => {
+                assert_eq!(failure.status_code, StatusCode::BAD_REQUEST);
+                assert_eq!(failure.operation_index, Some(0));
+                assert_eq!(failure.error_code.as_deref(), Some("InvalidInput"));
+                assert_eq!(
+                    failure.message.as_deref(),
+                    Some("One of the request inputs is not valid.")
+                );
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn display_failure() {
+        let failure = BatchFailure {
+            status_code: StatusCode::CONFLICT,
+            operation_index: Some(1),
+            error_code: Some("EntityAlreadyExists".to_owned()),
+            message: Some("The specified entity already exists.".to_owned()),
+        };
+        assert_eq!(
+            BatchError::OperationFailed(failure).to_string(),
+            "Batch rolled back, operation 1 failed with 409 Conflict (EntityAlreadyExists): \
+             The specified entity already exists."
+        );
+
+        let failure = BatchFailure {
+            status_code: StatusCode::BAD_REQUEST,
+            operation_index: None,
+            error_code: None,
+            message: None,
+        };
+        assert_eq!(
+            BatchError::OperationFailed(failure).to_string(),
+            "Batch rolled back, operation unknown failed with 400 Bad Request"
+        );
+    }
+}
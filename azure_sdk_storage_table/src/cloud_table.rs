@@ -1,9 +1,13 @@
+use crate::batch_response::batch_responses_from_response;
 use crate::{
-    entity_path, get_batch_mime, Batch, Continuation, MetadataDetail, TableClient, TableEntity,
+    edm, entity_path, get_batch_mime, Batch, BatchError, BatchOperationResponse, Continuation,
+    MetadataDetail, Query, TableClient, TableEntity,
 };
 use azure_sdk_core::errors::{
     check_status_extract_body, check_status_extract_headers_and_body, AzureError,
 };
+use azure_sdk_core::util::HeaderMapExt;
+use azure_sdk_core::StoredAccessPolicyList;
 use azure_sdk_storage_core::Client;
 use futures::stream::Stream;
 use hyper::{header, Method, StatusCode};
@@ -47,6 +51,26 @@ where
         })
     }
 
+    /// Deletes the table and all its entities.
+    pub async fn delete_table(&self) -> Result<(), AzureError> {
+        self.client.delete_table(&self.table_name).await
+    }
+
+    /// Returns the stored access policies of the table.
+    pub async fn get_acl(&self) -> Result<StoredAccessPolicyList, AzureError> {
+        self.client.get_table_acl(&self.table_name).await
+    }
+
+    /// Replaces the stored access policies of the table.
+    pub async fn set_acl(
+        &self,
+        stored_access_policy_list: &StoredAccessPolicyList,
+    ) -> Result<(), AzureError> {
+        self.client
+            .set_table_acl(&self.table_name, stored_access_policy_list)
+            .await
+    }
+
     pub async fn get<T>(
         &self,
         partition_key: &str,
@@ -98,7 +122,7 @@ where
             timestamp: None,
             payload,
         };
-        let obj_ser = serde_json::to_string(&entity)?.to_owned();
+        let obj_ser = edm::to_json(&entity)?;
 
         let future_response = self.client.request_with_default_header(
             &self.table_name,
@@ -144,7 +168,7 @@ where
             timestamp: None,
             payload,
         };
-        let obj_ser = serde_json::to_string(&entity)?.to_owned();
+        let obj_ser = edm::to_json(&entity)?;
         let path = &entity_path(&self.table_name, &entity.partition_key, &entity.row_key);
         let future_response = self.client.request_with_default_header(
             &path,
@@ -186,7 +210,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let obj_ser = serde_json::to_string(&entity)?.to_owned();
+        let obj_ser = edm::to_json(&entity)?;
         let path = &entity_path(&self.table_name, &entity.partition_key, &entity.row_key);
         let etag = entity.etag;
         let future_response = self.client.request_with_default_header(
@@ -216,6 +240,108 @@ where
         Ok(entity)
     }
 
+    /// Updates the properties of an existing entity present in `payload`, leaving the others
+    /// untouched. If `etag` is specified the operation fails if the entity has changed since.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/merge-entity
+    pub async fn merge<T>(
+        &self,
+        partition_key: &str,
+        row_key: &str,
+        payload: T,
+        etag: Option<&str>,
+    ) -> Result<TableEntity<T>, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.merge_internal(partition_key, row_key, payload, Some(etag.unwrap_or("*")))
+            .await
+    }
+
+    pub async fn merge_entity<T>(
+        &self,
+        entity: TableEntity<T>,
+    ) -> Result<TableEntity<T>, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.merge(
+            &entity.partition_key,
+            &entity.row_key,
+            entity.payload,
+            entity.etag.as_deref(),
+        )
+        .await
+    }
+
+    /// Merges the properties of `payload` into the entity, inserting it if missing.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/insert-or-merge-entity
+    pub async fn insert_or_merge<T>(
+        &self,
+        partition_key: &str,
+        row_key: &str,
+        payload: T,
+    ) -> Result<TableEntity<T>, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.merge_internal(partition_key, row_key, payload, None)
+            .await
+    }
+
+    pub async fn insert_or_merge_entity<T>(
+        &self,
+        entity: TableEntity<T>,
+    ) -> Result<TableEntity<T>, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.insert_or_merge(&entity.partition_key, &entity.row_key, entity.payload)
+            .await
+    }
+
+    // MERGE without If-Match is an insert or merge
+    async fn merge_internal<T>(
+        &self,
+        partition_key: &str,
+        row_key: &str,
+        payload: T,
+        if_match: Option<&str>,
+    ) -> Result<TableEntity<T>, AzureError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut entity: TableEntity<T> = TableEntity {
+            partition_key: partition_key.to_owned(),
+            row_key: row_key.to_owned(),
+            etag: None,
+            timestamp: None,
+            payload,
+        };
+        let obj_ser = edm::to_json(&entity)?;
+        let path = &entity_path(&self.table_name, &entity.partition_key, &entity.row_key);
+        let future_response = self.client.request_with_default_header(
+            path,
+            &merge_method(),
+            Some(&obj_ser),
+            MetadataDetail::None,
+            &|mut request| {
+                if let Some(if_match) = if_match {
+                    request = request.header(header::IF_MATCH, if_match);
+                }
+                request
+            },
+        )?;
+        let (headers, _body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::NO_CONTENT).await?;
+
+        entity.etag = match headers.get(header::ETAG) {
+            Some(etag) => Some(etag.to_str()?.to_owned()),
+            None => None,
+        };
+
+        Ok(entity)
+    }
+
     pub async fn delete(
         &self,
         partition_key: &str,
@@ -313,7 +439,48 @@ where
         })
     }
 
-    pub async fn execute_batch(&self, batch: Batch) -> Result<(), AzureError> {
+    /// Same as `execute_query` but with the query options built by `Query`.
+    pub async fn execute_typed_query<T>(
+        &self,
+        query: &Query,
+        continuation: &mut Continuation,
+    ) -> Result<Option<Vec<TableEntity<T>>>, AzureError>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        self.execute_query(Some(&query.to_string()), continuation)
+            .await
+    }
+
+    pub fn stream_typed_query<'a, T>(
+        &'a self,
+        query: &Query,
+    ) -> impl Stream<Item = Result<Vec<TableEntity<T>>, AzureError>> + 'a
+    where
+        T: Serialize + DeserializeOwned + 'a,
+    {
+        let query = query.to_string();
+        futures::stream::unfold(Continuation::start(), move |mut cont| {
+            let query = query.clone();
+            async move {
+                log::debug!("cont == {:?}", cont);
+                match self.execute_query::<T>(Some(&query), &mut cont).await {
+                    Ok(Some(segment)) => Some((Ok(segment), cont)),
+                    Ok(None) => None,
+                    Err(err) => Some((Err(err), cont)),
+                }
+            }
+        })
+    }
+
+    /// Executes the operations of `batch` as a single transaction. On success the
+    /// responses are returned in the order the operations were added; if any of
+    /// them fails the whole batch is rolled back and `BatchError::OperationFailed`
+    /// tells which one.
+    pub async fn execute_batch(
+        &self,
+        batch: Batch,
+    ) -> Result<Vec<BatchOperationResponse>, BatchError> {
         let payload = batch.into_payload(self.client.get_uri_prefix().as_str(), &self.table_name);
 
         let future_response =
@@ -324,14 +491,23 @@ where
                         header::HeaderValue::from_static(get_batch_mime()),
                     )
                 })?;
-        check_status_extract_body(future_response, StatusCode::ACCEPTED).await?;
-        // TODO deal with body response, handle batch failure.
-        // let ref body = get_response_body(&mut response)?;
-        // info!("{}", body);
-        Ok(())
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED).await?;
+
+        let content_type = headers
+            .get_as_str(header::CONTENT_TYPE)
+            .ok_or_else(|| AzureError::HeaderNotFound(header::CONTENT_TYPE.as_str().to_owned()))?;
+        let body = std::str::from_utf8(&body).map_err(AzureError::from)?;
+
+        batch_responses_from_response(content_type, body)
     }
 }
 
+#[inline]
+fn merge_method() -> Method {
+    Method::from_bytes(b"MERGE").unwrap()
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct EntityCollection<T> {
     value: Vec<TableEntity<T>>,
@@ -0,0 +1,414 @@
+//! Wrappers for the property types JSON cannot express on its own. They
+//! are stored with their `Prop@odata.type` annotation, as the other SDKs
+//! do, as long as the entity is serialized with `to_json` (which
+//! `CloudTable` and `Batch` do).
+use chrono::{SecondsFormat, Utc};
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+use uuid::Uuid;
+
+// The wrappers serialize as a map holding these two keys only: `to_json`
+// replaces it with the bare value and adds the annotation next to it.
+const TYPE_MARKER: &str = "@azure_sdk_edm_type";
+const VALUE_MARKER: &str = "@azure_sdk_edm_value";
+
+const ODATA_TYPE_SUFFIX: &str = "@odata.type";
+
+fn serialize_annotated<S>(serializer: S, edm_type: &str, value: &str) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry(TYPE_MARKER, edm_type)?;
+    map.serialize_entry(VALUE_MARKER, value)?;
+    map.end()
+}
+
+/// An `Edm.Int64` property. JSON numbers cannot hold every 64 bit
+/// integer so the value is stored as a string.
+///
+/// Serialize the entities holding it with `to_json`: plain `serde_json`
+/// writes an internal marker object in place of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Int64(pub i64);
+
+impl Serialize for Int64 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_annotated(serializer, "Edm.Int64", &self.0.to_string())
+    }
+}
+
+struct Int64Visitor;
+
+impl<'de> Visitor<'de> for Int64Visitor {
+    type Value = Int64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an integer or a string holding an integer")
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Int64, E>
+    where
+        E: de::Error,
+    {
+        Ok(Int64(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Int64, E>
+    where
+        E: de::Error,
+    {
+        if value > i64::MAX as u64 {
+            return Err(E::custom(format!("{} does not fit in an Int64", value)));
+        }
+        Ok(Int64(value as i64))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Int64, E>
+    where
+        E: de::Error,
+    {
+        value
+            .parse()
+            .map(Int64)
+            .map_err(|e| E::custom(format!("Parse error {} for {}", e, value)))
+    }
+}
+
+impl<'de> Deserialize<'de> for Int64 {
+    fn deserialize<D>(deserializer: D) -> Result<Int64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(Int64Visitor)
+    }
+}
+
+impl From<i64> for Int64 {
+    fn from(value: i64) -> Self {
+        Int64(value)
+    }
+}
+
+/// An `Edm.DateTime` property. Without the annotation the date would be
+/// stored as a plain string.
+///
+/// Serialize the entities holding it with `to_json`: plain `serde_json`
+/// writes an internal marker object in place of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTime(pub chrono::DateTime<Utc>);
+
+impl Serialize for DateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_annotated(
+            serializer,
+            "Edm.DateTime",
+            &self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for DateTime {
+    fn deserialize<D>(deserializer: D) -> Result<DateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::de::deserialize(deserializer).map(DateTime)
+    }
+}
+
+impl From<chrono::DateTime<Utc>> for DateTime {
+    fn from(value: chrono::DateTime<Utc>) -> Self {
+        DateTime(value)
+    }
+}
+
+/// An `Edm.Guid` property.
+///
+/// Serialize the entities holding it with `to_json`: plain `serde_json`
+/// writes an internal marker object in place of the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Guid(pub Uuid);
+
+impl Serialize for Guid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_annotated(serializer, "Edm.Guid", &self.0.to_hyphenated().to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Guid {
+    fn deserialize<D>(deserializer: D) -> Result<Guid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Uuid::parse_str(&s)
+            .map(Guid)
+            .map_err(|e| de::Error::custom(format!("Parse error {} for {}", e, s)))
+    }
+}
+
+impl From<Uuid> for Guid {
+    fn from(value: Uuid) -> Self {
+        Guid(value)
+    }
+}
+
+/// An `Edm.Binary` property, stored base64 encoded.
+///
+/// Serialize the entities holding it with `to_json`: plain `serde_json`
+/// writes an internal marker object in place of the value.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Binary(pub Vec<u8>);
+
+impl Serialize for Binary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_annotated(serializer, "Edm.Binary", &base64::encode(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Binary {
+    fn deserialize<D>(deserializer: D) -> Result<Binary, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        base64::decode(&s)
+            .map(Binary)
+            .map_err(|e| de::Error::custom(format!("Parse error {} for {}", e, s)))
+    }
+}
+
+impl From<Vec<u8>> for Binary {
+    fn from(value: Vec<u8>) -> Self {
+        Binary(value)
+    }
+}
+
+// Splits a marker object into its EDM type and its value.
+fn take_annotated(value: Value) -> Result<(Value, Value), Value> {
+    match value {
+        Value::Object(mut map)
+            if map.len() == 2
+                && map.contains_key(TYPE_MARKER)
+                && map.contains_key(VALUE_MARKER) =>
+        {
+            let edm_type = map.remove(TYPE_MARKER).unwrap();
+            let value = map.remove(VALUE_MARKER).unwrap();
+            Ok((edm_type, value))
+        }
+        value => Err(value),
+    }
+}
+
+// Replaces every marker object with its value, annotating the ones that
+// are properties of an object. The markers in arrays lose their type.
+fn lift_annotations(value: Value) -> Value {
+    match value {
+        Value::Object(properties) => {
+            let mut annotated = Map::new();
+            for (name, value) in properties {
+                match take_annotated(value) {
+                    Ok((edm_type, value)) => {
+                        annotated.insert(format!("{}{}", name, ODATA_TYPE_SUFFIX), edm_type);
+                        annotated.insert(name, value);
+                    }
+                    Err(value) => {
+                        annotated.insert(name, lift_annotations(value));
+                    }
+                }
+            }
+            Value::Object(annotated)
+        }
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| match take_annotated(value) {
+                    Ok((_, value)) => value,
+                    Err(value) => lift_annotations(value),
+                })
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+/// Serializes an entity, adding the `@odata.type` annotation of the
+/// properties wrapped in the types of this module, at any depth.
+pub fn to_json<T>(entity: &T) -> Result<String, serde_json::Error>
+where
+    T: Serialize,
+{
+    let value = match take_annotated(serde_json::to_value(entity)?) {
+        Ok((_, value)) => value,
+        Err(value) => lift_annotations(value),
+    };
+
+    serde_json::to_string(&value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TableEntity;
+    use chrono::TimeZone;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Typed {
+        count: Int64,
+        born: DateTime,
+        id: Guid,
+        hash: Binary,
+        missing: Option<Int64>,
+        name: String,
+    }
+
+    fn typed() -> Typed {
+        Typed {
+            count: Int64(9_007_199_254_740_993),
+            born: DateTime(Utc.with_ymd_and_hms(2020, 5, 1, 10, 0, 0).unwrap()),
+            id: Guid(Uuid::parse_str("c9da6455-213d-42c9-9a79-3e9149a57833").unwrap()),
+            hash: Binary(vec![0x0a, 0xff]),
+            missing: None,
+            name: "plain".to_owned(),
+        }
+    }
+
+    #[test]
+    fn serialize_with_annotations() {
+        let entity = TableEntity {
+            partition_key: "pk".to_owned(),
+            row_key: "rk".to_owned(),
+            etag: None,
+            timestamp: None,
+            payload: typed(),
+        };
+
+        let value: Value = serde_json::from_str(&to_json(&entity).unwrap()).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "PartitionKey": "pk",
+                "RowKey": "rk",
+                "Count": "9007199254740993",
+                "Count@odata.type": "Edm.Int64",
+                "Born": "2020-05-01T10:00:00Z",
+                "Born@odata.type": "Edm.DateTime",
+                "Id": "c9da6455-213d-42c9-9a79-3e9149a57833",
+                "Id@odata.type": "Edm.Guid",
+                "Hash": "Cv8=",
+                "Hash@odata.type": "Edm.Binary",
+                "Missing": null,
+                "Name": "plain",
+            })
+        );
+    }
+
+    #[test]
+    fn no_marker_leaks() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Inner {
+            count: Int64,
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct Outer {
+            #[serde(flatten)]
+            flattened: Inner,
+            nested: Inner,
+            list: Vec<Guid>,
+            maybe: Option<Int64>,
+        }
+
+        let entity = TableEntity {
+            partition_key: "pk".to_owned(),
+            row_key: "rk".to_owned(),
+            etag: None,
+            timestamp: None,
+            payload: Outer {
+                flattened: Inner { count: Int64(1) },
+                nested: Inner { count: Int64(2) },
+                list: vec![Guid(
+                    Uuid::parse_str("c9da6455-213d-42c9-9a79-3e9149a57833").unwrap(),
+                )],
+                maybe: Some(Int64(3)),
+            },
+        };
+
+        let json = to_json(&entity).unwrap();
+        assert!(!json.contains(TYPE_MARKER) && !json.contains(VALUE_MARKER));
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap(),
+            serde_json::json!({
+                "PartitionKey": "pk",
+                "RowKey": "rk",
+                "Count": "1",
+                "Count@odata.type": "Edm.Int64",
+                "Nested": {
+                    "Count": "2",
+                    "Count@odata.type": "Edm.Int64",
+                },
+                "List": ["c9da6455-213d-42c9-9a79-3e9149a57833"],
+                "Maybe": "3",
+                "Maybe@odata.type": "Edm.Int64",
+            })
+        );
+
+        assert_eq!(to_json(&Int64(4)).unwrap(), "\"4\"");
+    }
+
+    #[test]
+    fn deserialize_with_and_without_annotations() {
+        let full_metadata = r#"{
+            "odata.etag": "W/\"datetime'2020-05-01T10%3A00%3A01.1234567Z'\"",
+            "PartitionKey": "pk",
+            "RowKey": "rk",
+            "Timestamp": "2020-05-01T10:00:01.1234567Z",
+            "Count@odata.type": "Edm.Int64",
+            "Count": "9007199254740993",
+            "Born@odata.type": "Edm.DateTime",
+            "Born": "2020-05-01T10:00:00Z",
+            "Id@odata.type": "Edm.Guid",
+            "Id": "c9da6455-213d-42c9-9a79-3e9149a57833",
+            "Hash@odata.type": "Edm.Binary",
+            "Hash": "Cv8=",
+            "Name": "plain"
+        }"#;
+        let entity: TableEntity<Typed> = serde_json::from_str(full_metadata).unwrap();
+        assert_eq!(entity.payload, typed());
+
+        // entities written as Int32 still read as Int64
+        let no_metadata = r#"{
+            "PartitionKey": "pk",
+            "RowKey": "rk",
+            "Timestamp": "2020-05-01T10:00:01.1234567Z",
+            "Count": 42,
+            "Born": "2020-05-01T10:00:00Z",
+            "Id": "c9da6455-213d-42c9-9a79-3e9149a57833",
+            "Hash": "Cv8=",
+            "Missing": "7",
+            "Name": "plain"
+        }"#;
+        let entity: TableEntity<Typed> = serde_json::from_str(no_metadata).unwrap();
+        assert_eq!(entity.payload.count, Int64(42));
+        assert_eq!(entity.payload.missing, Some(Int64(7)));
+    }
+}
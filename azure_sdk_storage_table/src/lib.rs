@@ -14,4 +14,9 @@ mod table_entity;
 pub use table_entity::*;
 mod batch;
 pub use batch::*;
+mod batch_response;
+pub use batch_response::*;
+mod query;
+pub use query::*;
 pub mod de;
+pub mod edm;
@@ -0,0 +1,296 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::fmt;
+use url::form_urlencoded;
+use uuid::Uuid;
+
+/// A literal in a filter expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    String(String),
+    Int32(i32),
+    Int64(i64),
+    Double(f64),
+    Bool(bool),
+    DateTime(DateTime<Utc>),
+    Guid(Uuid),
+    Binary(Vec<u8>),
+}
+
+impl fmt::Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            // single quotes are escaped by doubling them
+            FilterValue::String(s) => write!(f, "'{}'", s.replace('\'', "''")),
+            FilterValue::Int32(i) => write!(f, "{}", i),
+            FilterValue::Int64(i) => write!(f, "{}L", i),
+            FilterValue::Double(d) if d.fract() == 0.0 => write!(f, "{:.1}", d),
+            FilterValue::Double(d) => write!(f, "{}", d),
+            FilterValue::Bool(b) => write!(f, "{}", b),
+            FilterValue::DateTime(dt) => write!(
+                f,
+                "datetime'{}'",
+                dt.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+            ),
+            FilterValue::Guid(guid) => write!(f, "guid'{}'", guid.to_hyphenated()),
+            FilterValue::Binary(bytes) => {
+                write!(f, "X'")?;
+                for byte in bytes {
+                    write!(f, "{:02x}", byte)?;
+                }
+                write!(f, "'")
+            }
+        }
+    }
+}
+
+impl From<&str> for FilterValue {
+    fn from(s: &str) -> Self {
+        FilterValue::String(s.to_owned())
+    }
+}
+
+impl From<String> for FilterValue {
+    fn from(s: String) -> Self {
+        FilterValue::String(s)
+    }
+}
+
+impl From<i32> for FilterValue {
+    fn from(i: i32) -> Self {
+        FilterValue::Int32(i)
+    }
+}
+
+impl From<i64> for FilterValue {
+    fn from(i: i64) -> Self {
+        FilterValue::Int64(i)
+    }
+}
+
+impl From<f64> for FilterValue {
+    fn from(d: f64) -> Self {
+        FilterValue::Double(d)
+    }
+}
+
+impl From<bool> for FilterValue {
+    fn from(b: bool) -> Self {
+        FilterValue::Bool(b)
+    }
+}
+
+impl From<DateTime<Utc>> for FilterValue {
+    fn from(dt: DateTime<Utc>) -> Self {
+        FilterValue::DateTime(dt)
+    }
+}
+
+impl From<Uuid> for FilterValue {
+    fn from(guid: Uuid) -> Self {
+        FilterValue::Guid(guid)
+    }
+}
+
+impl From<&[u8]> for FilterValue {
+    fn from(bytes: &[u8]) -> Self {
+        FilterValue::Binary(bytes.to_vec())
+    }
+}
+
+impl From<Vec<u8>> for FilterValue {
+    fn from(bytes: Vec<u8>) -> Self {
+        FilterValue::Binary(bytes)
+    }
+}
+
+/// A `$filter` expression, e.g.
+/// `Filter::eq("PartitionKey", "pk").and(Filter::gt("Age", 30))`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter(String);
+
+impl Filter {
+    fn comparison<V: Into<FilterValue>>(property: &str, operator: &str, value: V) -> Filter {
+        Filter(format!("{} {} {}", property, operator, value.into()))
+    }
+
+    pub fn eq<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "eq", value)
+    }
+
+    pub fn ne<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "ne", value)
+    }
+
+    pub fn gt<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "gt", value)
+    }
+
+    pub fn ge<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "ge", value)
+    }
+
+    pub fn lt<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "lt", value)
+    }
+
+    pub fn le<V: Into<FilterValue>>(property: &str, value: V) -> Filter {
+        Filter::comparison(property, "le", value)
+    }
+
+    pub fn and(self, other: Filter) -> Filter {
+        Filter(format!("({}) and ({})", self.0, other.0))
+    }
+
+    pub fn or(self, other: Filter) -> Filter {
+        Filter(format!("({}) or ({})", self.0, other.0))
+    }
+}
+
+impl std::ops::Not for Filter {
+    type Output = Filter;
+
+    fn not(self) -> Filter {
+        Filter(format!("not ({})", self.0))
+    }
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The query options of `CloudTable::execute_typed_query`. `Display`
+/// gives the encoded query string, ready for `CloudTable::execute_query`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Query {
+    filter: Option<Filter>,
+    select: Vec<String>,
+    top: Option<u32>,
+}
+
+impl Query {
+    pub fn new() -> Query {
+        Query::default()
+    }
+
+    pub fn with_filter(self, filter: Filter) -> Query {
+        Query {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
+    /// Returns only these properties of the entities.
+    pub fn with_select(self, properties: &[&str]) -> Query {
+        Query {
+            select: properties.iter().map(|p| (*p).to_owned()).collect(),
+            ..self
+        }
+    }
+
+    /// Maximum number of entities per page. The continuation still
+    /// returns the following pages.
+    pub fn with_top(self, top: u32) -> Query {
+        Query {
+            top: Some(top),
+            ..self
+        }
+    }
+
+    pub fn filter(&self) -> Option<&Filter> {
+        self.filter.as_ref()
+    }
+
+    pub fn select(&self) -> &[String] {
+        &self.select
+    }
+
+    pub fn top(&self) -> Option<u32> {
+        self.top
+    }
+}
+
+fn encode(s: &str) -> String {
+    // form encoding turns spaces into '+', which the service would take
+    // literally
+    form_urlencoded::byte_serialize(s.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+impl fmt::Display for Query {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut params = Vec::new();
+
+        if let Some(filter) = &self.filter {
+            params.push(format!("$filter={}", encode(&filter.0)));
+        }
+        if !self.select.is_empty() {
+            params.push(format!("$select={}", encode(&self.select.join(","))));
+        }
+        if let Some(top) = self.top {
+            params.push(format!("$top={}", top));
+        }
+
+        write!(f, "{}", params.join("&"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn filter_literals() {
+        assert_eq!(
+            Filter::eq("LastName", "O'Brien").to_string(),
+            "LastName eq 'O''Brien'"
+        );
+        assert_eq!(Filter::gt("Age", 30).to_string(), "Age gt 30");
+        assert_eq!(Filter::le("Count", 3_i64).to_string(), "Count le 3L");
+        assert_eq!(Filter::lt("Ratio", 2.0).to_string(), "Ratio lt 2.0");
+        assert_eq!(Filter::ne("Active", true).to_string(), "Active ne true");
+        assert_eq!(
+            Filter::ge("Born", Utc.with_ymd_and_hms(2020, 5, 1, 10, 0, 0).unwrap()).to_string(),
+            "Born ge datetime'2020-05-01T10:00:00Z'"
+        );
+        assert_eq!(
+            Filter::eq(
+                "Id",
+                Uuid::parse_str("c9da6455-213d-42c9-9a79-3e9149a57833").unwrap()
+            )
+            .to_string(),
+            "Id eq guid'c9da6455-213d-42c9-9a79-3e9149a57833'"
+        );
+        assert_eq!(
+            Filter::eq("Hash", &[0x0a, 0xff][..]).to_string(),
+            "Hash eq X'0aff'"
+        );
+    }
+
+    #[test]
+    fn filter_combinators() {
+        let filter = Filter::eq("PartitionKey", "pk")
+            .and(Filter::gt("Age", 30).or(!Filter::eq("Active", false)));
+        assert_eq!(
+            filter.to_string(),
+            "(PartitionKey eq 'pk') and ((Age gt 30) or (not (Active eq false)))"
+        );
+    }
+
+    #[test]
+    fn query_string() {
+        assert_eq!(Query::new().to_string(), "");
+
+        let query = Query::new()
+            .with_filter(Filter::eq("PartitionKey", "a b&c"))
+            .with_select(&["RowKey", "Age"])
+            .with_top(10);
+        assert_eq!(
+            query.to_string(),
+            "$filter=PartitionKey%20eq%20%27a%20b%26c%27&$select=RowKey%2CAge&$top=10"
+        );
+    }
+}
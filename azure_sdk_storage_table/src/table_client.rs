@@ -1,18 +1,23 @@
-use azure_sdk_core::errors::{check_status_extract_body, AzureError};
+use crate::Query;
+use azure_sdk_core::errors::{
+    check_status_extract_body, check_status_extract_headers_and_body, AzureError,
+};
+use azure_sdk_core::http_client::ResponseFuture;
+use azure_sdk_core::StoredAccessPolicyList;
 use azure_sdk_storage_core::key_client::KeyClient;
 use azure_sdk_storage_core::prelude::*;
 use azure_sdk_storage_core::{
     client, get_default_json_mime, get_json_mime_fullmetadata, get_json_mime_nometadata,
     ConnectionString, ServiceType,
 };
+use futures::stream::Stream;
 use http::request::Builder;
-use hyper::{
-    client::ResponseFuture,
-    header::{self, HeaderValue},
-};
+use http::HeaderMap;
+use hyper::header::{self, HeaderValue};
 use hyper::{Method, StatusCode};
 use log;
 use serde_json;
+use std::convert::TryFrom;
 use url::Url;
 
 const TABLE_TABLES: &str = "Tables";
@@ -85,8 +90,8 @@ impl TableClient<KeyClient> {
 
     pub fn with_emulator(table_storage_url: &Url) -> Self {
         TableClient {
-            // The blob storage part won't be used, so just use the same URL.
-            client: client::with_emulator(table_storage_url, table_storage_url),
+            // The blob and queue storage parts won't be used, so just use the same URL.
+            client: client::with_emulator(table_storage_url, table_storage_url, table_storage_url),
         }
     }
 }
@@ -95,19 +100,72 @@ impl<C> TableClient<C>
 where
     C: Client,
 {
+    /// Lists every table of the account, following the continuation.
     pub async fn list_tables(&self) -> Result<Vec<String>, AzureError> {
+        let mut continuation = TableContinuation::start();
+        let mut tables = Vec::new();
+        while let Some(page) = self.query_tables(None, &mut continuation).await? {
+            tables.extend(page);
+        }
+        Ok(tables)
+    }
+
+    /// Returns one page of the tables matching `query`, e.g.
+    /// `Query::new().with_filter(Filter::ge("TableName", "a"))`. Returns
+    /// `None` once `continuation` is exhausted.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/query-tables
+    pub async fn query_tables(
+        &self,
+        query: Option<&Query>,
+        continuation: &mut TableContinuation,
+    ) -> Result<Option<Vec<String>>, AzureError> {
+        log::debug!(
+            "query_tables(query = {:?}, continuation = {:?})",
+            query,
+            continuation
+        );
+        if continuation.fused {
+            return Ok(None);
+        }
+
+        let mut path = TABLE_TABLES.to_owned();
+        path.push('?');
+        if let Some(query) = query {
+            path.push_str(&query.to_string());
+        }
+        if let Some(ref next_table_name) = continuation.next_table_name {
+            path.push_str("&NextTableName=");
+            path.push_str(next_table_name);
+        }
+
         let future_response = self.request_with_default_header(
-            TABLE_TABLES,
+            &path,
             &Method::GET,
             None,
             MetadataDetail::None,
             &|req| req,
         )?;
-        let body = check_status_extract_body(future_response, StatusCode::OK).await?;
-        let entities = serde_json::from_str::<TableDataCollection>(&body)?;
-        // todo: shall we use the continuation or query result always fits into a single page
-        let e: Vec<String> = entities.value.into_iter().map(|x| x.table_name).collect();
-        Ok(e)
+        let (headers, body) =
+            check_status_extract_headers_and_body(future_response, StatusCode::OK).await?;
+        let entities = serde_json::from_slice::<TableDataCollection>(&body)?;
+        *continuation = TableContinuation::try_from(&headers)?;
+        Ok(Some(
+            entities.value.into_iter().map(|x| x.table_name).collect(),
+        ))
+    }
+
+    pub fn stream_query_tables<'a>(
+        &'a self,
+        query: Option<&'a Query>,
+    ) -> impl Stream<Item = Result<Vec<String>, AzureError>> + 'a {
+        futures::stream::unfold(TableContinuation::start(), move |mut cont| async move {
+            log::debug!("cont == {:?}", cont);
+            match self.query_tables(query, &mut cont).await {
+                Ok(Some(segment)) => Some((Ok(segment), cont)),
+                Ok(None) => None,
+                Err(err) => Some((Err(err), cont)),
+            }
+        })
     }
 
     // Create table if not exists.
@@ -129,6 +187,52 @@ where
         Ok(())
     }
 
+    /// Deletes the table and all its entities.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/delete-table
+    pub async fn delete_table(&self, table_name: &str) -> Result<(), AzureError> {
+        let path = format!("{}('{}')", TABLE_TABLES, table_name);
+        let future_response = self.request_with_default_header(
+            &path,
+            &Method::DELETE,
+            None,
+            MetadataDetail::None,
+            &|req| req,
+        )?;
+
+        check_status_extract_body(future_response, StatusCode::NO_CONTENT).await?;
+        Ok(())
+    }
+
+    /// Returns the stored access policies of the table.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/get-table-acl
+    pub async fn get_table_acl(
+        &self,
+        table_name: &str,
+    ) -> Result<StoredAccessPolicyList, AzureError> {
+        let path = format!("{}?comp=acl", table_name);
+        let future_response = self.request(&path, &Method::GET, None, &|req| req)?;
+
+        let body = check_status_extract_body(future_response, StatusCode::OK).await?;
+        // the service prepends a BOM to the XML
+        StoredAccessPolicyList::from_xml(body.trim_start_matches('\u{feff}'))
+    }
+
+    /// Replaces the stored access policies of the table. At most five policies
+    /// can be set; an empty list removes them all.
+    /// See https://docs.microsoft.com/en-us/rest/api/storageservices/set-table-acl
+    pub async fn set_table_acl(
+        &self,
+        table_name: &str,
+        stored_access_policy_list: &StoredAccessPolicyList,
+    ) -> Result<(), AzureError> {
+        let path = format!("{}?comp=acl", table_name);
+        let xml = stored_access_policy_list.to_xml();
+        let future_response = self.request(&path, &Method::PUT, Some(&xml), &|req| req)?;
+
+        check_status_extract_body(future_response, StatusCode::NO_CONTENT).await?;
+        Ok(())
+    }
+
     pub fn get_uri_prefix(&self) -> String {
         self.client.get_uri_prefix(ServiceType::Table)
     }
@@ -200,6 +304,41 @@ struct TableDataCollection {
     value: Vec<TableData>,
 }
 
+/// Tracks the position of a paginated `TableClient::query_tables`.
+#[derive(Debug, Clone)]
+pub struct TableContinuation {
+    pub(crate) fused: bool,
+    pub(crate) next_table_name: Option<String>,
+}
+
+impl TableContinuation {
+    pub fn start() -> Self {
+        TableContinuation {
+            fused: false,
+            next_table_name: None,
+        }
+    }
+}
+
+impl std::convert::TryFrom<&HeaderMap> for TableContinuation {
+    type Error = AzureError;
+
+    fn try_from(headers: &HeaderMap) -> Result<Self, Self::Error> {
+        const HEADER_NEXTTABLENAME: &str = "x-ms-continuation-NextTableName";
+
+        match headers.get(HEADER_NEXTTABLENAME) {
+            Some(next_table_name) => Ok(TableContinuation {
+                fused: false,
+                next_table_name: Some(next_table_name.to_str()?.to_owned()),
+            }),
+            None => Ok(TableContinuation {
+                fused: true,
+                next_table_name: None,
+            }),
+        }
+    }
+}
+
 #[inline]
 pub(crate) fn get_batch_mime() -> &'static str {
     "multipart/mixed; boundary=batch_a1e9d677-b28b-435e-a89e-87e6a768a431"
@@ -208,3 +347,28 @@ pub(crate) fn get_batch_mime() -> &'static str {
 pub(crate) fn entity_path(table_name: &str, partition_key: &str, row_key: &str) -> String {
     table_name.to_owned() + "(PartitionKey='" + partition_key + "',RowKey='" + row_key + "')"
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use http::HeaderValue;
+
+    #[test]
+    fn table_continuation_from_headers() {
+        let mut headers = HeaderMap::new();
+        let continuation = TableContinuation::try_from(&headers).unwrap();
+        assert!(continuation.fused);
+        assert_eq!(continuation.next_table_name, None);
+
+        headers.insert(
+            "x-ms-continuation-NextTableName",
+            HeaderValue::from_static("1!12!bXl0YWJsZQ--"),
+        );
+        let continuation = TableContinuation::try_from(&headers).unwrap();
+        assert!(!continuation.fused);
+        assert_eq!(
+            continuation.next_table_name.as_deref(),
+            Some("1!12!bXl0YWJsZQ--")
+        );
+    }
+}
@@ -26,6 +26,18 @@ cd azure_sdk_storage_table
 cargo publish
 cd ..
 
+cd azure_sdk_storage_queue
+cargo publish
+cd ..
+
+cd azure_sdk_storage_file
+cargo publish
+cd ..
+
+cd azure_sdk_storage_datalake
+cargo publish
+cd ..
+
 cd azure_sdk_auth_aad
 cargo publish
 cd ..